@@ -0,0 +1,65 @@
+//! Throughput of the conversions over representative inputs: small values,
+//! the greatest value, every value, and long unicode numerals.
+//!
+//! Run it with `cargo bench --bench conversions`, and compare a change against
+//! a baseline with `--save-baseline` and `--baseline`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+use numerals::roman::{to_arabic, to_roman, to_roman_into, MAX};
+
+fn bench_to_roman(c: &mut Criterion) {
+    let mut group = c.benchmark_group("to_roman");
+    for &value in &[4, 14, 1994, MAX] {
+        group.bench_with_input(BenchmarkId::new("ascii", value), &value, |b, &value| {
+            b.iter(|| to_roman(black_box(value), false))
+        });
+        group.bench_with_input(BenchmarkId::new("unicode", value), &value, |b, &value| {
+            b.iter(|| to_roman(black_box(value), true))
+        });
+    }
+
+    group.throughput(Throughput::Elements(MAX));
+    group.bench_function("all values", |b| {
+        let mut buffer = String::new();
+        b.iter(|| {
+            for value in 1..=MAX {
+                buffer.clear();
+                to_roman_into(black_box(value), false, &mut buffer).unwrap();
+            }
+        })
+    });
+    group.finish();
+}
+
+fn bench_to_arabic(c: &mut Criterion) {
+    let mut group = c.benchmark_group("to_arabic");
+    for &value in &[4, 14, 1994, 3888] {
+        let ascii = to_roman(value, false).unwrap();
+        let unicode = to_roman(value, true).unwrap();
+        group.bench_with_input(BenchmarkId::new("ascii", &ascii), &ascii, |b, numeral| {
+            b.iter(|| to_arabic(black_box(numeral)))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("unicode", value),
+            &unicode,
+            |b, numeral| b.iter(|| to_arabic(black_box(numeral))),
+        );
+    }
+
+    let numerals: Vec<String> = (1..=MAX)
+        .map(|value| to_roman(value, true).unwrap())
+        .collect();
+    group.throughput(Throughput::Elements(MAX));
+    group.bench_function("all unicode values", |b| {
+        b.iter(|| {
+            for numeral in &numerals {
+                to_arabic(black_box(numeral)).unwrap();
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_to_roman, bench_to_arabic);
+criterion_main!(benches);
@@ -0,0 +1,27 @@
+//! Latency of the first conversions of a process, when the lookup tables are
+//! used for the first time.
+//!
+//! This cannot be measured by repeating calls within one process, so run it a
+//! few times with `cargo bench --bench first_call`.
+
+use std::time::Instant;
+
+use numerals::roman::{to_arabic, to_roman};
+
+fn main() {
+    let start = Instant::now();
+    let value = to_arabic("MCMXCIV").unwrap();
+    let first_arabic = start.elapsed();
+
+    let start = Instant::now();
+    let numeral = to_roman(value, true).unwrap();
+    let first_roman = start.elapsed();
+
+    let start = Instant::now();
+    to_arabic(numeral).unwrap();
+    let second_arabic = start.elapsed();
+
+    println!("first to_arabic:  {:?}", first_arabic);
+    println!("first to_roman:   {:?}", first_roman);
+    println!("second to_arabic: {:?}", second_arabic);
+}
@@ -0,0 +1,39 @@
+//! Time taken to format every numeral from 1 to 3999, many times over.
+//!
+//! Run it with and without the lookup table to compare them:
+//! `cargo bench --bench format` and `cargo bench --bench format --features table`.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use numerals::roman::{to_roman, to_roman_into, MAX};
+
+const ROUNDS: u32 = 200;
+
+fn main() {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for value in 1..=MAX {
+            black_box(to_roman(black_box(value), false).unwrap());
+        }
+    }
+    let to_roman_elapsed = start.elapsed();
+
+    let mut buffer = String::new();
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for value in 1..=MAX {
+            buffer.clear();
+            to_roman_into(black_box(value), false, &mut buffer).unwrap();
+            black_box(&buffer);
+        }
+    }
+    let to_roman_into_elapsed = start.elapsed();
+
+    let count = ROUNDS * MAX as u32;
+    println!("to_roman:      {:?} per numeral", to_roman_elapsed / count);
+    println!(
+        "to_roman_into: {:?} per numeral",
+        to_roman_into_elapsed / count
+    );
+}
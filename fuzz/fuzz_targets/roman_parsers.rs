@@ -0,0 +1,25 @@
+//! The other roman parsers on arbitrary strings, which should never panic.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use numerals::roman::{
+    canonicalize, explain, find_all, parse_prefix, suggest, to_arabic_historical,
+    to_arabic_lenient, to_arabic_ordinal, to_arabic_signed, to_arabic_twelfths,
+    to_arabic_with_separators, validate, SEPARATORS,
+};
+
+fuzz_target!(|input: &str| {
+    let _ = validate(input);
+    let _ = parse_prefix(input);
+    let _ = find_all(input).count();
+    let _ = suggest(input);
+    let _ = explain(input);
+    let _ = canonicalize(input);
+    let _ = to_arabic_lenient(input);
+    let _ = to_arabic_historical(input);
+    let _ = to_arabic_ordinal(input);
+    let _ = to_arabic_signed(input);
+    let _ = to_arabic_twelfths(input);
+    let _ = to_arabic_with_separators(input, &SEPARATORS);
+});
@@ -0,0 +1,16 @@
+//! `to_arabic` on arbitrary strings: it should never panic, and the values it
+//! accepts within range should be written back to a numeral of the same value.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use numerals::roman::{to_arabic, to_roman, MAX};
+
+fuzz_target!(|input: &str| {
+    if let Ok(value) = to_arabic(input) {
+        if (1..=MAX).contains(&value) {
+            let numeral = to_roman(value, false).unwrap();
+            assert_eq!(to_arabic(&numeral), Ok(value));
+        }
+    }
+});
@@ -0,0 +1,308 @@
+/*!
+Arabic numbers, as found alongside the numerals of the other systems.
+
+Besides the ascii digits, the full-width digits ０ to ９ of japanese, chinese
+and korean text are accepted, so that numbers copied from such documents need
+not be converted first.
+
+The tibetan digits ༠ to ༩ are accepted as well, and the half digits ༪ to ༳ of
+the page numbers, standing for a digit less a half, are parsed by
+`parse_halves`.
+*/
+
+use alloc::string::String;
+
+use crate::error::NumeralError;
+
+const FULLWIDTH_ZERO: u32 = '０' as u32;
+
+const TIBETAN_ZERO: u32 = '༠' as u32;
+
+/// The half digits, from ༪ for one less a half to ༲ for nine less a half, and
+/// ༳ for zero less a half.
+const TIBETAN_HALVES: [char; 10] = ['༳', '༪', '༫', '༬', '༭', '༮', '༯', '༰', '༱', '༲'];
+
+/// The value of an arabic digit, in ascii or in full width.
+///
+///     use numerals::arabic::digit_value;
+///
+///     assert_eq!(digit_value('7'), Some(7));
+///     assert_eq!(digit_value('７'), Some(7));
+///     assert_eq!(digit_value('七'), None);
+pub fn digit_value(ch: char) -> Option<u32> {
+    match ch {
+        '0'..='9' => ch.to_digit(10),
+        '０'..='９' => Some(ch as u32 - FULLWIDTH_ZERO),
+        _ => None,
+    }
+}
+
+/// The value of a tibetan digit, from ༠ to ༩.
+///
+///     use numerals::arabic::tibetan_digit_value;
+///
+///     assert_eq!(tibetan_digit_value('༧'), Some(7));
+///     assert_eq!(tibetan_digit_value('7'), None);
+pub fn tibetan_digit_value(ch: char) -> Option<u32> {
+    match ch {
+        '༠'..='༩' => Some(ch as u32 - TIBETAN_ZERO),
+        _ => None,
+    }
+}
+
+/// Convert a number written in arabic digits to its value.
+///
+/// Ascii, full-width and tibetan digits can be mixed, although they seldom
+/// are.
+///
+///     use numerals::arabic::parse;
+///
+///     assert_eq!(parse("1994"), Ok(1994));
+///     assert_eq!(parse("１９９４"), Ok(1994));
+///     assert_eq!(parse("༡༩༩༤"), Ok(1994));
+///     assert!(parse("MCMXCIV").is_err());
+pub fn parse(input: &str) -> Result<u64, NumeralError> {
+    if input.is_empty() {
+        return Err(NumeralError::Empty);
+    }
+
+    let mut value: u64 = 0;
+    for (index, ch) in input.chars().enumerate() {
+        let digit = digit_value(ch)
+            .or_else(|| tibetan_digit_value(ch))
+            .ok_or(NumeralError::InvalidCharacter { ch, index })?;
+        value = value
+            .checked_mul(10)
+            .and_then(|value| value.checked_add(u64::from(digit)))
+            .ok_or(NumeralError::Overflow)?;
+    }
+    Ok(value)
+}
+
+/// Write the ascii digits of `input` in full width, leaving the other
+/// characters as they are.
+///
+///     use numerals::arabic::to_fullwidth;
+///
+///     assert_eq!(to_fullwidth("1994"), "１９９４");
+///     assert_eq!(to_fullwidth("1億2345万"), "１億２３４５万");
+pub fn to_fullwidth(input: &str) -> String {
+    input
+        .chars()
+        .map(|ch| match ch.to_digit(10) {
+            Some(digit) => char::from_u32(FULLWIDTH_ZERO + digit).unwrap_or(ch),
+            None => ch,
+        })
+        .collect()
+}
+
+/// Parse a number whose last digit may be a tibetan half digit, as a value
+/// counted in halves.
+///
+/// The half digits stand for their digit less a half, as in ༡༪ for 10½ and
+/// ༢༳ for 19½, and are found in the page numbers of tibetan books. They can
+/// only be the last digit.
+///
+///     use numerals::arabic::parse_halves;
+///
+///     assert_eq!(parse_halves("༡༪"), Ok(21));
+///     assert_eq!(parse_halves("༢༳"), Ok(39));
+///     assert_eq!(parse_halves("༡༥"), Ok(30));
+pub fn parse_halves(input: &str) -> Result<u64, NumeralError> {
+    let count = input.chars().count();
+    let half = input
+        .chars()
+        .enumerate()
+        .find(|&(_, ch)| TIBETAN_HALVES.contains(&ch));
+    let (whole, less) = match half {
+        Some((index, ch)) if index + 1 < count => {
+            return Err(NumeralError::InvalidSequence { ch, index });
+        }
+        Some((index, ch)) => {
+            let digit = TIBETAN_HALVES
+                .iter()
+                .position(|&half| half == ch)
+                .unwrap_or(0);
+            let mut whole = match index {
+                0 => 0,
+                _ => parse(input.trim_end_matches(ch))?,
+            };
+            whole = whole
+                .checked_mul(10)
+                .and_then(|whole| whole.checked_add(digit as u64))
+                .ok_or(NumeralError::Overflow)?;
+            (whole, 1)
+        }
+        None => (parse(input)?, 0),
+    };
+    whole
+        .checked_mul(2)
+        .ok_or(NumeralError::Overflow)?
+        .checked_sub(less)
+        .ok_or(NumeralError::Negative)
+}
+
+/// Write the ascii digits of `input` in tibetan, leaving the other characters
+/// as they are.
+///
+///     use numerals::arabic::to_tibetan;
+///
+///     assert_eq!(to_tibetan("1994"), "༡༩༩༤");
+pub fn to_tibetan(input: &str) -> String {
+    input
+        .chars()
+        .map(|ch| match ch.to_digit(10) {
+            Some(digit) if ch.is_ascii() => char::from_u32(TIBETAN_ZERO + digit).unwrap_or(ch),
+            _ => ch,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_parse {
+    use crate::arabic::parse;
+    use crate::NumeralError;
+
+    #[test]
+    fn test_parse() {
+        let x = parse("0");
+        assert_eq!(x.unwrap(), 0);
+
+        let x = parse("１２３４");
+        assert_eq!(x.unwrap(), 1234);
+
+        let x = parse("１2３4");
+        assert_eq!(x.unwrap(), 1234);
+
+        let x = parse("１８４４６７４４０７３７０９５５１６１５");
+        assert_eq!(x.unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let x = parse("");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = parse("１,２３４");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: ',', index: 1 }
+        );
+
+        let x = parse("－１４");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter {
+                ch: '－', index: 0
+            }
+        );
+
+        let x = parse("１８４４６７４４０７３７０９５５１６１６");
+        assert_eq!(x.unwrap_err(), NumeralError::Overflow);
+    }
+}
+
+#[cfg(test)]
+mod test_to_fullwidth {
+    use crate::arabic::{parse, to_fullwidth};
+
+    #[test]
+    fn test_to_fullwidth() {
+        assert_eq!(to_fullwidth("0123456789"), "０１２３４５６７８９");
+        assert_eq!(to_fullwidth("1,234.5万"), "１,２３４.５万");
+        assert_eq!(to_fullwidth("１2"), "１２");
+        assert_eq!(to_fullwidth("XIV"), "XIV");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for value in (0..=10_000).chain(Some(u64::MAX)) {
+            let x = parse(&to_fullwidth(&value.to_string()));
+            assert_eq!(x.unwrap(), value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_tibetan {
+    use crate::arabic::{parse, parse_halves, tibetan_digit_value, to_tibetan};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_tibetan_digit_value() {
+        assert_eq!(tibetan_digit_value('༠'), Some(0));
+        assert_eq!(tibetan_digit_value('༩'), Some(9));
+        assert_eq!(tibetan_digit_value('༪'), None);
+        assert_eq!(tibetan_digit_value('9'), None);
+    }
+
+    #[test]
+    fn test_to_tibetan() {
+        assert_eq!(to_tibetan("0123456789"), "༠༡༢༣༤༥༦༧༨༩");
+        assert_eq!(to_tibetan("p. 12"), "p. ༡༢");
+        assert_eq!(to_tibetan("１2"), "１༢");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for value in (0..=10_000).chain(Some(u64::MAX)) {
+            let x = parse(&to_tibetan(&value.to_string()));
+            assert_eq!(x.unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_parse_halves() {
+        let x = parse_halves("༪");
+        assert_eq!(x.unwrap(), 1);
+
+        let x = parse_halves("༲");
+        assert_eq!(x.unwrap(), 17);
+
+        let x = parse_halves("༡༳");
+        assert_eq!(x.unwrap(), 19);
+
+        let x = parse_halves("༢༤༫");
+        assert_eq!(x.unwrap(), 483);
+
+        let x = parse_halves("༢༤");
+        assert_eq!(x.unwrap(), 48);
+
+        let x = parse_halves("24");
+        assert_eq!(x.unwrap(), 48);
+    }
+
+    #[test]
+    fn test_invalid_halves() {
+        let x = parse_halves("");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = parse_halves("༳");
+        assert_eq!(x.unwrap_err(), NumeralError::Negative);
+
+        let x = parse_halves("༪༡");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '༪', index: 0
+            }
+        );
+
+        let x = parse_halves("༡༪༪");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '༪', index: 1
+            }
+        );
+
+        let x = parse_halves("༡x༪");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: 'x', index: 1 }
+        );
+
+        let x = parse_halves("9223372036854775808");
+        assert_eq!(x.unwrap_err(), NumeralError::Overflow);
+    }
+}
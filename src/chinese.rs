@@ -0,0 +1,1280 @@
+/*!
+Convert chinese numerals to arabic, and vice-versa.
+
+Chinese numerals are written with the same digits and units as the japanese
+ones, 一 to 九 followed by 十, 百 or 千, grouped by 万 for 10^4 and 亿 for
+10^8. Unlike in japanese, 一 is written before every unit but a leading 十, as
+in 一千九百九十四, and before 万 and 亿.
+
+The groups beyond 亿 are counted in 亿, so that 10^12 is written 一万亿, and
+10^16 一亿亿.
+
+A zero within the number is written 零, once for each run of zeros, as in
+一千零一 for 1001 or 一万零一十 for 10010. Zero by itself is written 零 too,
+and the years are written with one digit for each arabic digit, as in 二〇二四.
+
+The traditional characters of Taiwan and Hong Kong write 萬 and 億 rather than
+万 and 亿, which is selected with `Script`. Either is parsed.
+
+The financial numerals, or 大写, written on invoices and cheques so that they
+cannot be tampered with, use other characters for the digits and units, as in
+壹仟玖佰玖拾肆, with 壹 written before every unit. They are written with the
+simplified or traditional characters as well, as in 贰 and 貳 for 2.
+
+Older texts write 万万 rather than 亿, which is parsed, and written with
+`ChineseOptions::wanwan`. The character 兆 stands for 10^6 in mainland China,
+as in 兆瓦 for a megawatt, but for 10^12 in Taiwan, where the groups beyond 億
+are counted in 兆 and in 京 for 10^16. Which one is meant is selected with
+`Zhao`, and follows the script by default.
+
+The Suzhou numerals of market price boards are converted by `suzhou`, and the
+counting rods of chinese mathematics by `rods`.
+*/
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::cjk::{self, UNIT_VALUES};
+use crate::error::NumeralError;
+use crate::system::{NumeralSystem, Options, Strictness};
+
+pub mod rods;
+pub mod suzhou;
+
+/// The characters of a set of numerals.
+struct Characters {
+    digits: [char; 10],
+    units: [char; 3],
+    /// The ten thousands, by which the groups of four digits are multiplied.
+    wan: char,
+    /// The hundred millions, by which the groups of eight digits are
+    /// multiplied.
+    yi: char,
+}
+
+const SIMPLIFIED: Characters = Characters {
+    digits: cjk::DIGITS,
+    units: cjk::UNITS,
+    wan: '万',
+    yi: '亿',
+};
+
+const TRADITIONAL: Characters = Characters {
+    digits: cjk::DIGITS,
+    units: cjk::UNITS,
+    wan: '萬',
+    yi: '億',
+};
+
+const SIMPLIFIED_FINANCIAL: Characters = Characters {
+    digits: ['零', '壹', '贰', '叁', '肆', '伍', '陆', '柒', '捌', '玖'],
+    units: ['仟', '佰', '拾'],
+    wan: '万',
+    yi: '亿',
+};
+
+const TRADITIONAL_FINANCIAL: Characters = Characters {
+    digits: ['零', '壹', '貳', '參', '肆', '伍', '陸', '柒', '捌', '玖'],
+    units: ['仟', '佰', '拾'],
+    wan: '萬',
+    yi: '億',
+};
+
+/// The value of a digit, in any of the sets of numerals, also accepting 两
+/// for 二.
+fn digit_value(ch: char) -> Option<u64> {
+    if is_liang(ch) {
+        return Some(2);
+    }
+    cjk::digit_value(ch).or_else(|| {
+        [&SIMPLIFIED_FINANCIAL, &TRADITIONAL_FINANCIAL]
+            .iter()
+            .find_map(|characters| characters.digits.iter().position(|&digit| digit == ch))
+            .map(|digit| digit as u64)
+    })
+}
+
+/// The value of a unit, in any of the sets of numerals.
+fn unit_value(ch: char) -> Option<u64> {
+    cjk::unit_value(ch).or_else(|| {
+        let position = SIMPLIFIED_FINANCIAL
+            .units
+            .iter()
+            .position(|&unit| unit == ch)?;
+        Some(UNIT_VALUES[position])
+    })
+}
+
+/// The value of a character grouping the digits, in either script, with 兆
+/// and 京 counting the groups beyond 亿 as in Taiwan.
+fn myriad_value(ch: char, zhao: Zhao) -> Option<u64> {
+    match ch {
+        '万' | '萬' => Some(10_000),
+        '亿' | '億' => Some(100_000_000),
+        '兆' if zhao == Zhao::Trillion => Some(1_000_000_000_000),
+        '京' if zhao == Zhao::Trillion => Some(10_000_000_000_000_000),
+        _ => None,
+    }
+}
+
+/// The characters the numerals are written with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Script {
+    /// The simplified characters of mainland China and Singapore, as in 一万.
+    #[default]
+    Simplified,
+    /// The traditional characters of Taiwan and Hong Kong, as in 一萬.
+    Traditional,
+}
+
+/// The value of 兆.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Zhao {
+    /// 10^6, as in mainland China, where 兆 is only found at the end of a
+    /// numeral, as in 三百兆 for 300 million.
+    Million,
+    /// 10^12, as in Taiwan, where the groups beyond 億 are counted in 兆 and
+    /// in 京 for 10^16, as in 三兆 for 3 trillion.
+    Trillion,
+}
+
+/// The options of `to_chinese_with` and `to_arabic_with`.
+///
+///     use numerals::chinese::{to_chinese_with, ChineseOptions, Script};
+///
+///     let options = ChineseOptions::new().script(Script::Traditional);
+///     assert_eq!(to_chinese_with(323_000_000, &options), "三億二千三百萬");
+///
+///     let options = ChineseOptions::new().financial();
+///     assert_eq!(to_chinese_with(1994, &options), "壹仟玖佰玖拾肆");
+///
+///     let options = ChineseOptions::new().wanwan();
+///     assert_eq!(to_chinese_with(323_000_000, &options), "三万万二千三百万");
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ChineseOptions {
+    script: Script,
+    financial: bool,
+    wanwan: bool,
+    zhao: Option<Zhao>,
+    strictness: Strictness,
+}
+
+impl ChineseOptions {
+    /// The default options: the simplified characters.
+    pub fn new() -> ChineseOptions {
+        ChineseOptions::default()
+    }
+
+    /// Write the numerals with the characters of the given script.
+    pub fn script(mut self, script: Script) -> ChineseOptions {
+        self.script = script;
+        self
+    }
+
+    /// Write the numerals with the traditional characters.
+    pub fn traditional(self) -> ChineseOptions {
+        self.script(Script::Traditional)
+    }
+
+    /// Write the financial numerals, in the script selected.
+    pub fn financial(mut self) -> ChineseOptions {
+        self.financial = true;
+        self
+    }
+
+    /// Write 万万 rather than 亿, as in older texts.
+    pub fn wanwan(mut self) -> ChineseOptions {
+        self.wanwan = true;
+        self
+    }
+
+    /// Read 兆 with the given value, and write the groups beyond 亿 with 兆
+    /// and 京 if it stands for 10^12.
+    ///
+    /// Unless selected, 兆 stands for 10^6 with the simplified characters,
+    /// and for 10^12 with the traditional ones.
+    pub fn zhao(mut self, zhao: Zhao) -> ChineseOptions {
+        self.zhao = Some(zhao);
+        self
+    }
+
+    /// Parse the numerals following the rules as closely as asked.
+    pub fn strictness(mut self, strictness: Strictness) -> ChineseOptions {
+        self.strictness = strictness;
+        self
+    }
+
+    /// The value of 兆, as selected or following the script.
+    fn zhao_value(&self) -> Zhao {
+        self.zhao.unwrap_or(match self.script {
+            Script::Simplified => Zhao::Million,
+            Script::Traditional => Zhao::Trillion,
+        })
+    }
+
+    /// The characters to write the numerals with.
+    fn characters(&self) -> &'static Characters {
+        match (self.script, self.financial) {
+            (Script::Simplified, false) => &SIMPLIFIED,
+            (Script::Traditional, false) => &TRADITIONAL,
+            (Script::Simplified, true) => &SIMPLIFIED_FINANCIAL,
+            (Script::Traditional, true) => &TRADITIONAL_FINANCIAL,
+        }
+    }
+}
+
+/// Write `input` as a chinese numeral.
+///
+///     use numerals::chinese::to_chinese;
+///
+///     assert_eq!(to_chinese(1994), "一千九百九十四");
+///     assert_eq!(to_chinese(15), "十五");
+///     assert_eq!(to_chinese(10_010), "一万零一十");
+///     assert_eq!(to_chinese(323_000_000), "三亿二千三百万");
+pub fn to_chinese(input: u64) -> String {
+    to_chinese_with(input, &ChineseOptions::default())
+}
+
+/// `to_chinese`, following the options.
+pub fn to_chinese_with(input: u64, options: &ChineseOptions) -> String {
+    let characters = options.characters();
+    if input == 0 {
+        return characters.digits[0].into();
+    }
+
+    // The characters grouping the digits, from the largest
+    let mut myriads = Vec::new();
+    if options.zhao_value() == Zhao::Trillion {
+        myriads.push((10_000_000_000_000_000, String::from("京")));
+        myriads.push((1_000_000_000_000, String::from("兆")));
+    }
+    let yi = if options.wanwan {
+        [characters.wan, characters.wan].iter().collect()
+    } else {
+        String::from(characters.yi)
+    };
+    myriads.push((100_000_000, yi));
+    myriads.push((10_000, String::from(characters.wan)));
+
+    // The financial numerals write 壹 before a leading 拾 all the same
+    let mut ret = String::new();
+    write_myriads(input, !options.financial, characters, &myriads, &mut ret);
+    ret
+}
+
+/// Write a value grouped by the largest of the given myriads it reaches, the
+/// groups above it being written the same way, as in 一万亿.
+fn write_myriads(
+    input: u64,
+    leading: bool,
+    characters: &Characters,
+    myriads: &[(u64, String)],
+    out: &mut String,
+) {
+    let position = match myriads.iter().position(|&(myriad, _)| input >= myriad) {
+        Some(position) => position,
+        None => return write_group(input, leading, characters, out),
+    };
+
+    let (myriad, name) = &myriads[position];
+    let (high, low) = (input / myriad, input % myriad);
+    write_myriads(high, leading, characters, myriads, out);
+    out.push_str(name);
+    if low > 0 {
+        if low < myriad / 10 {
+            out.push(characters.digits[0]);
+        }
+        write_myriads(low, false, characters, &myriads[position + 1..], out);
+    }
+}
+
+/// Write a group of four digits, with 零 for the zeros between its digits.
+///
+/// The 一 before 十 is left out when it leads the whole numeral, as in 十五.
+fn write_group(input: u64, leading: bool, characters: &Characters, out: &mut String) {
+    let digits = &characters.digits;
+    // Whether a digit was written, and whether zeros followed it
+    let mut started = false;
+    let mut zero = false;
+    for (&unit, &ch) in UNIT_VALUES.iter().zip(characters.units.iter()) {
+        let digit = (input / unit % 10) as usize;
+        if digit == 0 {
+            zero |= started;
+            continue;
+        }
+        if zero {
+            out.push(digits[0]);
+            zero = false;
+        }
+        if !(digit == 1 && unit == 10 && leading && !started) {
+            out.push(digits[digit]);
+        }
+        out.push(ch);
+        started = true;
+    }
+
+    let digit = (input % 10) as usize;
+    if digit > 0 {
+        if zero {
+            out.push(digits[0]);
+        }
+        out.push(digits[digit]);
+    }
+}
+
+/// The pinyin of the digits, from 零 to 九.
+const PINYIN_DIGITS: [&str; 10] = [
+    "líng", "yī", "èr", "sān", "sì", "wǔ", "liù", "qī", "bā", "jiǔ",
+];
+
+/// The pinyin of a character of the numerals, in any of the sets of numerals.
+fn pinyin(ch: char) -> Option<&'static str> {
+    if is_liang(ch) {
+        return Some("liǎng");
+    }
+    if let Some(digit) = digit_value(ch) {
+        return Some(PINYIN_DIGITS[digit as usize]);
+    }
+    match unit_value(ch).or_else(|| myriad_value(ch, Zhao::Trillion))? {
+        10 => Some("shí"),
+        100 => Some("bǎi"),
+        1000 => Some("qiān"),
+        10_000 => Some("wàn"),
+        100_000_000 => Some("yì"),
+        1_000_000_000_000 => Some("zhào"),
+        _ => Some("jīng"),
+    }
+}
+
+/// Write how `input` is read, in pinyin with tone marks, one syllable for
+/// each character of the numeral.
+///
+/// The syllables are written with the tones of the characters by themselves,
+/// as in dictionaries, and not with the tones they take before one another.
+///
+///     use numerals::chinese::to_pinyin;
+///
+///     assert_eq!(to_pinyin(1994), "yī qiān jiǔ bǎi jiǔ shí sì");
+///     assert_eq!(to_pinyin(1001), "yī qiān líng yī");
+///     assert_eq!(to_pinyin(15), "shí wǔ");
+pub fn to_pinyin(input: u64) -> String {
+    to_pinyin_with(input, &ChineseOptions::default())
+}
+
+/// `to_pinyin`, reading the numeral written following the options.
+///
+///     use numerals::chinese::{to_pinyin_with, ChineseOptions, Zhao};
+///
+///     let options = ChineseOptions::new().zhao(Zhao::Trillion);
+///     assert_eq!(to_pinyin_with(3_000_000_000_000, &options), "sān zhào");
+pub fn to_pinyin_with(input: u64, options: &ChineseOptions) -> String {
+    let syllables: Vec<&str> = to_chinese_with(input, options)
+        .chars()
+        .filter_map(pinyin)
+        .collect();
+    syllables.join(" ")
+}
+
+/// Convert a chinese numeral to its value.
+///
+/// A final digit following a larger unit stands for the digit of the unit
+/// below, which is left out in everyday speech, as in 三万五 for 35,000. The
+/// 零 standing for the zeros within the number can be left out before a unit,
+/// as in 一千一十. The 两 of 两千 or 两万 is accepted for 二 before 百 and the
+/// larger units, and 万万 for 亿. A final 兆 stands for 10^6.
+///
+///     use numerals::chinese::to_arabic;
+///
+///     assert_eq!(to_arabic("一千九百九十四"), Ok(1994));
+///     assert_eq!(to_arabic("一千零一"), Ok(1001));
+///     assert_eq!(to_arabic("一千一"), Ok(1100));
+///     assert_eq!(to_arabic("两万三"), Ok(23_000));
+///     assert_eq!(to_arabic("一万亿"), Ok(1_000_000_000_000));
+///     assert_eq!(to_arabic("三万万"), Ok(300_000_000));
+///     assert_eq!(to_arabic("三億二千三百萬"), Ok(323_000_000));
+///     assert_eq!(to_arabic("壹仟玖佰玖拾肆"), Ok(1994));
+///     assert_eq!(to_arabic("二〇二四"), Ok(2024));
+///     assert!(to_arabic("两十").is_err());
+pub fn to_arabic(input: &str) -> Result<u64, NumeralError> {
+    to_arabic_with(input, &ChineseOptions::new())
+}
+
+/// `to_arabic`, following the rules as closely as asked.
+///
+/// Strictly, only the numerals written as by `to_chinese_with` are accepted,
+/// in either script, with 两 for 二 where it can be written: the 零 within
+/// the number are required, and the final digits cannot be left out.
+///
+///     use numerals::chinese::to_arabic_with_strictness;
+///     use numerals::Strictness;
+///
+///     assert_eq!(to_arabic_with_strictness("一千零一十", Strictness::Strict), Ok(1010));
+///     assert_eq!(to_arabic_with_strictness("两千", Strictness::Strict), Ok(2000));
+///     assert!(to_arabic_with_strictness("一千一十", Strictness::Strict).is_err());
+///     assert!(to_arabic_with_strictness("三万五", Strictness::Strict).is_err());
+pub fn to_arabic_with_strictness(input: &str, strictness: Strictness) -> Result<u64, NumeralError> {
+    to_arabic_with(input, &ChineseOptions::new().strictness(strictness))
+}
+
+/// `to_arabic`, following the options: the value of 兆 and the strictness.
+///
+/// Strictly, the numerals are compared with the ones written following the
+/// options, in either script and as financial numerals or not.
+///
+///     use numerals::chinese::{to_arabic_with, ChineseOptions, Zhao};
+///
+///     let options = ChineseOptions::new();
+///     assert_eq!(to_arabic_with("三百兆", &options), Ok(300_000_000));
+///
+///     let options = ChineseOptions::new().zhao(Zhao::Trillion);
+///     assert_eq!(to_arabic_with("三兆二千億", &options), Ok(3_200_000_000_000));
+pub fn to_arabic_with(input: &str, options: &ChineseOptions) -> Result<u64, NumeralError> {
+    if input.is_empty() {
+        return Err(NumeralError::Empty);
+    }
+
+    // Several digits and nothing else form a positional numeral
+    let positional =
+        input.chars().nth(1).is_some() && input.chars().all(|ch| cjk::digit_value(ch).is_some());
+    if positional {
+        return from_positional(input);
+    }
+    if input.chars().count() == 1
+        && cjk::digit_value(input.chars().next().unwrap_or_default()) == Some(0)
+    {
+        return Ok(0);
+    }
+
+    let value = from_myriads(input, options.zhao_value())?;
+    if options.strictness == Strictness::Strict {
+        if let Some((index, ch)) = find_non_canonical(input, value, options) {
+            return Err(NumeralError::InvalidSequence { ch, index });
+        }
+    }
+    Ok(value)
+}
+
+/// The value of a numeral written with units, reading 万万 as 亿, and a final
+/// 兆 as 10^6 unless it stands for 10^12.
+fn from_myriads(input: &str, zhao: Zhao) -> Result<u64, NumeralError> {
+    let chars: Vec<(usize, char)> = input.chars().enumerate().collect();
+    // The numeral with 亿 for 万万, and where each of its characters is found
+    // in the input
+    let mut numeral = String::new();
+    let mut indices = Vec::new();
+    let mut position = 0;
+    while let Some(&(index, ch)) = chars.get(position) {
+        if myriad_value(ch, zhao) != Some(10_000) {
+            numeral.push(ch);
+            indices.push(index);
+            position += 1;
+            continue;
+        }
+        // The 万 are read two by two from the last, so that 一万万万 is 一万亿
+        let run = chars[position..]
+            .iter()
+            .take_while(|&&(_, ch)| myriad_value(ch, zhao) == Some(10_000))
+            .count();
+        if run % 2 == 1 {
+            numeral.push(ch);
+            indices.push(index);
+        }
+        for pair in chars[position + run % 2..position + run].chunks(2) {
+            numeral.push(if pair[0].1 == '萬' { '億' } else { '亿' });
+            indices.push(pair[0].0);
+        }
+        position += run;
+    }
+
+    let (numeral, multiplier) = match numeral.strip_suffix('兆') {
+        Some(numeral) if zhao == Zhao::Million => (numeral, 1_000_000),
+        _ => (numeral.as_str(), 1),
+    };
+    if numeral.is_empty() {
+        return Err(NumeralError::InvalidSequence {
+            ch: '兆',
+            index: indices[0],
+        });
+    }
+    let value = from_units(numeral, zhao).map_err(|error| match error {
+        NumeralError::InvalidCharacter { ch, index } => NumeralError::InvalidCharacter {
+            ch,
+            index: indices[index],
+        },
+        NumeralError::InvalidSequence { ch, index } => NumeralError::InvalidSequence {
+            ch: input.chars().nth(indices[index]).unwrap_or(ch),
+            index: indices[index],
+        },
+        error => error,
+    })?;
+    value.checked_mul(multiplier).ok_or(NumeralError::Overflow)
+}
+
+/// The value of a numeral written with units.
+fn from_units(input: &str, zhao: Zhao) -> Result<u64, NumeralError> {
+    // The value of the groups completed by 兆 or 京, of the groups completed
+    // by 亿 since, of the group multiplied by 万 since, and of the units since
+    let mut high: u64 = 0;
+    let mut total: u64 = 0;
+    let mut wan: Option<u64> = None;
+    let mut group: u64 = 0;
+    // The last of 兆 or 京, the last unit of the group, the last unit or
+    // myriad, and the digit waiting for its unit
+    let mut last_large = u64::MAX;
+    let mut last_unit = 10_000;
+    let mut last_multiplier = 1;
+    let mut digit: Option<(u64, usize, char)> = None;
+    // Whether a final digit can stand for itself, rather than for the digit
+    // of an implied unit, as after 十 or 零
+    let mut closed = true;
+    let mut last: Option<char> = None;
+    let checked = |value: Option<u64>| value.ok_or(NumeralError::Overflow);
+
+    for (index, ch) in input.chars().enumerate() {
+        let invalid = NumeralError::InvalidSequence { ch, index };
+        if let Some(value) = digit_value(ch).filter(|&value| value > 0) {
+            if digit.is_some() {
+                return Err(invalid);
+            }
+            digit = Some((value, index, ch));
+        } else if digit_value(ch).is_some() {
+            // A zero stands between the digits of a number
+            if last.is_none() || digit.is_some() || last.and_then(digit_value) == Some(0) {
+                return Err(invalid);
+            }
+            closed = true;
+        } else if let Some(unit) = unit_value(ch) {
+            if unit >= last_unit {
+                return Err(invalid);
+            }
+            let value = match digit.take() {
+                Some((_, index, ch)) if is_liang(ch) && unit == 10 => {
+                    return Err(NumeralError::InvalidSequence { ch, index })
+                }
+                Some((value, _, _)) => value,
+                None => 1,
+            };
+            group = checked(group.checked_add(value * unit))?;
+            last_unit = unit;
+            last_multiplier = unit;
+            closed = unit == 10;
+        } else if let Some(myriad) = myriad_value(ch, zhao) {
+            if myriad == 10_000 && wan.is_some() || myriad > 100_000_000 && myriad >= last_large {
+                return Err(invalid);
+            }
+            // 两 only stands before a myriad by itself, as in 两万
+            if let Some((_, index, ch)) = digit.filter(|&(_, _, ch)| is_liang(ch) && group > 0) {
+                return Err(NumeralError::InvalidSequence { ch, index });
+            }
+            let value = match digit.take() {
+                Some((_, index, ch)) if !closed => {
+                    return Err(NumeralError::InvalidSequence { ch, index })
+                }
+                Some((value, _, _)) => group + value,
+                None => group,
+            };
+            group = 0;
+            if myriad == 10_000 {
+                if value == 0 {
+                    return Err(invalid);
+                }
+                wan = Some(checked(value.checked_mul(10_000))?);
+            } else if myriad == 100_000_000 {
+                let value = checked(value.checked_add(wan.take().unwrap_or(0)))?;
+                if value == 0 && last.and_then(|ch| myriad_value(ch, zhao)) != Some(myriad) {
+                    return Err(invalid);
+                }
+                total = checked(
+                    total
+                        .checked_add(value)
+                        .and_then(|total| total.checked_mul(100_000_000)),
+                )?;
+            } else {
+                // 兆 and 京 multiply the groups since the larger one only
+                let value = checked(value.checked_add(wan.take().unwrap_or(0)))?;
+                let value = checked(value.checked_add(total))?;
+                if value == 0 {
+                    return Err(invalid);
+                }
+                high = checked(
+                    value
+                        .checked_mul(myriad)
+                        .and_then(|value| high.checked_add(value)),
+                )?;
+                total = 0;
+                last_large = myriad;
+            }
+            last_unit = 10_000;
+            last_multiplier = myriad;
+            closed = false;
+        } else if ch == '兆' {
+            // A 兆 standing for 10^6 can only end the numeral
+            return Err(invalid);
+        } else {
+            return Err(NumeralError::InvalidCharacter { ch, index });
+        }
+        last = Some(ch);
+    }
+
+    if let Some(ch) = last.filter(|&ch| digit_value(ch) == Some(0)) {
+        let index = input.chars().count() - 1;
+        return Err(NumeralError::InvalidSequence { ch, index });
+    }
+    // A final digit after a larger unit is the digit of the unit below
+    let value = match digit {
+        Some((_, index, ch)) if is_liang(ch) => {
+            return Err(NumeralError::InvalidSequence { ch, index })
+        }
+        Some((value, _, _)) if !closed => value * (last_multiplier / 10),
+        Some((value, _, _)) => value,
+        None => 0,
+    };
+    let value = checked(group.checked_add(value))?;
+    let value = checked(value.checked_add(wan.unwrap_or(0)))?;
+    let value = checked(value.checked_add(total))?;
+    checked(high.checked_add(value))
+}
+
+/// Whether a character is 两, in either script.
+fn is_liang(ch: char) -> bool {
+    ch == '两' || ch == '兩'
+}
+
+/// Find where a numeral differs from the one written by `to_chinese_with`
+/// for its value, in any set of numerals, with 两 read as 二.
+fn find_non_canonical(input: &str, value: u64, options: &ChineseOptions) -> Option<(usize, char)> {
+    let normalized: String = input
+        .chars()
+        .map(|ch| if is_liang(ch) { '二' } else { ch })
+        .collect();
+    let options = ChineseOptions {
+        zhao: Some(options.zhao_value()),
+        ..*options
+    };
+    let options = [
+        (Script::Simplified, false),
+        (Script::Traditional, false),
+        (Script::Simplified, true),
+        (Script::Traditional, true),
+    ]
+    .map(|(script, financial)| ChineseOptions {
+        script,
+        financial,
+        ..options
+    });
+    // The length of the longest prefix shared with a canonical numeral
+    let length = options
+        .iter()
+        .map(|options| {
+            let canonical = to_chinese_with(value, options);
+            normalized
+                .chars()
+                .zip(canonical.chars())
+                .take_while(|(a, b)| a == b)
+                .count()
+                + usize::from(canonical == normalized)
+        })
+        .max()?;
+    let count = input.chars().count();
+    if length > count {
+        return None;
+    }
+    let index = length.min(count - 1);
+    Some((index, input.chars().nth(index)?))
+}
+
+/// The value of a positional numeral, with one digit for each arabic digit.
+fn from_positional(input: &str) -> Result<u64, NumeralError> {
+    input.chars().try_fold(0u64, |value, ch| {
+        value
+            .checked_mul(10)
+            .and_then(|value| value.checked_add(cjk::digit_value(ch)?))
+            .ok_or(NumeralError::Overflow)
+    })
+}
+
+/// The chinese numeral system, for use through `NumeralSystem`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Chinese;
+
+impl NumeralSystem for Chinese {
+    fn name(&self) -> &'static str {
+        "chinese"
+    }
+
+    fn parse(&self, input: &str) -> Result<u64, NumeralError> {
+        to_arabic(input)
+    }
+
+    fn parse_with(&self, input: &str, options: &Options) -> Result<u64, NumeralError> {
+        to_arabic_with_strictness(input, options.strictness)
+    }
+
+    fn format(&self, value: u64, _options: &Options) -> Result<String, NumeralError> {
+        Ok(to_chinese(value))
+    }
+}
+
+#[cfg(test)]
+mod test_to_chinese {
+    use crate::chinese::to_chinese;
+
+    #[test]
+    fn test_small_values() {
+        assert_eq!(to_chinese(0), "零");
+        assert_eq!(to_chinese(1), "一");
+        assert_eq!(to_chinese(10), "十");
+        assert_eq!(to_chinese(11), "十一");
+        assert_eq!(to_chinese(20), "二十");
+        assert_eq!(to_chinese(100), "一百");
+        assert_eq!(to_chinese(110), "一百一十");
+        assert_eq!(to_chinese(101), "一百零一");
+        assert_eq!(to_chinese(1000), "一千");
+        assert_eq!(to_chinese(1001), "一千零一");
+        assert_eq!(to_chinese(1010), "一千零一十");
+        assert_eq!(to_chinese(1100), "一千一百");
+        assert_eq!(to_chinese(1994), "一千九百九十四");
+    }
+
+    #[test]
+    fn test_large_values() {
+        assert_eq!(to_chinese(10_000), "一万");
+        assert_eq!(to_chinese(100_000), "十万");
+        assert_eq!(to_chinese(100_001), "十万零一");
+        assert_eq!(to_chinese(110_000), "十一万");
+        assert_eq!(to_chinese(1_001_000), "一百万一千");
+        assert_eq!(to_chinese(1_000_100), "一百万零一百");
+        assert_eq!(to_chinese(100_000_000), "一亿");
+        assert_eq!(to_chinese(100_000_001), "一亿零一");
+        assert_eq!(to_chinese(101_000_000), "一亿零一百万");
+        assert_eq!(to_chinese(110_000_000), "一亿一千万");
+        assert_eq!(to_chinese(1_000_000_000_000), "一万亿");
+        assert_eq!(to_chinese(10_000_000_000_000_000), "一亿亿");
+        assert_eq!(
+            to_chinese(u64::MAX),
+            "一千八百四十四亿六千七百四十四万零七百三十七亿零九百五十五万一千六百一十五"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_traditional {
+    use crate::chinese::{to_arabic, to_arabic_with, to_chinese, to_chinese_with, ChineseOptions};
+
+    #[test]
+    fn test_to_chinese_with() {
+        let options = ChineseOptions::new().traditional();
+        assert_eq!(to_chinese_with(0, &options), "零");
+        assert_eq!(to_chinese_with(1994, &options), "一千九百九十四");
+        assert_eq!(to_chinese_with(10_010, &options), "一萬零一十");
+        assert_eq!(to_chinese_with(1_000_000_000_000, &options), "一兆");
+        assert_eq!(to_chinese_with(10_000_000_000_000_000, &options), "一京");
+    }
+
+    #[test]
+    fn test_to_arabic() {
+        let x = to_arabic("三億二千三百萬");
+        assert_eq!(x.unwrap(), 323_000_000);
+
+        let x = to_arabic("一億亿");
+        assert_eq!(x.unwrap(), 10_000_000_000_000_000);
+
+        let options = ChineseOptions::new().traditional();
+        for value in (0..=20_000).chain((0..64).map(|shift| 1u64 << shift)) {
+            assert_eq!(
+                to_arabic_with(&to_chinese_with(value, &options), &options),
+                Ok(value)
+            );
+            assert_eq!(to_arabic(&to_chinese(value)), Ok(value));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_large_numbers {
+    use crate::chinese::{to_arabic, to_arabic_with, to_chinese_with, ChineseOptions, Zhao};
+    use crate::{NumeralError, Strictness};
+
+    #[test]
+    fn test_wanwan() {
+        let options = ChineseOptions::new().wanwan();
+        assert_eq!(to_chinese_with(100_000_000, &options), "一万万");
+        assert_eq!(to_chinese_with(323_000_000, &options), "三万万二千三百万");
+        assert_eq!(to_chinese_with(300_050_000, &options), "三万万零五万");
+        assert_eq!(to_chinese_with(1_000_000_000_000, &options), "一万万万");
+        assert_eq!(
+            to_chinese_with(200_000_000, &options.traditional()),
+            "二萬萬"
+        );
+
+        let x = to_arabic("三万万二千三百万");
+        assert_eq!(x.unwrap(), 323_000_000);
+
+        let x = to_arabic("一万万万");
+        assert_eq!(x.unwrap(), 1_000_000_000_000);
+
+        let x = to_arabic("四万万");
+        assert_eq!(x.unwrap(), 400_000_000);
+
+        let x = to_arabic("二萬萬");
+        assert_eq!(x.unwrap(), 200_000_000);
+
+        for value in (0..64).map(|shift| 1u64 << shift) {
+            assert_eq!(to_arabic(&to_chinese_with(value, &options)), Ok(value));
+        }
+    }
+
+    #[test]
+    fn test_zhao() {
+        let options = ChineseOptions::new().zhao(Zhao::Trillion);
+        assert_eq!(to_chinese_with(1_000_000_000_000, &options), "一兆");
+        assert_eq!(to_chinese_with(3_200_000_000_000, &options), "三兆二千亿");
+        assert_eq!(to_chinese_with(1_000_000_000_001, &options), "一兆零一");
+        assert_eq!(
+            to_chinese_with(u64::MAX, &options.traditional()),
+            "一千八百四十四京六千七百四十四兆零七百三十七億零九百五十五萬一千六百一十五"
+        );
+
+        let options = ChineseOptions::new().traditional().zhao(Zhao::Million);
+        assert_eq!(to_chinese_with(1_000_000_000_000, &options), "一萬億");
+
+        let x = to_arabic("三百兆");
+        assert_eq!(x.unwrap(), 300_000_000);
+
+        let x = to_arabic("一兆");
+        assert_eq!(x.unwrap(), 1_000_000);
+
+        let options = ChineseOptions::new().traditional();
+        let x = to_arabic_with("三兆二千億", &options);
+        assert_eq!(x.unwrap(), 3_200_000_000_000);
+
+        let x = to_arabic_with("一京零一", &options);
+        assert_eq!(x.unwrap(), 10_000_000_000_000_001);
+
+        let x = to_arabic_with("一兆二", &options);
+        assert_eq!(x.unwrap(), 1_200_000_000_000);
+
+        for value in (0..64).map(|shift| 1u64 << shift) {
+            let options = ChineseOptions::new().zhao(Zhao::Trillion);
+            assert_eq!(
+                to_arabic_with(&to_chinese_with(value, &options), &options),
+                Ok(value)
+            );
+        }
+    }
+
+    #[test]
+    fn test_strict() {
+        let options = ChineseOptions::new().strictness(Strictness::Strict);
+        let x = to_arabic_with("三亿", &options);
+        assert_eq!(x.unwrap(), 300_000_000);
+
+        let x = to_arabic_with("三万万", &options.wanwan());
+        assert_eq!(x.unwrap(), 300_000_000);
+
+        let x = to_arabic_with("三万万", &options);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '万', index: 1
+            }
+        );
+
+        let x = to_arabic_with("三兆", &options.zhao(Zhao::Trillion));
+        assert_eq!(x.unwrap(), 3_000_000_000_000);
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let x = to_arabic("兆");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '兆', index: 0
+            }
+        );
+
+        let x = to_arabic("三兆二");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '兆', index: 1
+            }
+        );
+
+        let x = to_arabic("三京");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter {
+                ch: '京', index: 1
+            }
+        );
+
+        let options = ChineseOptions::new().zhao(Zhao::Trillion);
+        let x = to_arabic_with("三兆二京", &options);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '京', index: 3
+            }
+        );
+
+        let x = to_arabic_with("三兆四兆", &options);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '兆', index: 3
+            }
+        );
+
+        let x = to_arabic_with("三万万四万万", &options);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '四', index: 3
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_financial {
+    use crate::chinese::{to_arabic, to_arabic_with, to_chinese_with, ChineseOptions};
+
+    #[test]
+    fn test_to_chinese_with() {
+        let options = ChineseOptions::new().financial();
+        assert_eq!(to_chinese_with(0, &options), "零");
+        assert_eq!(to_chinese_with(15, &options), "壹拾伍");
+        assert_eq!(to_chinese_with(1994, &options), "壹仟玖佰玖拾肆");
+        assert_eq!(to_chinese_with(1001, &options), "壹仟零壹");
+        assert_eq!(to_chinese_with(236_000, &options), "贰拾叁万陆仟");
+        assert_eq!(to_chinese_with(100_000_000, &options), "壹亿");
+
+        let options = options.traditional();
+        assert_eq!(to_chinese_with(236_000, &options), "貳拾參萬陸仟");
+        assert_eq!(to_chinese_with(300_000_000, &options), "參億");
+    }
+
+    #[test]
+    fn test_to_arabic() {
+        let x = to_arabic("壹拾伍");
+        assert_eq!(x.unwrap(), 15);
+
+        let x = to_arabic("贰拾叁万陆仟");
+        assert_eq!(x.unwrap(), 236_000);
+
+        let x = to_arabic("貳拾參萬陸仟");
+        assert_eq!(x.unwrap(), 236_000);
+
+        let financial = ChineseOptions::new().financial();
+        for options in [financial, financial.traditional()].iter() {
+            for value in (0..=20_000).chain((0..64).map(|shift| 1u64 << shift)) {
+                assert_eq!(
+                    to_arabic_with(&to_chinese_with(value, options), options),
+                    Ok(value)
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_to_arabic {
+    use crate::chinese::{to_arabic, to_chinese};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_to_arabic() {
+        let x = to_arabic("一千九百九十四");
+        assert_eq!(x.unwrap(), 1994);
+
+        let x = to_arabic("十五");
+        assert_eq!(x.unwrap(), 15);
+
+        let x = to_arabic("一十五");
+        assert_eq!(x.unwrap(), 15);
+
+        let x = to_arabic("一万零一十");
+        assert_eq!(x.unwrap(), 10_010);
+
+        let x = to_arabic("三亿二千三百万");
+        assert_eq!(x.unwrap(), 323_000_000);
+
+        let x = to_arabic("零");
+        assert_eq!(x.unwrap(), 0);
+
+        let x = to_arabic("〇");
+        assert_eq!(x.unwrap(), 0);
+    }
+
+    #[test]
+    fn test_missing_zeros() {
+        // The zeros can be left out before a unit
+        let x = to_arabic("一千一十");
+        assert_eq!(x.unwrap(), 1010);
+
+        let x = to_arabic("一万一百");
+        assert_eq!(x.unwrap(), 10_100);
+    }
+
+    #[test]
+    fn test_positional() {
+        let x = to_arabic("二〇二四");
+        assert_eq!(x.unwrap(), 2024);
+
+        let x = to_arabic("二零二四");
+        assert_eq!(x.unwrap(), 2024);
+
+        let x = to_arabic("一八四四六七四四〇七三七〇九五五一六一六");
+        assert_eq!(x.unwrap_err(), NumeralError::Overflow);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let values = (0..=100_000).chain((0..64).map(|shift| 1u64 << shift));
+        for value in values.chain((0..20).map(|exponent| 10u64.pow(exponent) + 1)) {
+            assert_eq!(to_arabic(&to_chinese(value)), Ok(value));
+        }
+        assert_eq!(to_arabic(&to_chinese(u64::MAX)), Ok(u64::MAX));
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let x = to_arabic("");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = to_arabic("一千x");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: 'x', index: 2 }
+        );
+
+        let x = to_arabic("一千二万");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '二', index: 2
+            }
+        );
+
+        let x = to_arabic("一百零");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '零', index: 2
+            }
+        );
+
+        let x = to_arabic("一百零零一");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '零', index: 3
+            }
+        );
+
+        let x = to_arabic("十百");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '百', index: 1
+            }
+        );
+
+        let x = to_arabic("万");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '万', index: 0
+            }
+        );
+
+        let x = to_arabic("一万二万");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '万', index: 3
+            }
+        );
+
+        let x = to_arabic("二千亿亿亿");
+        assert_eq!(x.unwrap_err(), NumeralError::Overflow);
+    }
+}
+
+#[cfg(test)]
+mod test_quirks {
+    use crate::chinese::{to_arabic, to_arabic_with_strictness, to_chinese};
+    use crate::{NumeralError, Strictness};
+
+    #[test]
+    fn test_liang() {
+        let x = to_arabic("两千");
+        assert_eq!(x.unwrap(), 2000);
+
+        let x = to_arabic("两百二十二");
+        assert_eq!(x.unwrap(), 222);
+
+        let x = to_arabic("两万两千");
+        assert_eq!(x.unwrap(), 22_000);
+
+        let x = to_arabic("兩億");
+        assert_eq!(x.unwrap(), 200_000_000);
+
+        let x = to_arabic("两十");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '两', index: 0
+            }
+        );
+
+        let x = to_arabic("十两");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '两', index: 1
+            }
+        );
+
+        let x = to_arabic("两");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '两', index: 0
+            }
+        );
+
+        let x = to_arabic("一百零两");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '两', index: 3
+            }
+        );
+
+        let x = to_arabic("十两万");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '两', index: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_elision() {
+        let x = to_arabic("三万五");
+        assert_eq!(x.unwrap(), 35_000);
+
+        let x = to_arabic("一千一");
+        assert_eq!(x.unwrap(), 1100);
+
+        let x = to_arabic("三百五");
+        assert_eq!(x.unwrap(), 350);
+
+        let x = to_arabic("一亿二");
+        assert_eq!(x.unwrap(), 120_000_000);
+
+        let x = to_arabic("三万五千六");
+        assert_eq!(x.unwrap(), 35_600);
+
+        // After 十 or 零, the final digit stands for itself
+        let x = to_arabic("三十五");
+        assert_eq!(x.unwrap(), 35);
+
+        let x = to_arabic("三万零五");
+        assert_eq!(x.unwrap(), 30_005);
+    }
+
+    #[test]
+    fn test_strict() {
+        let x = to_arabic_with_strictness("一千零一十", Strictness::Strict);
+        assert_eq!(x.unwrap(), 1010);
+
+        let x = to_arabic_with_strictness("两万两千", Strictness::Strict);
+        assert_eq!(x.unwrap(), 22_000);
+
+        let x = to_arabic_with_strictness("壹拾伍", Strictness::Strict);
+        assert_eq!(x.unwrap(), 15);
+
+        let x = to_arabic_with_strictness("二〇二四", Strictness::Strict);
+        assert_eq!(x.unwrap(), 2024);
+
+        let x = to_arabic_with_strictness("一千一十", Strictness::Strict);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '一', index: 2
+            }
+        );
+
+        let x = to_arabic_with_strictness("三万五", Strictness::Strict);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '五', index: 2
+            }
+        );
+
+        let x = to_arabic_with_strictness("一十五", Strictness::Strict);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '一', index: 0
+            }
+        );
+
+        for value in 0..=20_000 {
+            let x = to_arabic_with_strictness(&to_chinese(value), Strictness::Strict);
+            assert_eq!(x.unwrap(), value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_pinyin {
+    use crate::chinese::{to_pinyin, to_pinyin_with, ChineseOptions, Zhao};
+
+    #[test]
+    fn test_to_pinyin() {
+        assert_eq!(to_pinyin(0), "líng");
+        assert_eq!(to_pinyin(2), "èr");
+        assert_eq!(to_pinyin(10), "shí");
+        assert_eq!(to_pinyin(1994), "yī qiān jiǔ bǎi jiǔ shí sì");
+        assert_eq!(to_pinyin(10_010), "yī wàn líng yī shí");
+        assert_eq!(to_pinyin(678), "liù bǎi qī shí bā");
+        assert_eq!(to_pinyin(300_000_000), "sān yì");
+        assert_eq!(to_pinyin(1_000_000_000_000), "yī wàn yì");
+    }
+
+    #[test]
+    fn test_to_pinyin_with() {
+        let options = ChineseOptions::new().financial().traditional();
+        assert_eq!(to_pinyin_with(1994, &options), "yī qiān jiǔ bǎi jiǔ shí sì");
+        assert_eq!(to_pinyin_with(15, &options), "yī shí wǔ");
+
+        let options = ChineseOptions::new().wanwan();
+        assert_eq!(to_pinyin_with(300_000_000, &options), "sān wàn wàn");
+
+        let options = ChineseOptions::new().zhao(Zhao::Trillion);
+        assert_eq!(to_pinyin_with(10_000_000_000_000_000, &options), "yī jīng");
+    }
+}
+
+#[cfg(test)]
+mod test_numeral_system {
+    use crate::chinese::Chinese;
+    use crate::{NumeralSystem, Options};
+
+    #[test]
+    fn test_numeral_system() {
+        assert_eq!(Chinese.name(), "chinese");
+        assert_eq!(Chinese.parse("一千九百九十四").unwrap(), 1994);
+        assert_eq!(
+            Chinese.format(1994, &Options::default()).unwrap(),
+            "一千九百九十四"
+        );
+    }
+}
@@ -0,0 +1,223 @@
+/*!
+Convert the counting rod numerals to arabic, and vice-versa.
+
+The counting rods were laid out on a board to compute, and were written the
+same way in the texts of chinese mathematics. Each digit is a number of rods,
+laid vertically for the units, as in 𝍢 for 3, and horizontally for the tens,
+as in 𝍫 for 30, the two alternating from one place to the next so that the
+digits stand apart: 1994 is written 𝍩𝍨𝍱𝍣.
+
+A zero was left as a blank place, and written 〇 in the later texts, which is
+selected with `Zero`. Both are parsed.
+*/
+
+use alloc::string::String;
+
+use crate::error::NumeralError;
+
+/// The vertical digits, for the units, hundreds and every other place, from
+/// 𝍠 to 𝍨.
+const UNITS: [char; 9] = ['𝍠', '𝍡', '𝍢', '𝍣', '𝍤', '𝍥', '𝍦', '𝍧', '𝍨'];
+
+/// The horizontal digits, for the tens, thousands and every other place, from
+/// 𝍩 to 𝍱.
+const TENS: [char; 9] = ['𝍩', '𝍪', '𝍫', '𝍬', '𝍭', '𝍮', '𝍯', '𝍰', '𝍱'];
+
+/// How zero is written.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Zero {
+    /// As 〇.
+    #[default]
+    Circle,
+    /// As a blank place, the ideographic space U+3000.
+    Blank,
+}
+
+impl Zero {
+    /// The character of zero.
+    fn character(self) -> char {
+        match self {
+            Zero::Circle => '〇',
+            Zero::Blank => '\u{3000}',
+        }
+    }
+}
+
+/// Write `input` in counting rods, with 〇 for zero.
+///
+///     use numerals::chinese::rods::to_rods;
+///
+///     assert_eq!(to_rods(3), "𝍢");
+///     assert_eq!(to_rods(1994), "𝍩𝍨𝍱𝍣");
+///     assert_eq!(to_rods(405), "𝍣〇𝍤");
+pub fn to_rods(input: u64) -> String {
+    to_rods_with(input, Zero::Circle)
+}
+
+/// Write `input` in counting rods, with zero written as asked.
+///
+///     use numerals::chinese::rods::{to_rods_with, Zero};
+///
+///     assert_eq!(to_rods_with(405, Zero::Blank), "𝍣\u{3000}𝍤");
+pub fn to_rods_with(input: u64, zero: Zero) -> String {
+    let mut places: u32 = 1;
+    let mut power = 1;
+    while power <= input / 10 {
+        power *= 10;
+        places += 1;
+    }
+
+    let mut ret = String::new();
+    for place in (0..places).rev() {
+        let digit = (input / power % 10) as usize;
+        ret.push(match digit {
+            0 => zero.character(),
+            digit if place.is_multiple_of(2) => UNITS[digit - 1],
+            digit => TENS[digit - 1],
+        });
+        power /= 10;
+    }
+    ret
+}
+
+/// Convert counting rods to their value.
+///
+/// The vertical and horizontal digits alternate from the units, which are
+/// vertical, and a zero is either 〇 or a blank place, written as a space.
+///
+///     use numerals::chinese::rods::from_rods;
+///
+///     assert_eq!(from_rods("𝍩𝍨𝍱𝍣"), Ok(1994));
+///     assert_eq!(from_rods("𝍣 𝍤"), Ok(405));
+///     assert!(from_rods("𝍠𝍠").is_err());
+pub fn from_rods(input: &str) -> Result<u64, NumeralError> {
+    if input.is_empty() {
+        return Err(NumeralError::Empty);
+    }
+
+    let count = input.chars().count();
+    let mut ret: u64 = 0;
+    for (index, ch) in input.chars().enumerate() {
+        // The units are vertical, and so is every other place above them
+        let vertical = (count - 1 - index).is_multiple_of(2);
+        let digit = if ch == '〇' || ch == ' ' || ch == '\u{3000}' {
+            0
+        } else if let Some(digit) = UNITS.iter().position(|&digit| digit == ch) {
+            if !vertical {
+                return Err(NumeralError::InvalidSequence { ch, index });
+            }
+            digit as u64 + 1
+        } else if let Some(digit) = TENS.iter().position(|&digit| digit == ch) {
+            if vertical {
+                return Err(NumeralError::InvalidSequence { ch, index });
+            }
+            digit as u64 + 1
+        } else {
+            return Err(NumeralError::InvalidCharacter { ch, index });
+        };
+        ret = ret
+            .checked_mul(10)
+            .and_then(|ret| ret.checked_add(digit))
+            .ok_or(NumeralError::Overflow)?;
+    }
+    Ok(ret)
+}
+
+#[cfg(test)]
+mod test_to_rods {
+    use crate::chinese::rods::{to_rods, to_rods_with, Zero};
+
+    #[test]
+    fn test_to_rods() {
+        assert_eq!(to_rods(0), "〇");
+        assert_eq!(to_rods(1), "𝍠");
+        assert_eq!(to_rods(9), "𝍨");
+        assert_eq!(to_rods(10), "𝍩〇");
+        assert_eq!(to_rods(11), "𝍩𝍠");
+        assert_eq!(to_rods(99), "𝍱𝍨");
+        assert_eq!(to_rods(100), "𝍠〇〇");
+        assert_eq!(to_rods(6728), "𝍮𝍦𝍪𝍧");
+        assert_eq!(to_rods(10_203), "𝍠〇𝍡〇𝍢");
+    }
+
+    #[test]
+    fn test_blank() {
+        assert_eq!(to_rods_with(0, Zero::Blank), "\u{3000}");
+        assert_eq!(to_rods_with(10, Zero::Blank), "𝍩\u{3000}");
+        assert_eq!(to_rods_with(6728, Zero::Blank), "𝍮𝍦𝍪𝍧");
+    }
+}
+
+#[cfg(test)]
+mod test_from_rods {
+    use crate::chinese::rods::{from_rods, to_rods, to_rods_with, Zero};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_from_rods() {
+        let x = from_rods("𝍮𝍦𝍪𝍧");
+        assert_eq!(x.unwrap(), 6728);
+
+        let x = from_rods("𝍩〇");
+        assert_eq!(x.unwrap(), 10);
+
+        let x = from_rods("𝍩 ");
+        assert_eq!(x.unwrap(), 10);
+
+        let x = from_rods("𝍠\u{3000}𝍡\u{3000}𝍢");
+        assert_eq!(x.unwrap(), 10_203);
+
+        let x = from_rods("〇");
+        assert_eq!(x.unwrap(), 0);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for value in (0..=20_000).chain((0..64).map(|shift| 1u64 << shift)) {
+            assert_eq!(from_rods(&to_rods(value)), Ok(value));
+            assert_eq!(from_rods(&to_rods_with(value, Zero::Blank)), Ok(value));
+        }
+        assert_eq!(from_rods(&to_rods(u64::MAX)), Ok(u64::MAX));
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let x = from_rods("");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = from_rods("𝍩");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '𝍩', index: 0
+            }
+        );
+
+        let x = from_rods("𝍠𝍠");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '𝍠', index: 0
+            }
+        );
+
+        let x = from_rods("𝍠𝍠〇");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '𝍠', index: 1
+            }
+        );
+
+        let x = from_rods("𝍩三");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter {
+                ch: '三', index: 1
+            }
+        );
+
+        let x = from_rods("𝍠〇〇〇〇〇〇〇〇〇〇〇〇〇〇〇〇〇〇〇〇");
+        assert_eq!(x.unwrap_err(), NumeralError::Overflow);
+    }
+}
@@ -0,0 +1,391 @@
+/*!
+Convert the Suzhou numerals, or 花码, to arabic, and vice-versa.
+
+The Suzhou numerals are still seen on the price boards of markets, and in the
+ledgers of old shops. They are written positionally, with 〇 for zero and
+〡 to 〩 for the digits one to nine, as in 〤〢〨 for 428.
+
+As the strokes of 〡, 〢 and 〣 run together, the digits one to three that
+follow one of them are written horizontally, as 一, 二 and 三, so that 11 is
+written 〡一 and 123 is written 〡二〣.
+
+On the price boards, the significant digits are written on a first row and the
+magnitude of the first one on a second row below, so that the digits 〤〢 over
+百 stand for 420. This layout is `Layout`.
+
+The tens 〸, 〹 and 〺 are parsed by themselves, for 10, 20 and 30.
+*/
+
+use alloc::string::String;
+use core::fmt;
+
+use crate::chinese::{to_arabic, to_chinese};
+use crate::error::NumeralError;
+
+/// The digits, from 〇 to 〩.
+const DIGITS: [char; 10] = ['〇', '〡', '〢', '〣', '〤', '〥', '〦', '〧', '〨', '〩'];
+
+/// The horizontal forms of the digits one to three.
+const HORIZONTAL: [char; 3] = ['一', '二', '三'];
+
+/// The tens, from 〸 to 〺.
+const TENS: [char; 3] = ['〸', '〹', '〺'];
+
+/// The positional layout of a price board: the significant digits, and below
+/// them the magnitude of the first one, as in 〤〢 over 百 for 420.
+///
+/// The magnitude of the units is left empty.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Layout {
+    /// The first row: the digits, without the trailing zeros.
+    pub digits: String,
+    /// The second row: the magnitude of the first digit, as in 百 or 十万.
+    pub magnitude: String,
+}
+
+impl fmt::Display for Layout {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.digits)?;
+        if !self.magnitude.is_empty() {
+            f.write_str("\n")?;
+            f.write_str(&self.magnitude)?;
+        }
+        Ok(())
+    }
+}
+
+/// The value of a digit, either vertical or horizontal.
+fn digit_value(ch: char) -> Option<u64> {
+    DIGITS
+        .iter()
+        .position(|&digit| digit == ch)
+        .or_else(|| {
+            HORIZONTAL
+                .iter()
+                .position(|&digit| digit == ch)
+                .map(|digit| digit + 1)
+        })
+        .map(|digit| digit as u64)
+}
+
+/// Write `input` in Suzhou numerals, one digit for each arabic digit.
+///
+///     use numerals::chinese::suzhou::to_suzhou;
+///
+///     assert_eq!(to_suzhou(428), "〤〢〨");
+///     assert_eq!(to_suzhou(1994), "〡〩〩〤");
+///     assert_eq!(to_suzhou(123), "〡二〣");
+///     assert_eq!(to_suzhou(2005), "〢〇〇〥");
+pub fn to_suzhou(input: u64) -> String {
+    let mut ret = String::new();
+    // Whether the last digit was a vertical one to three
+    let mut vertical = false;
+    let mut power = 1;
+    while power <= input / 10 {
+        power *= 10;
+    }
+    while power > 0 {
+        let digit = (input / power % 10) as usize;
+        if (1..=3).contains(&digit) && vertical {
+            ret.push(HORIZONTAL[digit - 1]);
+            vertical = false;
+        } else {
+            ret.push(DIGITS[digit]);
+            vertical = (1..=3).contains(&digit);
+        }
+        power /= 10;
+    }
+    ret
+}
+
+/// Write `input` in the positional layout of a price board.
+///
+///     use numerals::chinese::suzhou::to_suzhou_layout;
+///
+///     let layout = to_suzhou_layout(420);
+///     assert_eq!(layout.digits, "〤〢");
+///     assert_eq!(layout.magnitude, "百");
+///     assert_eq!(layout.to_string(), "〤〢\n百");
+///
+///     assert_eq!(to_suzhou_layout(35_000).magnitude, "万");
+///     assert_eq!(to_suzhou_layout(7).magnitude, "");
+pub fn to_suzhou_layout(input: u64) -> Layout {
+    let mut significant = input;
+    let mut exponent = 0;
+    while significant >= 10 {
+        significant /= 10;
+        exponent += 1;
+    }
+    let mut digits = input;
+    while digits > 0 && digits.is_multiple_of(10) {
+        digits /= 10;
+    }
+
+    // The magnitude is the numeral of the power of ten, without its 一
+    let magnitude = match exponent {
+        0 => String::new(),
+        exponent => to_chinese(10u64.pow(exponent))
+            .trim_start_matches('一')
+            .into(),
+    };
+    Layout {
+        digits: to_suzhou(digits),
+        magnitude,
+    }
+}
+
+/// Convert Suzhou numerals to their value.
+///
+/// The digits one to three are accepted either vertical or horizontal,
+/// wherever they are.
+///
+///     use numerals::chinese::suzhou::from_suzhou;
+///
+///     assert_eq!(from_suzhou("〤〢〨"), Ok(428));
+///     assert_eq!(from_suzhou("〡一"), Ok(11));
+///     assert_eq!(from_suzhou("〹"), Ok(20));
+///     assert!(from_suzhou("〡〸").is_err());
+pub fn from_suzhou(input: &str) -> Result<u64, NumeralError> {
+    if input.is_empty() {
+        return Err(NumeralError::Empty);
+    }
+
+    let mut ret: u64 = 0;
+    let mut chars = input.chars().enumerate().peekable();
+    while let Some((index, ch)) = chars.next() {
+        if let Some(tens) = TENS.iter().position(|&tens| tens == ch) {
+            // The tens stand by themselves only
+            if index > 0 || chars.peek().is_some() {
+                return Err(NumeralError::InvalidSequence { ch, index });
+            }
+            return Ok(10 * (tens as u64 + 1));
+        }
+        let digit = digit_value(ch).ok_or(NumeralError::InvalidCharacter { ch, index })?;
+        ret = ret
+            .checked_mul(10)
+            .and_then(|ret| ret.checked_add(digit))
+            .ok_or(NumeralError::Overflow)?;
+    }
+    Ok(ret)
+}
+
+/// Convert the two rows of a price board to their value: the digits, and
+/// the magnitude of the first one.
+///
+/// The magnitude is the numeral of a power of ten without its 一, as in 百 or
+/// 十万, or empty for the units. The errors in the magnitude are found at
+/// their index within it.
+///
+///     use numerals::chinese::suzhou::from_suzhou_layout;
+///
+///     assert_eq!(from_suzhou_layout("〤〢", "百"), Ok(420));
+///     assert_eq!(from_suzhou_layout("〣〥", "万"), Ok(35_000));
+///     assert_eq!(from_suzhou_layout("〧", ""), Ok(7));
+///     assert!(from_suzhou_layout("〤〢〨", "十").is_err());
+pub fn from_suzhou_layout(digits: &str, magnitude: &str) -> Result<u64, NumeralError> {
+    let value = from_suzhou(digits)?;
+    let exponent = match magnitude.chars().next() {
+        None => 0,
+        Some(first) => {
+            let mut numeral = String::from("一");
+            numeral.push_str(magnitude);
+            let power = to_arabic(&numeral).map_err(|error| match error {
+                NumeralError::InvalidCharacter { ch, index } => NumeralError::InvalidCharacter {
+                    ch,
+                    index: index.saturating_sub(1),
+                },
+                NumeralError::InvalidSequence { ch, index } => NumeralError::InvalidSequence {
+                    ch,
+                    index: index.saturating_sub(1),
+                },
+                error => error,
+            })?;
+            let exponent = power.ilog10();
+            if 10u64.pow(exponent) != power {
+                return Err(NumeralError::InvalidSequence {
+                    ch: first,
+                    index: 0,
+                });
+            }
+            exponent
+        }
+    };
+
+    // The first digit stands at the magnitude, and the others below it
+    let count = digits.chars().count() as u32;
+    let shift = (exponent + 1)
+        .checked_sub(count)
+        .ok_or_else(|| NumeralError::OutOfRange {
+            value,
+            min: 0,
+            max: 10u64
+                .checked_pow(exponent + 1)
+                .map_or(u64::MAX, |power| power - 1),
+        })?;
+    10u64
+        .checked_pow(shift)
+        .and_then(|power| value.checked_mul(power))
+        .ok_or(NumeralError::Overflow)
+}
+
+#[cfg(test)]
+mod test_to_suzhou {
+    use crate::chinese::suzhou::{to_suzhou, to_suzhou_layout, Layout};
+
+    #[test]
+    fn test_to_suzhou() {
+        assert_eq!(to_suzhou(0), "〇");
+        assert_eq!(to_suzhou(5), "〥");
+        assert_eq!(to_suzhou(10), "〡〇");
+        assert_eq!(to_suzhou(11), "〡一");
+        assert_eq!(to_suzhou(111), "〡一〡");
+        assert_eq!(to_suzhou(123), "〡二〣");
+        assert_eq!(to_suzhou(1203), "〡二〇〣");
+        assert_eq!(to_suzhou(4321), "〤〣二〡");
+        assert_eq!(
+            to_suzhou(u64::MAX),
+            "〡〨〤〤〦〧〤〤〇〧〣〧〇〩〥〥〡〦〡〥"
+        );
+    }
+
+    #[test]
+    fn test_to_suzhou_layout() {
+        let layout = to_suzhou_layout(420);
+        assert_eq!(
+            layout,
+            Layout {
+                digits: "〤〢".into(),
+                magnitude: "百".into()
+            }
+        );
+        assert_eq!(layout.to_string(), "〤〢\n百");
+
+        let layout = to_suzhou_layout(0);
+        assert_eq!(layout.to_string(), "〇");
+
+        let layout = to_suzhou_layout(10);
+        assert_eq!(layout.to_string(), "〡\n十");
+
+        let layout = to_suzhou_layout(305_000);
+        assert_eq!(layout.to_string(), "〣〇〥\n十万");
+
+        let layout = to_suzhou_layout(1_000_000_000_000);
+        assert_eq!(layout.to_string(), "〡\n万亿");
+    }
+}
+
+#[cfg(test)]
+mod test_from_suzhou {
+    use crate::chinese::suzhou::{from_suzhou, from_suzhou_layout, to_suzhou, to_suzhou_layout};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_from_suzhou() {
+        let x = from_suzhou("〤〢〨");
+        assert_eq!(x.unwrap(), 428);
+
+        let x = from_suzhou("〡二〣");
+        assert_eq!(x.unwrap(), 123);
+
+        let x = from_suzhou("〡〢〣");
+        assert_eq!(x.unwrap(), 123);
+
+        let x = from_suzhou("〇〇〥");
+        assert_eq!(x.unwrap(), 5);
+
+        let x = from_suzhou("〸");
+        assert_eq!(x.unwrap(), 10);
+
+        let x = from_suzhou("〺");
+        assert_eq!(x.unwrap(), 30);
+    }
+
+    #[test]
+    fn test_from_suzhou_layout() {
+        let x = from_suzhou_layout("〤〢", "百");
+        assert_eq!(x.unwrap(), 420);
+
+        let x = from_suzhou_layout("〣〇〥", "十万");
+        assert_eq!(x.unwrap(), 305_000);
+
+        let x = from_suzhou_layout("〣〇〥", "十萬");
+        assert_eq!(x.unwrap(), 305_000);
+
+        let x = from_suzhou_layout("〤〢", "十");
+        assert_eq!(x.unwrap(), 42);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for value in (0..=20_000).chain((0..64).map(|shift| 1u64 << shift)) {
+            assert_eq!(from_suzhou(&to_suzhou(value)), Ok(value));
+            let layout = to_suzhou_layout(value);
+            assert_eq!(
+                from_suzhou_layout(&layout.digits, &layout.magnitude),
+                Ok(value)
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let x = from_suzhou("");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = from_suzhou("〡四");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter {
+                ch: '四', index: 1
+            }
+        );
+
+        let x = from_suzhou("〸〡");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '〸', index: 0
+            }
+        );
+
+        let x = from_suzhou("〡〡〡〡〡〡〡〡〡〡〡〡〡〡〡〡〡〡〡〡〡");
+        assert_eq!(x.unwrap_err(), NumeralError::Overflow);
+
+        let x = from_suzhou_layout("〤〢〨", "十");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::OutOfRange {
+                value: 428,
+                min: 0,
+                max: 99
+            }
+        );
+
+        let x = from_suzhou_layout("〤", "三百");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '三', index: 0
+            }
+        );
+
+        let x = from_suzhou_layout("〤", "百十");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '百', index: 0
+            }
+        );
+
+        let x = from_suzhou_layout("〤", "元");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter {
+                ch: '元', index: 0
+            }
+        );
+
+        let x = from_suzhou_layout("〡", "亿亿亿");
+        assert_eq!(x.unwrap_err(), NumeralError::Overflow);
+    }
+}
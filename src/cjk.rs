@@ -0,0 +1,420 @@
+/*!
+The core shared by the chinese and japanese numerals, and the korean Hanja.
+
+They write the same digits, followed by the same units 十, 百 and 千, and
+only differ in their larger units and in how they write the zeros.
+
+The characters of the numerals differ from one set to the other, as in 万, 萬
+and 亿, or in the formal numerals written so that they cannot be tampered with,
+as in 壱, 壹 and 贰 for 1 and 2. `normalize` maps all of them onto the same
+characters, and `parse_cjk` parses a numeral of any of the sets, telling which
+one it was written in, for text whose origin is unknown.
+*/
+
+#[cfg(all(feature = "chinese", feature = "japanese"))]
+use crate::error::NumeralError;
+
+/// The digits, from 零 to 九.
+pub(crate) const DIGITS: [char; 10] = ['零', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+
+/// The units multiplying the digits, from the largest.
+pub(crate) const UNITS: [char; 3] = ['千', '百', '十'];
+
+/// The values of the units.
+pub(crate) const UNIT_VALUES: [u64; 3] = [1000, 100, 10];
+
+/// The value of a digit, zero being written 零 or 〇.
+pub(crate) fn digit_value(ch: char) -> Option<u64> {
+    match ch {
+        '〇' => Some(0),
+        _ => DIGITS
+            .iter()
+            .position(|&digit| digit == ch)
+            .map(|digit| digit as u64),
+    }
+}
+
+/// The value of a unit.
+pub(crate) fn unit_value(ch: char) -> Option<u64> {
+    UNITS
+        .iter()
+        .position(|&unit| unit == ch)
+        .map(|position| UNIT_VALUES[position])
+}
+
+/// The characters of the other sets, with the character they are normalized
+/// to.
+const VARIANTS: [(char, char); 32] = [
+    ('壱', '一'),
+    ('壹', '一'),
+    ('弌', '一'),
+    ('弐', '二'),
+    ('貳', '二'),
+    ('貮', '二'),
+    ('贰', '二'),
+    ('弍', '二'),
+    ('两', '二'),
+    ('兩', '二'),
+    ('参', '三'),
+    ('參', '三'),
+    ('叁', '三'),
+    ('弎', '三'),
+    ('肆', '四'),
+    ('伍', '五'),
+    ('陸', '六'),
+    ('陆', '六'),
+    ('漆', '七'),
+    ('柒', '七'),
+    ('捌', '八'),
+    ('玖', '九'),
+    ('拾', '十'),
+    ('佰', '百'),
+    ('陌', '百'),
+    ('仟', '千'),
+    ('阡', '千'),
+    ('萬', '万'),
+    ('亿', '億'),
+    ('万', '万'),
+    ('億', '億'),
+    ('京', '京'),
+];
+
+/// The characters found in all the sets of numerals.
+const SHARED: [char; 15] = [
+    '零', '〇', '一', '二', '三', '四', '五', '六', '七', '八', '九', '十', '百', '千', '兆',
+];
+
+/// A set of characters the numerals are written with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Variant {
+    /// The japanese numerals, as in 三億二千三百万.
+    Japanese,
+    /// The simplified chinese numerals, as in 三亿二千三百万.
+    Simplified,
+    /// The traditional chinese numerals, as in 三億二千三百萬.
+    Traditional,
+    /// The simplified chinese financial numerals, as in 叁亿贰仟叁佰万.
+    SimplifiedFinancial,
+    /// The traditional chinese financial numerals, as in 參億貳仟參佰萬.
+    TraditionalFinancial,
+    /// The japanese formal numerals, or daiji, as in 参億弐仟参佰萬.
+    Daiji,
+}
+
+/// The variants, in the order they are preferred when a numeral could be
+/// written in several of them.
+#[cfg(all(feature = "chinese", feature = "japanese"))]
+const PREFERRED: [Variant; 6] = [
+    Variant::Japanese,
+    Variant::Simplified,
+    Variant::Traditional,
+    Variant::SimplifiedFinancial,
+    Variant::TraditionalFinancial,
+    Variant::Daiji,
+];
+
+#[cfg(all(feature = "chinese", feature = "japanese"))]
+impl Variant {
+    /// The characters of the variant, besides the shared ones.
+    fn characters(self) -> &'static [char] {
+        match self {
+            Variant::Japanese => &['万', '億', '京'],
+            Variant::Simplified => &['万', '亿', '两'],
+            Variant::Traditional => &['萬', '億', '兩', '京'],
+            Variant::SimplifiedFinancial => &[
+                '壹', '贰', '叁', '肆', '伍', '陆', '柒', '捌', '玖', '拾', '佰', '仟', '万', '亿',
+            ],
+            Variant::TraditionalFinancial => &[
+                '壹', '貳', '參', '肆', '伍', '陸', '柒', '捌', '玖', '拾', '佰', '仟', '萬', '億',
+                '京',
+            ],
+            Variant::Daiji => &[
+                '壱', '弐', '参', '拾', '萬', '万', '億', '京', '壹', '貳', '貮', '參', '肆', '伍',
+                '陸', '漆', '柒', '捌', '玖', '佰', '仟', '陌', '阡',
+            ],
+        }
+    }
+
+    /// Whether a character is written in the variant.
+    fn contains(self, ch: char) -> bool {
+        SHARED.contains(&ch) || self.characters().contains(&ch)
+    }
+}
+
+/// The character a character of the numerals is normalized to, or `None` if
+/// it is not found in the numerals.
+fn normalized(ch: char) -> Option<char> {
+    if SHARED.contains(&ch) {
+        return Some(if ch == '〇' { '零' } else { ch });
+    }
+    VARIANTS
+        .iter()
+        .find(|&&(variant, _)| variant == ch)
+        .map(|&(_, normalized)| normalized)
+}
+
+/// Map the characters of the numerals in any of the sets onto the same ones:
+/// the digits 零 to 九, the units 十, 百 and 千, and the myriads 万, 億, 兆 and
+/// 京. The other characters are left as they are.
+///
+///     use numerals::cjk::normalize;
+///
+///     assert_eq!(normalize("贰仟零壹"), "二千零一");
+///     assert_eq!(normalize("壱萬"), "一万");
+///     assert_eq!(normalize("两亿"), "二億");
+pub fn normalize(input: &str) -> alloc::string::String {
+    input
+        .chars()
+        .map(|ch| normalized(ch).unwrap_or(ch))
+        .collect()
+}
+
+/// A numeral, as parsed by `parse_cjk`.
+#[cfg(all(feature = "chinese", feature = "japanese"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CjkValue {
+    pub value: u64,
+    /// The set of characters the numeral was written with.
+    pub variant: Variant,
+}
+
+/// Parse a numeral written with the characters of any of the sets, and tell
+/// which one.
+///
+/// The numeral is parsed following the rules of the first set written with
+/// all of its characters and accepting it, the sets being tried in the order
+/// of `Variant`. A numeral mixing several sets is not accepted, and neither
+/// are the digits of the financial numerals or daiji written one by one, as in
+/// 貳〇貳肆.
+///
+///     use numerals::cjk::{parse_cjk, Variant};
+///
+///     let x = parse_cjk("三億二千三百万").unwrap();
+///     assert_eq!((x.value, x.variant), (323_000_000, Variant::Japanese));
+///
+///     let x = parse_cjk("一千零一").unwrap();
+///     assert_eq!((x.value, x.variant), (1001, Variant::Simplified));
+///
+///     let x = parse_cjk("贰仟零壹").unwrap();
+///     assert_eq!((x.value, x.variant), (2001, Variant::SimplifiedFinancial));
+///
+///     assert!(parse_cjk("壱亿").is_err());
+#[cfg(all(feature = "chinese", feature = "japanese"))]
+pub fn parse_cjk(input: &str) -> Result<CjkValue, NumeralError> {
+    use crate::chinese::{self, ChineseOptions};
+    use crate::japanese::{self, Style};
+
+    if input.is_empty() {
+        return Err(NumeralError::Empty);
+    }
+    if let Some((index, ch)) = input
+        .chars()
+        .enumerate()
+        .find(|&(_, ch)| normalized(ch).is_none())
+    {
+        return Err(NumeralError::InvalidCharacter { ch, index });
+    }
+
+    let candidates = PREFERRED
+        .iter()
+        .filter(|variant| input.chars().all(|ch| variant.contains(ch)));
+    // The chinese numerals are parsed as they are, their parser knowing the
+    // rules of 两 and of the financial numerals
+    let numeral = normalize(input);
+    let mut first_error = None;
+    for &variant in candidates {
+        let value = match variant {
+            Variant::Japanese => japanese::to_arabic(&numeral),
+            Variant::Daiji => match japanese::to_arabic_with_style(&numeral) {
+                Ok(parsed) if parsed.style == Style::Positional => {
+                    let ch = input.chars().nth(1).unwrap_or_default();
+                    Err(NumeralError::InvalidSequence { ch, index: 1 })
+                }
+                parsed => parsed.map(|parsed| parsed.value),
+            },
+            Variant::Simplified | Variant::SimplifiedFinancial => {
+                chinese::to_arabic_with(input, &ChineseOptions::new())
+            }
+            Variant::Traditional | Variant::TraditionalFinancial => {
+                chinese::to_arabic_with(input, &ChineseOptions::new().traditional())
+            }
+        };
+        match value {
+            Ok(value) => return Ok(CjkValue { value, variant }),
+            Err(error) => {
+                first_error.get_or_insert(error);
+            }
+        }
+    }
+
+    Err(match first_error {
+        // The errors point at the characters of the input, not normalized
+        Some(NumeralError::InvalidCharacter { index, .. }) => NumeralError::InvalidCharacter {
+            ch: input.chars().nth(index).unwrap_or_default(),
+            index,
+        },
+        Some(NumeralError::InvalidSequence { index, .. }) => NumeralError::InvalidSequence {
+            ch: input.chars().nth(index).unwrap_or_default(),
+            index,
+        },
+        Some(error) => error,
+        None => {
+            // The numeral mixes several sets, and goes wrong where the longest
+            // written in one of them stops
+            let index = PREFERRED
+                .iter()
+                .map(|variant| input.chars().take_while(|&ch| variant.contains(ch)).count())
+                .max()
+                .unwrap_or(0);
+            let ch = input.chars().nth(index).unwrap_or_default();
+            NumeralError::InvalidSequence { ch, index }
+        }
+    })
+}
+
+#[cfg(test)]
+mod test_normalize {
+    use crate::cjk::normalize;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("一千九百九十四"), "一千九百九十四");
+        assert_eq!(normalize("二〇二四"), "二零二四");
+        assert_eq!(normalize("壹仟玖佰玖拾肆"), "一千九百九十四");
+        assert_eq!(normalize("參億貳仟參佰萬"), "三億二千三百万");
+        assert_eq!(normalize("叁亿贰仟叁佰万"), "三億二千三百万");
+        assert_eq!(normalize("参億弐仟参佰萬"), "三億二千三百万");
+        assert_eq!(normalize("两千"), "二千");
+        assert_eq!(normalize("三百円"), "三百円");
+    }
+}
+
+#[cfg(all(test, feature = "chinese", feature = "japanese"))]
+mod test_parse_cjk {
+    use crate::cjk::{parse_cjk, CjkValue, Variant};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_parse_cjk() {
+        let x = parse_cjk("千九百九十四");
+        assert_eq!(
+            x.unwrap(),
+            CjkValue {
+                value: 1994,
+                variant: Variant::Japanese
+            }
+        );
+
+        let x = parse_cjk("两万三");
+        assert_eq!(
+            x.unwrap(),
+            CjkValue {
+                value: 23_000,
+                variant: Variant::Simplified
+            }
+        );
+
+        let x = parse_cjk("三億二千三百萬");
+        assert_eq!(
+            x.unwrap(),
+            CjkValue {
+                value: 323_000_000,
+                variant: Variant::Traditional
+            }
+        );
+
+        let x = parse_cjk("一兆");
+        assert_eq!(
+            x.unwrap(),
+            CjkValue {
+                value: 1_000_000_000_000,
+                variant: Variant::Japanese
+            }
+        );
+
+        let x = parse_cjk("貳拾參萬陸仟");
+        assert_eq!(
+            x.unwrap(),
+            CjkValue {
+                value: 236_000,
+                variant: Variant::TraditionalFinancial
+            }
+        );
+
+        let x = parse_cjk("壱万弐千");
+        assert_eq!(
+            x.unwrap(),
+            CjkValue {
+                value: 12_000,
+                variant: Variant::Daiji
+            }
+        );
+
+        let x = parse_cjk("二〇二四");
+        assert_eq!(x.unwrap().value, 2024);
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let x = parse_cjk("");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = parse_cjk("三百円");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter {
+                ch: '円', index: 2
+            }
+        );
+
+        let x = parse_cjk("壱亿");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '亿', index: 1
+            }
+        );
+
+        let x = parse_cjk("两");
+        assert!(x.is_err());
+
+        let x = parse_cjk("十两");
+        assert!(x.is_err());
+
+        let x = parse_cjk("两十");
+        assert!(x.is_err());
+
+        let x = parse_cjk("一百零两");
+        assert!(x.is_err());
+
+        let x = parse_cjk("貳〇貳肆");
+        assert!(x.is_err());
+
+        let x = parse_cjk("两〇");
+        assert!(x.is_err());
+
+        let x = parse_cjk("百百");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '百', index: 1
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_cjk {
+    use crate::cjk::{digit_value, unit_value};
+
+    #[test]
+    fn test_values() {
+        assert_eq!(digit_value('〇'), Some(0));
+        assert_eq!(digit_value('零'), Some(0));
+        assert_eq!(digit_value('九'), Some(9));
+        assert_eq!(digit_value('十'), None);
+        assert_eq!(unit_value('十'), Some(10));
+        assert_eq!(unit_value('千'), Some(1000));
+        assert_eq!(unit_value('万'), None);
+    }
+}
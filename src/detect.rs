@@ -0,0 +1,247 @@
+/*!
+Guess the numeral system of an input.
+
+Each character of the input is classified by the script it is written in, and
+every numeral system compiled in the crate writing the input in its scripts is
+a guess, the more likely as it covers more of the input and as it parses it.
+The arabic numbers, which are not a numeral system of the registry, are guessed
+as well.
+*/
+
+use alloc::vec::Vec;
+
+use crate::arabic;
+use crate::registry::registry;
+
+/// A script the characters of the numerals are written in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Script {
+    /// The ascii, full-width or tibetan digits of the arabic numbers.
+    Arabic,
+    /// The ascii letters of the roman numerals, in either case.
+    RomanAscii,
+    /// The unicode roman numerals, from Ⅰ to ↈ.
+    RomanUnicode,
+    /// The chinese characters of the chinese, japanese and korean numerals.
+    Han,
+    /// The korean alphabet.
+    Hangul,
+    /// The greek letters of the greek numerals, and their keraia.
+    Greek,
+    /// The other latin letters, with or without diacritics, as in the
+    /// vietnamese number words.
+    Latin,
+}
+
+/// The scripts each system is written in, the main one first.
+const SYSTEMS: [(&str, &[Script]); 7] = [
+    ("arabic", &[Script::Arabic]),
+    ("chinese", &[Script::Han]),
+    ("greek", &[Script::Greek]),
+    ("japanese", &[Script::Han]),
+    ("korean", &[Script::Hangul, Script::Han]),
+    ("roman", &[Script::RomanAscii, Script::RomanUnicode]),
+    ("vietnamese", &[Script::Latin, Script::RomanAscii]),
+];
+
+/// A numeral system the input may be written in, found by `detect`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SystemGuess {
+    /// The name of the system, as in the registry, or "arabic".
+    pub system: &'static str,
+    /// The script most of the input is written in, among those of the system.
+    pub script: Script,
+    /// How likely the guess is, from 0 to 1.
+    ///
+    /// This is the share of the characters of the input written in the
+    /// scripts of the system, halved if the system does not parse the input.
+    pub confidence: f32,
+}
+
+/// The script a character is written in, if any.
+fn script(ch: char) -> Option<Script> {
+    match ch {
+        _ if arabic::digit_value(ch).is_some() => Some(Script::Arabic),
+        _ if arabic::tibetan_digit_value(ch).is_some() => Some(Script::Arabic),
+        'I' | 'V' | 'X' | 'L' | 'C' | 'D' | 'M' => Some(Script::RomanAscii),
+        'i' | 'v' | 'x' | 'l' | 'c' | 'd' | 'm' => Some(Script::RomanAscii),
+        '\u{2160}'..='\u{2188}' => Some(Script::RomanUnicode),
+        '〇' | '\u{3400}'..='\u{4dbf}' | '\u{4e00}'..='\u{9fff}' => Some(Script::Han),
+        '\u{ac00}'..='\u{d7a3}' => Some(Script::Hangul),
+        'ʹ' | '\u{370}'..='\u{3ff}' => Some(Script::Greek),
+        'a'..='z' | 'A'..='Z' => Some(Script::Latin),
+        '\u{c0}'..='\u{24f}' | '\u{1e00}'..='\u{1eff}' if ch.is_alphabetic() => Some(Script::Latin),
+        _ => None,
+    }
+}
+
+/// Guess the numeral systems `input` may be written in, the most likely first.
+///
+/// Only the systems compiled in the crate are guessed, and those with none of
+/// the characters of the input are left out. The white space is ignored, so
+/// that an empty input has no guesses.
+///
+///     # #[cfg(all(feature = "roman", feature = "korean"))] {
+///     use numerals::detect::{detect, Script};
+///
+///     let guesses = detect("MCMXCIV");
+///     assert_eq!(guesses[0].system, "roman");
+///     assert_eq!(guesses[0].script, Script::RomanAscii);
+///     assert_eq!(guesses[0].confidence, 1.0);
+///
+///     assert_eq!(detect("천구백구십사")[0].system, "korean");
+///     assert_eq!(detect("1994")[0].system, "arabic");
+///     # }
+pub fn detect(input: &str) -> Vec<SystemGuess> {
+    let scripts: Vec<Option<Script>> = input
+        .chars()
+        .filter(|ch| !ch.is_whitespace())
+        .map(script)
+        .collect();
+    if scripts.is_empty() {
+        return Vec::new();
+    }
+
+    let mut guesses = Vec::new();
+    for &(system, system_scripts) in SYSTEMS.iter() {
+        let parsed = match system {
+            "arabic" => arabic::parse(input).is_ok(),
+            _ => match registry().get(system) {
+                Some(numeral_system) => numeral_system.parse(input).is_ok(),
+                None => continue,
+            },
+        };
+
+        let count = |script: Script| scripts.iter().filter(|&&ch| ch == Some(script)).count();
+        let covered: usize = system_scripts.iter().map(|&script| count(script)).sum();
+        if covered == 0 {
+            continue;
+        }
+        // The first script wins a tie, being the main one of the system
+        let script = system_scripts
+            .iter()
+            .cloned()
+            .rev()
+            .max_by_key(|&script| count(script))
+            .unwrap_or(system_scripts[0]);
+
+        let coverage = covered as f32 / scripts.len() as f32;
+        guesses.push(SystemGuess {
+            system,
+            script,
+            confidence: if parsed { coverage } else { coverage / 2.0 },
+        });
+    }
+    // The sort is stable, so that the guesses tied stay in alphabetical order
+    guesses.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+    guesses
+}
+
+#[cfg(test)]
+mod test_detect {
+    use crate::detect::{detect, Script};
+
+    #[test]
+    fn test_empty() {
+        assert!(detect("").is_empty());
+        assert!(detect(" ").is_empty());
+        assert!(detect("?!").is_empty());
+
+        // The scripts of no numeral system are not guessed
+        assert!(detect("さんびゃく").is_empty());
+        assert!(detect("сто").is_empty());
+        assert!(detect("×").is_empty());
+    }
+
+    #[test]
+    fn test_arabic() {
+        let guesses = detect("1994");
+        assert_eq!(guesses.len(), 1);
+        assert_eq!(guesses[0].system, "arabic");
+        assert_eq!(guesses[0].script, Script::Arabic);
+        assert_eq!(guesses[0].confidence, 1.0);
+
+        let guesses = detect("１９９４");
+        assert_eq!(guesses[0].system, "arabic");
+        assert_eq!(guesses[0].confidence, 1.0);
+
+        let guesses = detect("༡༩༩༤");
+        assert_eq!(guesses[0].system, "arabic");
+        assert_eq!(guesses[0].confidence, 1.0);
+
+        let guesses = detect("19x4");
+        assert_eq!(guesses[0].system, "arabic");
+        assert_eq!(guesses[0].confidence, 0.375);
+    }
+
+    #[cfg(feature = "roman")]
+    #[test]
+    fn test_roman() {
+        let guesses = detect("MCMXCIV");
+        assert_eq!(guesses[0].system, "roman");
+        assert_eq!(guesses[0].script, Script::RomanAscii);
+        assert_eq!(guesses[0].confidence, 1.0);
+
+        let guesses = detect("ⅯⅭⅯⅩⅭⅣ");
+        assert_eq!(guesses.len(), 1);
+        assert_eq!(guesses[0].system, "roman");
+        assert_eq!(guesses[0].script, Script::RomanUnicode);
+        assert_eq!(guesses[0].confidence, 1.0);
+
+        // Mistyped numerals are still guessed, with less confidence
+        let guesses = detect("MCMXCIIIII");
+        assert_eq!(guesses[0].system, "roman");
+        assert_eq!(guesses[0].confidence, 0.5);
+    }
+
+    #[cfg(all(feature = "chinese", feature = "japanese", feature = "korean"))]
+    #[test]
+    fn test_han() {
+        // The three systems share the characters, and are all as likely
+        let guesses = detect("三億二千三百万");
+        let systems: Vec<&str> = guesses.iter().map(|guess| guess.system).collect();
+        assert_eq!(systems, vec!["chinese", "japanese", "korean"]);
+        assert!(guesses.iter().all(|guess| guess.script == Script::Han));
+        assert!(guesses.iter().all(|guess| guess.confidence == 1.0));
+
+        let guesses = detect("三百円");
+        assert_eq!(guesses.len(), 3);
+        assert!(guesses.iter().all(|guess| guess.confidence == 0.5));
+    }
+
+    #[cfg(feature = "korean")]
+    #[test]
+    fn test_hangul() {
+        let guesses = detect("삼억 이천삼백만");
+        assert_eq!(guesses.len(), 1);
+        assert_eq!(guesses[0].system, "korean");
+        assert_eq!(guesses[0].script, Script::Hangul);
+        assert_eq!(guesses[0].confidence, 1.0);
+    }
+
+    #[cfg(feature = "greek")]
+    #[test]
+    fn test_greek() {
+        let guesses = detect("͵αϡϙδʹ");
+        assert_eq!(guesses.len(), 1);
+        assert_eq!(guesses[0].system, "greek");
+        assert_eq!(guesses[0].script, Script::Greek);
+        assert_eq!(guesses[0].confidence, 1.0);
+    }
+
+    #[cfg(all(feature = "roman", feature = "vietnamese"))]
+    #[test]
+    fn test_latin() {
+        let guesses = detect("hai mươi mốt");
+        assert_eq!(guesses[0].system, "vietnamese");
+        assert_eq!(guesses[0].script, Script::Latin);
+        assert_eq!(guesses[0].confidence, 1.0);
+        assert_eq!(guesses[1].system, "roman");
+
+        let guesses = detect("XIV");
+        assert_eq!(guesses[0].system, "roman");
+        assert_eq!(guesses[1].system, "vietnamese");
+        assert_eq!(guesses[1].script, Script::RomanAscii);
+        assert_eq!(guesses[1].confidence, 0.5);
+    }
+}
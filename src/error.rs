@@ -0,0 +1,53 @@
+/*!
+The error returned by the conversions.
+*/
+
+use core::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NumeralError {
+    /// The input is empty.
+    Empty,
+    /// The input contains a character that is not a numeral.
+    /// `index` is the position of `ch` in the input, counted in characters.
+    InvalidCharacter { ch: char, index: usize },
+    /// The numerals are not arranged in a valid sequence.
+    /// `ch` is the numeral where the sequence was found invalid, and `index`
+    /// its position in the input, counted in characters.
+    InvalidSequence { ch: char, index: usize },
+    /// The value of the numerals overflows the arithmetic.
+    Overflow,
+    /// The value cannot be represented, as it is not within `min..=max`.
+    OutOfRange { value: u64, min: u64, max: u64 },
+    /// The value is negative, and there is no numeral for it.
+    Negative,
+    /// The numeral could not be written out.
+    Write,
+}
+
+impl From<fmt::Error> for NumeralError {
+    fn from(_: fmt::Error) -> NumeralError {
+        NumeralError::Write
+    }
+}
+
+impl fmt::Display for NumeralError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NumeralError::Empty => write!(f, "Invalid empty string"),
+            NumeralError::InvalidCharacter { .. } => write!(f, "Input contains invalid characters"),
+            NumeralError::InvalidSequence { .. } => write!(f, "Invalid sequence"),
+            NumeralError::Overflow => write!(f, "The value is too large"),
+            NumeralError::OutOfRange { value, min, max } => write!(
+                f,
+                "The value should be between {} and {} inclusive, not {}",
+                min, max, value
+            ),
+            NumeralError::Negative => write!(f, "Negative values have no numeral"),
+            NumeralError::Write => write!(f, "Could not write the numeral"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NumeralError {}
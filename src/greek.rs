@@ -0,0 +1,386 @@
+/*!
+Convert greek numerals to arabic, and vice-versa.
+
+The greek numerals, also called Ionic or alphabetic, write each digit with a
+letter of its own: α to θ for the units, ι to ϙ for the tens and ρ to ϡ for
+the hundreds, the letters being added as in ρκγ for 123. The alphabet is
+completed with three letters kept for the numerals: stigma ϛ for 6, koppa ϙ
+for 90 and sampi ϡ for 900.
+
+A numeral is told apart from a word by the keraia ʹ written after it, as in
+ρκγʹ, and the thousands are written with the units, following the lower left
+keraia ͵, as in ͵αϡϙδʹ for 1994. The numerals go up to 9999.
+*/
+
+use alloc::string::String;
+
+use crate::error::NumeralError;
+use crate::system::{NumeralSystem, Options, Strictness};
+
+/// The letters of the units, tens and hundreds, each from 1 to 9.
+const LETTERS: [[char; 9]; 3] = [
+    ['α', 'β', 'γ', 'δ', 'ε', 'ϛ', 'ζ', 'η', 'θ'],
+    ['ι', 'κ', 'λ', 'μ', 'ν', 'ξ', 'ο', 'π', 'ϙ'],
+    ['ρ', 'σ', 'τ', 'υ', 'φ', 'χ', 'ψ', 'ω', 'ϡ'],
+];
+
+/// The keraia, following a numeral.
+const KERAIA: char = 'ʹ';
+
+/// The lower left keraia, preceding the thousands.
+const LEFT_KERAIA: char = '͵';
+
+/// The largest value written with the numerals.
+const MAX: u64 = 9999;
+
+/// The digit and place of a letter, the units being at place 0.
+///
+/// The capital letters are accepted, as are the digamma ϝ and the final sigma ς
+/// for 6, and the archaic koppa ϟ for 90.
+fn letter_value(ch: char) -> Option<(u64, u32)> {
+    let ch = match ch {
+        'ϝ' | 'Ϝ' | 'Ϛ' | 'ς' => 'ϛ',
+        'ϟ' | 'Ϟ' | 'Ϙ' => 'ϙ',
+        'Ϡ' => 'ϡ',
+        ch => ch.to_lowercase().next().unwrap_or(ch),
+    };
+    LETTERS.iter().zip(0..).find_map(|(letters, place)| {
+        letters
+            .iter()
+            .position(|&letter| letter == ch)
+            .map(|digit| (digit as u64 + 1, place))
+    })
+}
+
+/// Write `input` in greek numerals, followed by the keraia.
+///
+///     use numerals::greek::to_greek;
+///
+///     assert_eq!(to_greek(123).unwrap(), "ρκγʹ");
+///     assert_eq!(to_greek(666).unwrap(), "χξϛʹ");
+///     assert_eq!(to_greek(1994).unwrap(), "͵αϡϙδʹ");
+///     assert!(to_greek(0).is_err());
+pub fn to_greek(input: u64) -> Result<String, NumeralError> {
+    if input == 0 || input > MAX {
+        return Err(NumeralError::OutOfRange {
+            value: input,
+            min: 1,
+            max: MAX,
+        });
+    }
+
+    let mut ret = String::new();
+    if input >= 1000 {
+        ret.push(LEFT_KERAIA);
+        ret.push(LETTERS[0][(input / 1000 - 1) as usize]);
+    }
+    for place in (0..3).rev() {
+        let digit = input / 10u64.pow(place) % 10;
+        if digit > 0 {
+            ret.push(LETTERS[place as usize][digit as usize - 1]);
+        }
+    }
+    ret.push(KERAIA);
+    Ok(ret)
+}
+
+/// Convert greek numerals to their value.
+///
+/// The letters are accepted in either case, with or without the keraia, which
+/// may also be written as the greek numeral sign ʹ or an apostrophe. Six is
+/// accepted as the digamma ϝ, as the final sigma ς often typed for the stigma,
+/// or as στ as in modern greek, besides the stigma.
+///
+///     use numerals::greek::to_arabic;
+///
+///     assert_eq!(to_arabic("ρκγʹ"), Ok(123));
+///     assert_eq!(to_arabic("͵αϡϙδʹ"), Ok(1994));
+///     assert_eq!(to_arabic("ΙΣΤ'"), Ok(16));
+///     assert!(to_arabic("κρ").is_err());
+pub fn to_arabic(input: &str) -> Result<u64, NumeralError> {
+    to_arabic_with_strictness(input, Strictness::Standard)
+}
+
+/// `to_arabic`, following the rules as closely as asked.
+///
+/// Strictly, only the numerals written as by `to_greek` are accepted.
+///
+///     use numerals::greek::to_arabic_with_strictness;
+///     use numerals::Strictness;
+///
+///     assert_eq!(to_arabic_with_strictness("χξϛʹ", Strictness::Strict), Ok(666));
+///     assert!(to_arabic_with_strictness("χξϛ", Strictness::Strict).is_err());
+pub fn to_arabic_with_strictness(input: &str, strictness: Strictness) -> Result<u64, NumeralError> {
+    if input.is_empty() {
+        return Err(NumeralError::Empty);
+    }
+
+    let value = from_letters(input)?;
+    if strictness == Strictness::Strict {
+        if let Some((index, ch)) = find_non_canonical(input, value) {
+            return Err(NumeralError::InvalidSequence { ch, index });
+        }
+    }
+    Ok(value)
+}
+
+/// Add up the letters of the numeral, from the thousands down to the units.
+fn from_letters(input: &str) -> Result<u64, NumeralError> {
+    let count = input.chars().count();
+    let mut value = 0;
+    // The place of the previous letter, the thousands being at place 3
+    let mut last: Option<u32> = None;
+    let mut chars = input.chars().enumerate().peekable();
+    while let Some((index, ch)) = chars.next() {
+        let (digit, place) = match ch {
+            KERAIA | '\u{374}' | '\'' if index + 1 == count && last.is_some() => break,
+            KERAIA | '\u{374}' | '\'' => return Err(NumeralError::InvalidSequence { ch, index }),
+            LEFT_KERAIA => match chars.next() {
+                Some((index, ch)) => match letter_value(ch) {
+                    Some((digit, 0)) => (digit, 3),
+                    _ => return Err(NumeralError::InvalidSequence { ch, index }),
+                },
+                None => return Err(NumeralError::InvalidSequence { ch, index }),
+            },
+            // στ is six, as in modern greek, σ being followed by τ in no numeral
+            'σ' | 'Σ' if matches!(chars.peek(), Some(&(_, 'τ')) | Some(&(_, 'Τ'))) => {
+                chars.next();
+                (6, 0)
+            }
+            ch => letter_value(ch).ok_or(NumeralError::InvalidCharacter { ch, index })?,
+        };
+        if last.is_some_and(|last| place >= last) {
+            return Err(NumeralError::InvalidSequence { ch, index });
+        }
+        last = Some(place);
+        value += digit * 10u64.pow(place);
+    }
+
+    if last.is_none() {
+        let ch = input.chars().next().unwrap_or_default();
+        return Err(NumeralError::InvalidSequence { ch, index: 0 });
+    }
+    Ok(value)
+}
+
+/// Find where the numeral differs from the one written by `to_greek` for its
+/// value.
+fn find_non_canonical(input: &str, value: u64) -> Option<(usize, char)> {
+    let canonical = to_greek(value).ok()?;
+    // The length of the prefix shared with the canonical numeral
+    let length = input
+        .chars()
+        .zip(canonical.chars())
+        .take_while(|(a, b)| a == b)
+        .count()
+        + usize::from(canonical == input);
+    let count = input.chars().count();
+    if length > count {
+        return None;
+    }
+    let index = length.min(count - 1);
+    Some((index, input.chars().nth(index)?))
+}
+
+/// The greek numeral system, for use through `NumeralSystem`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Greek;
+
+impl NumeralSystem for Greek {
+    fn name(&self) -> &'static str {
+        "greek"
+    }
+
+    fn parse(&self, input: &str) -> Result<u64, NumeralError> {
+        to_arabic(input)
+    }
+
+    fn parse_with(&self, input: &str, options: &Options) -> Result<u64, NumeralError> {
+        to_arabic_with_strictness(input, options.strictness)
+    }
+
+    fn format(&self, value: u64, _options: &Options) -> Result<String, NumeralError> {
+        to_greek(value)
+    }
+}
+
+#[cfg(test)]
+mod test_to_greek {
+    use crate::greek::to_greek;
+    use crate::NumeralError;
+
+    #[test]
+    fn test_to_greek() {
+        assert_eq!(to_greek(1).unwrap(), "αʹ");
+        assert_eq!(to_greek(6).unwrap(), "ϛʹ");
+        assert_eq!(to_greek(10).unwrap(), "ιʹ");
+        assert_eq!(to_greek(16).unwrap(), "ιϛʹ");
+        assert_eq!(to_greek(90).unwrap(), "ϙʹ");
+        assert_eq!(to_greek(99).unwrap(), "ϙθʹ");
+        assert_eq!(to_greek(100).unwrap(), "ρʹ");
+        assert_eq!(to_greek(318).unwrap(), "τιηʹ");
+        assert_eq!(to_greek(900).unwrap(), "ϡʹ");
+        assert_eq!(to_greek(1000).unwrap(), "͵αʹ");
+        assert_eq!(to_greek(1001).unwrap(), "͵ααʹ");
+        assert_eq!(to_greek(2024).unwrap(), "͵βκδʹ");
+        assert_eq!(to_greek(9999).unwrap(), "͵θϡϙθʹ");
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let x = to_greek(0);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::OutOfRange {
+                value: 0,
+                min: 1,
+                max: 9999
+            }
+        );
+
+        let x = to_greek(10_000);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::OutOfRange {
+                value: 10_000,
+                min: 1,
+                max: 9999
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_to_arabic {
+    use crate::greek::{to_arabic, to_arabic_with_strictness, to_greek};
+    use crate::{NumeralError, Strictness};
+
+    #[test]
+    fn test_to_arabic() {
+        let x = to_arabic("ρκγʹ");
+        assert_eq!(x.unwrap(), 123);
+
+        let x = to_arabic("ρκγ");
+        assert_eq!(x.unwrap(), 123);
+
+        let x = to_arabic("ρκγ\u{374}");
+        assert_eq!(x.unwrap(), 123);
+
+        let x = to_arabic("ΡΚΓ'");
+        assert_eq!(x.unwrap(), 123);
+
+        let x = to_arabic("͵αϡϙδʹ");
+        assert_eq!(x.unwrap(), 1994);
+
+        let x = to_arabic("͵α");
+        assert_eq!(x.unwrap(), 1000);
+    }
+
+    #[test]
+    fn test_variants() {
+        let x = to_arabic("χξϝ");
+        assert_eq!(x.unwrap(), 666);
+
+        let x = to_arabic("ΧΞϚ");
+        assert_eq!(x.unwrap(), 666);
+
+        let x = to_arabic("ις");
+        assert_eq!(x.unwrap(), 16);
+
+        let x = to_arabic("χξςʹ");
+        assert_eq!(x.unwrap(), 666);
+
+        let x = to_arabic("ιστʹ");
+        assert_eq!(x.unwrap(), 16);
+
+        let x = to_arabic("στ");
+        assert_eq!(x.unwrap(), 6);
+
+        let x = to_arabic("ϟδ");
+        assert_eq!(x.unwrap(), 94);
+
+        let x = to_arabic("Ϡ");
+        assert_eq!(x.unwrap(), 900);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for value in 1..=9999 {
+            let numeral = to_greek(value).unwrap();
+            assert_eq!(to_arabic(&numeral), Ok(value));
+            assert_eq!(
+                to_arabic_with_strictness(&numeral, Strictness::Strict),
+                Ok(value)
+            );
+        }
+    }
+
+    #[test]
+    fn test_strict() {
+        let x = to_arabic_with_strictness("ρκγ", Strictness::Strict);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'γ', index: 2 }
+        );
+
+        let x = to_arabic_with_strictness("ιστʹ", Strictness::Strict);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'σ', index: 1 }
+        );
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let x = to_arabic("");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = to_arabic("ʹ");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'ʹ', index: 0 }
+        );
+
+        let x = to_arabic("κρ");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'ρ', index: 1 }
+        );
+
+        let x = to_arabic("κλ");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'λ', index: 1 }
+        );
+
+        let x = to_arabic("ρʹκ");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'ʹ', index: 1 }
+        );
+
+        let x = to_arabic("͵ρ");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'ρ', index: 1 }
+        );
+
+        let x = to_arabic("α͵β");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: '͵', index: 1 }
+        );
+
+        let x = to_arabic("ισ");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'σ', index: 1 }
+        );
+
+        let x = to_arabic("ρβ!");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: '!', index: 2 }
+        );
+    }
+}
@@ -0,0 +1,2422 @@
+/*!
+Convert japanese numerals to arabic, and vice-versa.
+
+Japanese numerals are written with the kanji for the digits 一 to 九, each
+followed by the unit it multiplies: 十, 百 or 千. The digit 一 is left out
+before a unit, so that 1994 is written 千九百九十四.
+
+Larger values are grouped by myriads, each group of four digits being written
+as above and followed by its myriad: 万 for 10^4, 億 for 10^8, 兆 for 10^12 and
+京 for 10^16, as in 三億二千三百万 for 323,000,000. As for the units, 一 is left
+out before a myriad.
+
+Many style guides write 一 before 千 and the myriads all the same, as in
+一万一千百十一, which is selected with `ExplicitOne`. Either way is parsed.
+
+The myriads go on beyond 京, with 垓 for 10^20, 𥝱 for 10^24, 穣, 溝, 澗, 正, 載
+and 極 for 10^48. Values past `u64::MAX` are written and parsed as `u128` by
+`to_japanese_u128` and `to_arabic_u128`, up to the myriad 澗, for 10^36. The
+larger myriads, named with several kanji such as 恒河沙, are not supported.
+
+Zero is written 零, or 〇.
+
+The formal numerals, or daiji, written on banking and legal documents so that
+they cannot be tampered with, use other characters, as in 壱萬弐仟参佰.
+*/
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::ops::Range;
+
+use crate::arabic;
+use crate::cjk;
+use crate::error::NumeralError;
+use crate::system::{NumeralSystem, Options, Strictness};
+
+pub mod counter;
+pub mod era;
+pub mod fraction;
+pub mod ordinal;
+pub mod wari;
+
+/// The units multiplying the digits within a myriad group.
+const UNITS: [u128; 3] = [1000, 100, 10];
+
+/// The myriads multiplying the groups of four digits, as powers of ten.
+const MYRIADS: [u32; 12] = [48, 44, 40, 36, 32, 28, 24, 20, 16, 12, 8, 4];
+
+/// The characters of a style of numerals, in the order of the tables above.
+struct Characters {
+    digits: [char; 10],
+    units: [char; 3],
+    myriads: [char; 12],
+}
+
+const COMMON: Characters = Characters {
+    digits: cjk::DIGITS,
+    units: cjk::UNITS,
+    myriads: [
+        '極', '載', '正', '澗', '溝', '穣', '𥝱', '垓', '京', '兆', '億', '万',
+    ],
+};
+
+const DAIJI: Characters = Characters {
+    digits: ['零', '壱', '弐', '参', '肆', '伍', '陸', '漆', '捌', '玖'],
+    units: ['仟', '佰', '拾'],
+    myriads: [
+        '極', '載', '正', '澗', '溝', '穣', '𥝱', '垓', '京', '兆', '億', '萬',
+    ],
+};
+
+/// The value of a digit, zero included.
+///
+/// Besides the common numerals and the daiji, the older forms of the daiji
+/// found on former banknotes and deeds are accepted: 壹, 貳, 貮, 參 and 柒.
+fn digit_value(ch: char) -> Option<u64> {
+    match ch {
+        '壹' => Some(1),
+        '貳' | '貮' => Some(2),
+        '參' => Some(3),
+        '柒' => Some(7),
+        _ => cjk::digit_value(ch).or_else(|| {
+            let digit = DAIJI.digits.iter().position(|&digit| digit == ch)?;
+            Some(digit as u64)
+        }),
+    }
+}
+
+/// The value of a unit, also accepting the older forms 陌 and 阡.
+fn unit_value(ch: char) -> Option<u128> {
+    match ch {
+        '陌' => Some(100),
+        '阡' => Some(1000),
+        _ => cjk::unit_value(ch).map(u128::from).or_else(|| {
+            let position = DAIJI.units.iter().position(|&unit| unit == ch)?;
+            Some(UNITS[position])
+        }),
+    }
+}
+
+/// The value of the archaic shorthands for 二十, 三十 and 四十, which stand
+/// for a digit and the unit 十 at once.
+fn tens_value(ch: char) -> Option<u128> {
+    match ch {
+        '廿' => Some(20),
+        '卅' | '丗' => Some(30),
+        '卌' => Some(40),
+        _ => None,
+    }
+}
+
+/// The power of ten of a myriad, also accepting 秭 for 𥝱 and the older
+/// form 穰 of 穣.
+fn myriad_value(ch: char) -> Option<u32> {
+    let position = |myriads: &[char; 12]| myriads.iter().position(|&myriad| myriad == ch);
+    match ch {
+        '秭' => Some(24),
+        '穰' => Some(28),
+        _ => position(&COMMON.myriads)
+            .or_else(|| position(&DAIJI.myriads))
+            .map(|position| MYRIADS[position]),
+    }
+}
+
+/// The myriads whose value fits in a u128, with their characters.
+fn myriads(characters: &Characters) -> impl Iterator<Item = (u128, char)> + '_ {
+    MYRIADS
+        .iter()
+        .zip(characters.myriads.iter())
+        .filter_map(|(&exponent, &ch)| Some((10u128.checked_pow(exponent)?, ch)))
+}
+
+/// Write `input` as a japanese numeral.
+///
+///     use numerals::japanese::to_japanese;
+///
+///     assert_eq!(to_japanese(1994), "千九百九十四");
+///     assert_eq!(to_japanese(323_000_000), "三億二千三百万");
+///     assert_eq!(to_japanese(0), "零");
+pub fn to_japanese(input: u64) -> String {
+    write_myriads(u128::from(input), &COMMON, ExplicitOne::Never)
+}
+
+/// `to_japanese`, for values past `u64::MAX`.
+///
+///     use numerals::japanese::to_japanese_u128;
+///
+///     assert_eq!(to_japanese_u128(10u128.pow(20)), "垓");
+///     assert_eq!(to_japanese_u128(3 * 10u128.pow(36)), "三澗");
+pub fn to_japanese_u128(input: u128) -> String {
+    write_myriads(input, &COMMON, ExplicitOne::Never)
+}
+
+/// How the japanese numerals are written.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Style {
+    /// The everyday numerals, as in 千九百九十四.
+    #[default]
+    Common,
+    /// The formal numerals of banking and legal documents, as in
+    /// 壱仟玖佰玖拾肆, where 壱 is always written before the units.
+    Daiji,
+    /// The digits only, one for each arabic digit, as in 一九九四, which is
+    /// how years and phone numbers are written vertically.
+    Positional,
+    /// The arabic digits of each group followed by the kanji of its myriad,
+    /// as in 1億2345万, which is how most modern text writes numbers.
+    Mixed,
+}
+
+/// Where the digit 一 is written before a unit or a myriad it multiplies.
+///
+/// The daiji always have it, so that 壱 cannot be added in front.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ExplicitOne {
+    /// Never, as in 万千百十一.
+    #[default]
+    Never,
+    /// Before the myriads only, as in 一万千百十一.
+    Myriads,
+    /// Before the myriads and 千, as in 一万一千百十一, which most style
+    /// guides call for.
+    Thousands,
+    /// Before every unit and myriad, as in 一万一千一百一十一.
+    Always,
+}
+
+/// How zero is written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Zero {
+    /// 零, usually written for zero by itself.
+    Rei,
+    /// 〇, usually written for the zeros of positional numerals.
+    Circle,
+}
+
+impl Zero {
+    /// The character written for zero.
+    pub fn char(self) -> char {
+        match self {
+            Zero::Rei => '零',
+            Zero::Circle => '〇',
+        }
+    }
+}
+
+/// How negative values are marked.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Negative {
+    /// マイナス, as in マイナス五 for temperatures.
+    #[default]
+    Minus,
+    /// 負の, as in 負の五 in mathematics.
+    Fu,
+    /// A minus sign, as in -5万, in full width with the full-width digits.
+    Sign,
+}
+
+impl Negative {
+    /// The prefix written before a negative value.
+    pub fn prefix(self, fullwidth: bool) -> &'static str {
+        match self {
+            Negative::Minus => "マイナス",
+            Negative::Fu => "負の",
+            Negative::Sign if fullwidth => "－",
+            Negative::Sign => "-",
+        }
+    }
+}
+
+/// The options of `to_japanese_with`.
+///
+///     use numerals::japanese::{to_japanese_with, JapaneseOptions, Style};
+///
+///     let options = JapaneseOptions::new().daiji();
+///     assert_eq!(to_japanese_with(12_300, &options), "壱萬弐仟参佰");
+///
+///     let options = JapaneseOptions::new().style(Style::Mixed).rounded(1);
+///     assert_eq!(to_japanese_with(12_345, &options), "1.2万");
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct JapaneseOptions {
+    style: Style,
+    explicit_one: ExplicitOne,
+    zero: Option<Zero>,
+    decimals: Option<u32>,
+    separator: Option<char>,
+    fullwidth: bool,
+    negative: Negative,
+}
+
+impl JapaneseOptions {
+    /// The default options: the everyday numerals.
+    pub fn new() -> JapaneseOptions {
+        JapaneseOptions::default()
+    }
+
+    /// Write the numerals in the given style.
+    pub fn style(mut self, style: Style) -> JapaneseOptions {
+        self.style = style;
+        self
+    }
+
+    /// Write the formal numerals.
+    pub fn daiji(self) -> JapaneseOptions {
+        self.style(Style::Daiji)
+    }
+
+    /// In the common style, write 一 before the given units and myriads.
+    pub fn explicit_one(mut self, explicit_one: ExplicitOne) -> JapaneseOptions {
+        self.explicit_one = explicit_one;
+        self
+    }
+
+    /// Write zero with the given character, rather than with 零 by itself
+    /// and 〇 in positional numerals.
+    pub fn zero(mut self, zero: Zero) -> JapaneseOptions {
+        self.zero = Some(zero);
+        self
+    }
+
+    /// In the mixed style, write the value in its largest myriad only,
+    /// rounded to the given number of decimals, as in 1.2万 for 12,345.
+    /// Trailing zeros are left out, so that 120,000 is still written 12万.
+    pub fn rounded(mut self, decimals: u32) -> JapaneseOptions {
+        self.decimals = Some(decimals);
+        self
+    }
+
+    /// In the mixed style, separate the thousands of the arabic digits with
+    /// the given character, as in 1,234万.
+    pub fn separator(mut self, separator: Option<char>) -> JapaneseOptions {
+        self.separator = separator;
+        self
+    }
+
+    /// In the mixed style, write the arabic digits in full width, as in
+    /// １億２３４５万, to match the surrounding japanese text.
+    pub fn fullwidth(mut self, fullwidth: bool) -> JapaneseOptions {
+        self.fullwidth = fullwidth;
+        self
+    }
+
+    /// Mark the negative values written by `to_japanese_signed` with the
+    /// given prefix.
+    pub fn negative(mut self, negative: Negative) -> JapaneseOptions {
+        self.negative = negative;
+        self
+    }
+}
+
+/// `to_japanese`, following the options.
+pub fn to_japanese_with(input: u64, options: &JapaneseOptions) -> String {
+    let zero = |default: Zero| options.zero.unwrap_or(default).char();
+    match options.style {
+        Style::Common | Style::Daiji if input == 0 => zero(Zero::Rei).into(),
+        Style::Common => write_myriads(u128::from(input), &COMMON, options.explicit_one),
+        Style::Daiji => write_myriads(u128::from(input), &DAIJI, ExplicitOne::Always),
+        Style::Positional => write_positional(input, zero(Zero::Circle)),
+        Style::Mixed => {
+            let ret = match options.decimals {
+                Some(decimals) if input >= 10_000 => {
+                    write_rounded(input, decimals, options.separator)
+                }
+                _ => write_mixed(input, options.separator),
+            };
+            if options.fullwidth {
+                arabic::to_fullwidth(&ret)
+            } else {
+                ret
+            }
+        }
+    }
+}
+
+/// `to_japanese_with` for signed values, writing a prefix before the
+/// negative ones.
+///
+///     use numerals::japanese::{to_japanese_signed, JapaneseOptions, Negative};
+///
+///     let options = JapaneseOptions::new();
+///     assert_eq!(to_japanese_signed(-5, &options), "マイナス五");
+///     assert_eq!(to_japanese_signed(5, &options), "五");
+///
+///     let options = options.negative(Negative::Fu);
+///     assert_eq!(to_japanese_signed(-5, &options), "負の五");
+pub fn to_japanese_signed(input: i64, options: &JapaneseOptions) -> String {
+    let numeral = to_japanese_with(input.unsigned_abs(), options);
+    if input < 0 {
+        let fullwidth = options.fullwidth && options.style == Style::Mixed;
+        String::from(options.negative.prefix(fullwidth)) + &numeral
+    } else {
+        numeral
+    }
+}
+
+/// `to_japanese_u128` for signed values, writing the given prefix before
+/// the negative ones.
+///
+///     use numerals::japanese::{to_japanese_i128, Negative};
+///
+///     assert_eq!(to_japanese_i128(-3 * 10i128.pow(20), Negative::Minus), "マイナス三垓");
+pub fn to_japanese_i128(input: i128, negative: Negative) -> String {
+    let numeral = to_japanese_u128(input.unsigned_abs());
+    if input < 0 {
+        String::from(negative.prefix(false)) + &numeral
+    } else {
+        numeral
+    }
+}
+
+/// Write each digit of `input`, with the given character for zero.
+fn write_positional(input: u64, zero: char) -> String {
+    let mut digits = Vec::new();
+    let mut rest = input;
+    loop {
+        digits.push(match rest % 10 {
+            0 => zero,
+            digit => COMMON.digits[digit as usize],
+        });
+        rest /= 10;
+        if rest == 0 {
+            break;
+        }
+    }
+    digits.iter().rev().collect()
+}
+
+/// Write the groups of `input` in arabic digits, followed by their myriad.
+fn write_mixed(input: u64, separator: Option<char>) -> String {
+    if input == 0 {
+        return "0".into();
+    }
+
+    let mut ret = String::new();
+    let mut rest = u128::from(input);
+    for (myriad, ch) in myriads(&COMMON) {
+        if rest >= myriad {
+            write_digits(rest / myriad, separator, &mut ret);
+            ret.push(ch);
+            rest %= myriad;
+        }
+    }
+    if rest > 0 {
+        write_digits(rest, separator, &mut ret);
+    }
+    ret
+}
+
+/// Write `input` in its largest myriad, rounded to `decimals`.
+///
+/// The value is expected to be at least a myriad.
+fn write_rounded(input: u64, decimals: u32, separator: Option<char>) -> String {
+    // No myriad is smaller than 10^4, nor is any value larger than 10^20, so
+    // that further decimals are all zeros and the products fit in a u128
+    let decimals = decimals.min(16);
+    let scale = 10u128.pow(decimals);
+    let rounded = |myriad: u128| (u128::from(input) * scale + myriad / 2) / myriad;
+
+    // The largest myriad within the value, and the one above it
+    let mut larger = None;
+    let mut myriad = (10_000, COMMON.myriads[11]);
+    for (value, ch) in myriads(&COMMON) {
+        if u128::from(input) >= value {
+            myriad = (value, ch);
+            break;
+        }
+        larger = Some((value, ch));
+    }
+    let mut scaled = rounded(myriad.0);
+    // Rounding up to 10000 of a myriad carries over to the next one
+    if let Some(next) = larger.filter(|_| scaled >= 10_000 * scale) {
+        myriad = next;
+        scaled = rounded(myriad.0);
+    }
+
+    let mut ret = String::new();
+    write_digits(scaled / scale, separator, &mut ret);
+    let mut fraction = scaled % scale;
+    if fraction > 0 {
+        let mut digits = decimals;
+        while fraction.is_multiple_of(10) {
+            fraction /= 10;
+            digits -= 1;
+        }
+        ret.push('.');
+        for digit in (0..digits).rev() {
+            let digit = fraction / 10u128.pow(digit) % 10;
+            ret.push(char::from(b'0' + digit as u8));
+        }
+    }
+    ret.push(myriad.1);
+    ret
+}
+
+/// Write `input` in arabic digits, with `separator` between the thousands.
+fn write_digits(input: u128, separator: Option<char>, out: &mut String) {
+    let digits = input.to_string();
+    for (index, digit) in digits.chars().enumerate() {
+        if let Some(separator) = separator {
+            if index > 0 && (digits.len() - index).is_multiple_of(3) {
+                out.push(separator);
+            }
+        }
+        out.push(digit);
+    }
+}
+
+/// Write `input` grouped by myriads, with the given characters.
+fn write_myriads(input: u128, characters: &Characters, one: ExplicitOne) -> String {
+    if input == 0 {
+        return characters.digits[0].into();
+    }
+
+    let mut ret = String::new();
+    let mut rest = input;
+    for (myriad, ch) in myriads(characters) {
+        if rest >= myriad {
+            if rest / myriad > 1 || one != ExplicitOne::Never {
+                write_group(rest / myriad, characters, one, &mut ret);
+            }
+            ret.push(ch);
+            rest %= myriad;
+        }
+    }
+    write_group(rest, characters, one, &mut ret);
+    ret
+}
+
+/// Write a group of four digits.
+fn write_group(input: u128, characters: &Characters, one: ExplicitOne, out: &mut String) {
+    let mut rest = input;
+    for (&unit, &ch) in UNITS.iter().zip(characters.units.iter()) {
+        let digit = rest / unit;
+        let explicit = match one {
+            ExplicitOne::Always => true,
+            ExplicitOne::Thousands => unit == 1000,
+            ExplicitOne::Never | ExplicitOne::Myriads => false,
+        };
+        if digit > 1 || (digit == 1 && explicit) {
+            out.push(characters.digits[digit as usize]);
+        }
+        if digit > 0 {
+            out.push(ch);
+        }
+        rest %= unit;
+    }
+    if rest > 0 {
+        out.push(characters.digits[rest as usize]);
+    }
+}
+
+/// Convert a japanese numeral to its value.
+///
+/// The daiji are accepted as well as the common numerals, and can be mixed
+/// with them, as on handwritten receipts.
+/// The digit 一 before a unit or a myriad can be written or left out. The
+/// units within a group, and the myriads, should come in decreasing order,
+/// and each digit should be followed by a unit, short of the last one.
+///
+/// Several digits without any unit are read positionally, as in 二〇二四.
+///
+/// The archaic 廿, 卅 and 卌 are accepted for 二十, 三十 and 四十, as in 廿一.
+///
+/// Arabic digits can be written instead of the kanji, either for a digit or
+/// for a whole group, as in 3万5000 or 2千500, in ascii or in full width. A
+/// numeral should have a kanji, though, or it would be an arabic number.
+///
+///     use numerals::japanese::to_arabic;
+///
+///     assert_eq!(to_arabic("千九百九十四"), Ok(1994));
+///     assert_eq!(to_arabic("一万二千三百"), Ok(12_300));
+///     assert_eq!(to_arabic("〇"), Ok(0));
+///     assert_eq!(to_arabic("壱萬弐仟参佰"), Ok(12_300));
+///     assert_eq!(to_arabic("二〇二四"), Ok(2024));
+///     assert_eq!(to_arabic("1億2000万"), Ok(120_000_000));
+///     assert!(to_arabic("九十九十").is_err());
+pub fn to_arabic(input: &str) -> Result<u64, NumeralError> {
+    to_arabic_with_style(input).map(|parsed| parsed.value)
+}
+
+/// A value parsed by `to_arabic_with_style`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StyledValue {
+    pub value: u64,
+    /// The style the numeral was found to be written in.
+    pub style: Style,
+    /// Whether the archaic 廿, 卅 or 卌 were written.
+    pub archaic: bool,
+}
+
+/// `to_arabic`, also reporting the style of the numeral.
+///
+/// A numeral of several digits without any unit is positional, and one with
+/// any of the daiji is formal, unless it has arabic digits, in which case it
+/// is mixed. A single digit is taken as a common numeral.
+///
+///     use numerals::japanese::{to_arabic_with_style, Style};
+///
+///     assert_eq!(to_arabic_with_style("二〇二四").unwrap().style, Style::Positional);
+///     assert_eq!(to_arabic_with_style("二千二十四").unwrap().style, Style::Common);
+///     assert_eq!(to_arabic_with_style("弐千弐拾四").unwrap().style, Style::Daiji);
+///     assert_eq!(to_arabic_with_style("2千24").unwrap().style, Style::Mixed);
+pub fn to_arabic_with_style(input: &str) -> Result<StyledValue, NumeralError> {
+    let (value, style) = parse_styled(input)?;
+    let value = u64::try_from(value).map_err(|_| NumeralError::Overflow)?;
+    let archaic = input.chars().any(|ch| tens_value(ch).is_some());
+    Ok(StyledValue {
+        value,
+        style,
+        archaic,
+    })
+}
+
+/// `to_arabic`, for values past `u64::MAX`.
+///
+///     use numerals::japanese::to_arabic_u128;
+///
+///     assert_eq!(to_arabic_u128("一垓"), Ok(10u128.pow(20)));
+///     assert_eq!(to_arabic_u128("三澗"), Ok(3 * 10u128.pow(36)));
+///     assert!(to_arabic_u128("一正").is_err());
+pub fn to_arabic_u128(input: &str) -> Result<u128, NumeralError> {
+    parse_styled(input).map(|(value, _)| value)
+}
+
+/// The separators found between the groups of numerals in japanese text:
+/// commas, spaces and middle dots, in ascii or in full width.
+pub const SEPARATORS: [char; 7] = [',', '，', '、', ' ', '\u{3000}', '・', '･'];
+
+/// `to_arabic`, skipping the given separators wherever they are found, as
+/// in 1,234万.
+///
+///     use numerals::japanese::{to_arabic_with_separators, SEPARATORS};
+///
+///     assert_eq!(to_arabic_with_separators("１，２３４万", &SEPARATORS), Ok(12_340_000));
+///     assert_eq!(to_arabic_with_separators("三億　五千万", &SEPARATORS), Ok(350_000_000));
+///     assert!(to_arabic_with_separators("１，２３４万", &[' ']).is_err());
+pub fn to_arabic_with_separators(input: &str, separators: &[char]) -> Result<u64, NumeralError> {
+    // The numeral without its separators, and where each of its characters is
+    // found in the input
+    let mut numeral = String::new();
+    let mut indices = Vec::new();
+    for (index, ch) in input.chars().enumerate() {
+        if !separators.contains(&ch) {
+            numeral.push(ch);
+            indices.push(index);
+        }
+    }
+
+    to_arabic(&numeral).map_err(|error| match error {
+        NumeralError::InvalidCharacter { ch, index } => NumeralError::InvalidCharacter {
+            ch,
+            index: indices[index],
+        },
+        NumeralError::InvalidSequence { ch, index } => NumeralError::InvalidSequence {
+            ch,
+            index: indices[index],
+        },
+        error => error,
+    })
+}
+
+/// Parse a numeral in the style it is found to be written in.
+fn parse_styled(input: &str) -> Result<(u128, Style), NumeralError> {
+    if input.is_empty() {
+        return Err(NumeralError::Empty);
+    }
+
+    if input.chars().all(|ch| arabic::digit_value(ch).is_some()) {
+        let ch = input.chars().next().unwrap_or_default();
+        return Err(NumeralError::InvalidCharacter { ch, index: 0 });
+    }
+
+    let positional =
+        input.chars().nth(1).is_some() && input.chars().all(|ch| digit_value(ch).is_some());
+    let style = if positional {
+        Style::Positional
+    } else if input.chars().any(|ch| arabic::digit_value(ch).is_some()) {
+        Style::Mixed
+    } else if input.chars().any(is_daiji) {
+        Style::Daiji
+    } else {
+        Style::Common
+    };
+    let value = match style {
+        Style::Positional => from_positional(input)?,
+        _ => from_myriads(input)?,
+    };
+    Ok((value, style))
+}
+
+/// Whether a character is only found in the daiji.
+fn is_daiji(ch: char) -> bool {
+    ch != '零'
+        && (DAIJI.digits.contains(&ch)
+            || DAIJI.units.contains(&ch)
+            || DAIJI.myriads.contains(&ch) && !COMMON.myriads.contains(&ch)
+            || matches!(ch, '壹' | '貳' | '貮' | '參' | '柒' | '陌' | '阡'))
+}
+
+/// The value of a positional numeral, made of digits only.
+fn from_positional(input: &str) -> Result<u128, NumeralError> {
+    let mut value: u128 = 0;
+    for ch in input.chars() {
+        value = value
+            .checked_mul(10)
+            .and_then(|value| value.checked_add(u128::from(digit_value(ch)?)))
+            .ok_or(NumeralError::Overflow)?;
+    }
+    Ok(value)
+}
+
+/// The value of a numeral grouped by myriads.
+fn from_myriads(input: &str) -> Result<u128, NumeralError> {
+    if input.chars().count() == 1 && input.chars().all(|ch| digit_value(ch) == Some(0)) {
+        return Ok(0);
+    }
+
+    let mut total: u128 = 0;
+    // The value of the current myriad group, and its last unit
+    let mut group: u128 = 0;
+    let mut last_unit = u128::MAX;
+    let mut last_myriad = u32::MAX;
+    // The last digit, or the number written in arabic digits
+    let mut digit: Option<u128> = None;
+    let mut arabic = false;
+    for (index, ch) in input.chars().enumerate() {
+        let invalid = NumeralError::InvalidSequence { ch, index };
+        if let Some(value) = arabic::digit_value(ch).map(u128::from) {
+            digit = match digit {
+                None if value > 0 => Some(value),
+                Some(number) if arabic => Some(number * 10 + value),
+                _ => return Err(invalid),
+            };
+            arabic = true;
+            // Beyond a single digit, the number can only end the group, and
+            // should be less than its last unit
+            if matches!(digit, Some(number) if number > 9 && number >= last_unit.min(10_000)) {
+                return Err(invalid);
+            }
+        } else if let Some(value) = digit_value(ch) {
+            if value == 0 || digit.is_some() {
+                return Err(invalid);
+            }
+            digit = Some(u128::from(value));
+            arabic = false;
+        } else if let Some(unit) = unit_value(ch) {
+            if unit >= last_unit || matches!(digit, Some(number) if number > 9) {
+                return Err(invalid);
+            }
+            group += digit.take().unwrap_or(1) * unit;
+            last_unit = unit;
+        } else if let Some(tens) = tens_value(ch) {
+            if 10 >= last_unit || digit.is_some() {
+                return Err(invalid);
+            }
+            group += tens;
+            last_unit = 10;
+        } else if let Some(exponent) = myriad_value(ch) {
+            if exponent >= last_myriad {
+                return Err(invalid);
+            }
+            let myriad = 10u128.checked_pow(exponent).ok_or(NumeralError::Overflow)?;
+            group += digit.take().unwrap_or(0);
+            if group == 0 {
+                group = 1;
+            }
+            total = group
+                .checked_mul(myriad)
+                .and_then(|group| total.checked_add(group))
+                .ok_or(NumeralError::Overflow)?;
+            group = 0;
+            last_unit = u128::MAX;
+            last_myriad = exponent;
+        } else {
+            return Err(NumeralError::InvalidCharacter { ch, index });
+        }
+    }
+    group += digit.unwrap_or(0);
+    total.checked_add(group).ok_or(NumeralError::Overflow)
+}
+
+/// The readings of the digits, from 1 to 9.
+const DIGIT_READINGS: [&str; 10] = [
+    "",
+    "いち",
+    "に",
+    "さん",
+    "よん",
+    "ご",
+    "ろく",
+    "なな",
+    "はち",
+    "きゅう",
+];
+
+/// The readings of the myriads, in the order of `MYRIADS`.
+const MYRIAD_READINGS: [&str; 12] = [
+    "ごく",
+    "さい",
+    "せい",
+    "かん",
+    "こう",
+    "じょう",
+    "じょ",
+    "がい",
+    "けい",
+    "ちょう",
+    "おく",
+    "まん",
+];
+
+/// The readings of the digits, when read one by one as in phone numbers.
+///
+/// The single sounds of 2 and 5 are lengthened, and 4, 7 and 9 are given
+/// their readings that cannot be mistaken for others.
+const SPELLED_READINGS: [&str; 10] = [
+    "ゼロ",
+    "いち",
+    "にい",
+    "さん",
+    "よん",
+    "ごお",
+    "ろく",
+    "なな",
+    "はち",
+    "きゅう",
+];
+
+/// The hepburn romanization of the kana found in the readings.
+const ROMAJI: [(&str, &str); 32] = [
+    ("きゅ", "kyu"),
+    ("じゅ", "ju"),
+    ("じょ", "jo"),
+    ("ちょ", "cho"),
+    ("ひゃ", "hya"),
+    ("びゃ", "bya"),
+    ("ぴゃ", "pya"),
+    ("い", "i"),
+    ("う", "u"),
+    ("お", "o"),
+    ("か", "ka"),
+    ("が", "ga"),
+    ("き", "ki"),
+    ("く", "ku"),
+    ("け", "ke"),
+    ("こ", "ko"),
+    ("ご", "go"),
+    ("さ", "sa"),
+    ("じ", "ji"),
+    ("せ", "se"),
+    ("ぜ", "ze"),
+    ("ち", "chi"),
+    ("な", "na"),
+    ("に", "ni"),
+    ("は", "ha"),
+    ("ひ", "hi"),
+    ("ま", "ma"),
+    ("よ", "yo"),
+    ("ろ", "ro"),
+    ("れ", "re"),
+    ("ん", "n"),
+    ("っ", ""),
+];
+
+/// Write how `input` is read, in hiragana.
+///
+/// The sounds changing after some digits are followed, as in さんびゃく for
+/// 300 or いっちょう for 一兆. Unlike in the numerals, いち is always read
+/// before a myriad.
+///
+///     use numerals::japanese::to_reading;
+///
+///     assert_eq!(to_reading(1994), "せんきゅうひゃくきゅうじゅうよん");
+///     assert_eq!(to_reading(8600), "はっせんろっぴゃく");
+///     assert_eq!(to_reading(10_000), "いちまん");
+pub fn to_reading(input: u64) -> String {
+    if input == 0 {
+        return "ぜろ".into();
+    }
+
+    let mut ret = String::new();
+    let mut rest = u128::from(input);
+    let readings = MYRIADS.iter().zip(MYRIAD_READINGS.iter());
+    let readings =
+        readings.filter_map(|(&exponent, &reading)| Some((10u128.checked_pow(exponent)?, reading)));
+    for (myriad, reading) in readings {
+        if rest >= myriad {
+            write_group_reading(rest / myriad, &mut ret);
+            join_myriad_reading(&mut ret, reading);
+            rest %= myriad;
+        }
+    }
+    write_group_reading(rest, &mut ret);
+    ret
+}
+
+/// Write how `input` is read, in hepburn romanization.
+///
+///     use numerals::japanese::to_romaji;
+///
+///     assert_eq!(to_romaji(1994), "senkyūhyakukyūjūyon");
+///     assert_eq!(to_romaji(1_000_000_000_000), "itchō");
+pub fn to_romaji(input: u64) -> String {
+    let reading = to_reading(input);
+    let mut ret = String::new();
+    let mut rest = reading.as_str();
+    // Whether the last kana was a small tsu, doubling the next consonant,
+    // or a ん, to be set apart from a following vowel
+    let mut sokuon = false;
+    let mut moraic_n = false;
+    while let Some(&(kana, romaji)) = ROMAJI.iter().find(|(kana, _)| rest.starts_with(kana)) {
+        rest = &rest[kana.len()..];
+        if kana == "う" && (ret.ends_with('u') || ret.ends_with('o')) {
+            let long = if ret.pop() == Some('u') { 'ū' } else { 'ō' };
+            ret.push(long);
+        } else {
+            if sokuon {
+                ret.push(if romaji.starts_with("ch") {
+                    't'
+                } else {
+                    romaji.chars().next().unwrap_or_default()
+                });
+            }
+            if moraic_n && romaji.starts_with(['a', 'i', 'u', 'e', 'o', 'y']) {
+                ret.push('\'');
+            }
+            ret.push_str(romaji);
+        }
+        sokuon = kana == "っ";
+        moraic_n = kana == "ん";
+    }
+    ret
+}
+
+/// Write the arabic digits of `input` one by one in kanji, as in serial
+/// numbers, keeping the leading zeros. The hyphens and spaces grouping the
+/// digits are kept as well.
+///
+///     use numerals::japanese::to_digits;
+///
+///     assert_eq!(to_digits("0120"), Ok("〇一二〇".into()));
+///     assert_eq!(to_digits("２０２４"), Ok("二〇二四".into()));
+pub fn to_digits(input: &str) -> Result<String, NumeralError> {
+    spell(input, |digit, out| {
+        out.push(match digit {
+            0 => '〇',
+            digit => COMMON.digits[digit],
+        })
+    })
+}
+
+/// Write how the arabic digits of `input` are read one by one, as in phone
+/// numbers, keeping the leading zeros, the hyphens and the spaces.
+///
+///     use numerals::japanese::to_digit_reading;
+///
+///     assert_eq!(to_digit_reading("0120"), Ok("ゼロいちにいゼロ".into()));
+///     assert_eq!(to_digit_reading("4790"), Ok("よんななきゅうゼロ".into()));
+///     assert_eq!(to_digit_reading("03-1234"), Ok("ゼロさん-いちにいさんよん".into()));
+pub fn to_digit_reading(input: &str) -> Result<String, NumeralError> {
+    spell(input, |digit, out| out.push_str(SPELLED_READINGS[digit]))
+}
+
+/// The characters grouping the digits of phone and serial numbers.
+const DIGIT_SEPARATORS: [char; 5] = ['-', '‐', '－', ' ', '　'];
+
+/// Write each arabic digit of `input` with `write`, keeping the separators.
+fn spell(input: &str, write: impl Fn(usize, &mut String)) -> Result<String, NumeralError> {
+    if input.is_empty() {
+        return Err(NumeralError::Empty);
+    }
+
+    let mut ret = String::new();
+    for (index, ch) in input.chars().enumerate() {
+        if DIGIT_SEPARATORS.contains(&ch) {
+            ret.push(ch);
+            continue;
+        }
+        let digit = arabic::digit_value(ch).ok_or(NumeralError::InvalidCharacter { ch, index })?;
+        write(digit as usize, &mut ret);
+    }
+    Ok(ret)
+}
+
+/// The readings of the digits and units, with the kanji they stand for.
+///
+/// Besides the readings written by `to_reading`, the ones shortened by a
+/// following sound, as in いっ, and the older readings of 4, 7 and 9, as in
+/// しち, are accepted.
+const KANA_NUMERALS: [(&str, char); 27] = [
+    ("ぜろ", '零'),
+    ("れい", '零'),
+    ("いち", '一'),
+    ("いっ", '一'),
+    ("に", '二'),
+    ("さん", '三'),
+    ("よん", '四'),
+    ("し", '四'),
+    ("ご", '五'),
+    ("ろく", '六'),
+    ("ろっ", '六'),
+    ("なな", '七'),
+    ("しち", '七'),
+    ("はち", '八'),
+    ("はっ", '八'),
+    ("きゅう", '九'),
+    ("く", '九'),
+    ("じゅう", '十'),
+    ("じゅっ", '十'),
+    ("じっ", '十'),
+    ("ひゃく", '百'),
+    ("びゃく", '百'),
+    ("ぴゃく", '百'),
+    ("ひゃっ", '百'),
+    ("ぴゃっ", '百'),
+    ("せん", '千'),
+    ("ぜん", '千'),
+];
+
+/// Convert how a value is read, in hiragana or katakana, to the value.
+///
+/// The changes of sound are accepted as well as the regular readings, as in
+/// いっちょう and いちちょう. Spaces between the words are ignored.
+///
+///     use numerals::japanese::from_reading;
+///
+///     assert_eq!(from_reading("にせんにじゅうよん"), Ok(2024));
+///     assert_eq!(from_reading("ハッピャク"), Ok(800));
+///     assert_eq!(from_reading("さんぜんきゅうひゃくしちじゅう"), Ok(3970));
+pub fn from_reading(input: &str) -> Result<u64, NumeralError> {
+    let kana: String = input
+        .chars()
+        .map(|ch| match ch {
+            // The katakana are read as the matching hiragana, and their long
+            // vowels all stand for う in the readings
+            'ァ'..='ヶ' => char::from_u32(ch as u32 - 0x60).unwrap_or(ch),
+            'ー' => 'う',
+            _ => ch,
+        })
+        .collect();
+
+    // The kanji standing for the words, and where each word starts
+    let mut numeral = String::new();
+    let mut starts = Vec::new();
+    let mut rest = kana.as_str();
+    let mut index = 0;
+    while let Some(next) = rest.chars().next() {
+        if next.is_whitespace() {
+            rest = &rest[next.len_utf8()..];
+            index += 1;
+            continue;
+        }
+        let myriads = MYRIAD_READINGS.iter().zip(COMMON.myriads.iter());
+        let (reading, ch) = KANA_NUMERALS
+            .iter()
+            .copied()
+            .chain(myriads.map(|(&reading, &ch)| (reading, ch)))
+            .filter(|(reading, _)| rest.starts_with(reading))
+            .max_by_key(|(reading, _)| reading.len())
+            .ok_or(NumeralError::InvalidCharacter {
+                ch: input.chars().nth(index).unwrap_or_default(),
+                index,
+            })?;
+        numeral.push(ch);
+        starts.push(index);
+        rest = &rest[reading.len()..];
+        index += reading.chars().count();
+    }
+    if numeral.is_empty() {
+        return Err(NumeralError::Empty);
+    }
+
+    let value = from_myriads(&numeral).map_err(|error| match error {
+        NumeralError::InvalidSequence { index, .. } => {
+            let index = starts[index];
+            let ch = input.chars().nth(index).unwrap_or_default();
+            NumeralError::InvalidSequence { ch, index }
+        }
+        error => error,
+    })?;
+    u64::try_from(value).map_err(|_| NumeralError::Overflow)
+}
+
+/// Pair each kanji of `input` with its reading, for furigana.
+///
+/// The readings are the ones of `to_reading`, split between the kanji, so
+/// that the changes of sound go to the kanji they belong to. The いち read
+/// before a myriad goes to the myriad, as the 一 is not written.
+///
+///     use numerals::japanese::to_ruby;
+///
+///     assert_eq!(to_ruby(300), [('三', "さん".into()), ('百', "びゃく".into())]);
+///     assert_eq!(to_ruby(10_000), [('万', "いちまん".into())]);
+pub fn to_ruby(input: u64) -> Vec<(char, String)> {
+    let reading = to_reading(input);
+    let mut rest = reading.as_str();
+    // The longest reading of the kanji `ch` found at the start of `rest`
+    let find = |ch: char, rest: &str| {
+        let myriads = MYRIAD_READINGS.iter().zip(COMMON.myriads.iter());
+        KANA_NUMERALS
+            .iter()
+            .copied()
+            .chain(myriads.map(|(&reading, &ch)| (reading, ch)))
+            .filter(|&(reading, kanji)| kanji == ch && rest.starts_with(reading))
+            .map(|(reading, _)| reading)
+            .max_by_key(|reading| reading.len())
+            .unwrap_or_default()
+    };
+
+    let mut ret = Vec::new();
+    for ch in to_japanese(input).chars() {
+        let mut segment = String::new();
+        if myriad_value(ch).is_some() && find(ch, rest).is_empty() {
+            let one = find(COMMON.digits[1], rest);
+            segment.push_str(one);
+            rest = &rest[one.len()..];
+        }
+        let reading = find(ch, rest);
+        segment.push_str(reading);
+        rest = &rest[reading.len()..];
+        ret.push((ch, segment));
+    }
+    ret
+}
+
+/// Write `input` in kanji with its furigana, as html ruby markup.
+///
+///     use numerals::japanese::to_ruby_html;
+///
+///     assert_eq!(
+///         to_ruby_html(300),
+///         "<ruby>三<rt>さん</rt>百<rt>びゃく</rt></ruby>"
+///     );
+pub fn to_ruby_html(input: u64) -> String {
+    let mut ret = String::from("<ruby>");
+    for (ch, reading) in to_ruby(input) {
+        ret.push(ch);
+        ret.push_str("<rt>");
+        ret.push_str(&reading);
+        ret.push_str("</rt>");
+    }
+    ret.push_str("</ruby>");
+    ret
+}
+
+/// Write how a group of four digits is read.
+fn write_group_reading(input: u128, out: &mut String) {
+    let thousands = (input / 1000) as usize;
+    out.push_str(match thousands {
+        0 => "",
+        1 => "せん",
+        3 => "さんぜん",
+        8 => "はっせん",
+        _ => DIGIT_READINGS[thousands],
+    });
+    if thousands > 1 && thousands != 3 && thousands != 8 {
+        out.push_str("せん");
+    }
+
+    let hundreds = (input / 100 % 10) as usize;
+    out.push_str(match hundreds {
+        0 => "",
+        1 => "ひゃく",
+        3 => "さんびゃく",
+        6 => "ろっぴゃく",
+        8 => "はっぴゃく",
+        _ => DIGIT_READINGS[hundreds],
+    });
+    if hundreds > 1 && ![3, 6, 8].contains(&hundreds) {
+        out.push_str("ひゃく");
+    }
+
+    let tens = (input / 10 % 10) as usize;
+    if tens > 1 {
+        out.push_str(DIGIT_READINGS[tens]);
+    }
+    if tens > 0 {
+        out.push_str("じゅう");
+    }
+
+    out.push_str(DIGIT_READINGS[(input % 10) as usize]);
+}
+
+/// Add the reading of a myriad, shortening the end of the group read before
+/// it to a small tsu where the sound changes, as in はっちょう for 八兆.
+fn join_myriad_reading(out: &mut String, myriad: &str) {
+    let k_row = myriad.starts_with(['か', 'け', 'こ']);
+    let geminated = k_row || myriad.starts_with(['さ', 'せ', 'ち']);
+    let sokuon = geminated
+        && (out.ends_with("いち")
+            || out.ends_with("はち")
+            || out.ends_with("じゅう")
+            || k_row && (out.ends_with("ろく") || out.ends_with("ゃく")));
+    if sokuon {
+        out.pop();
+        out.push('っ');
+    }
+    out.push_str(myriad);
+}
+
+/// A japanese numeral found in a text by `find_all`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Match<'a> {
+    /// The position of the numeral in the text, in bytes.
+    pub range: Range<usize>,
+    pub value: u64,
+    pub text: &'a str,
+}
+
+/// Iterator over the japanese numerals of a text, created by `find_all`.
+#[derive(Clone, Debug)]
+pub struct Matches<'a> {
+    text: &'a str,
+    offset: usize,
+}
+
+impl<'a> Iterator for Matches<'a> {
+    type Item = Match<'a>;
+
+    fn next(&mut self) -> Option<Match<'a>> {
+        loop {
+            let rest = &self.text[self.offset..];
+            let start = self.offset + rest.find(is_numeral)?;
+            let end = self.text[start..]
+                .find(|ch: char| !is_numeral(ch))
+                .map_or(self.text.len(), |end| start + end);
+
+            let run = &self.text[start..end];
+            if run.chars().all(|ch| arabic::digit_value(ch).is_some()) {
+                self.offset = end;
+                continue;
+            }
+            // The longest numeral found at the start of the run, leaving out
+            // the myriads and daiji by themselves, which are mostly words
+            let found = run
+                .char_indices()
+                .map(|(index, ch)| start + index + ch.len_utf8())
+                .rev()
+                .filter(|&end| {
+                    let mut chars = self.text[start..end].chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(ch), None) => myriad_value(ch).is_none() && !is_daiji(ch),
+                        _ => true,
+                    }
+                })
+                .find_map(|end| Some((end, to_arabic(&self.text[start..end]).ok()?)));
+            match found {
+                Some((end, value)) => {
+                    self.offset = end;
+                    return Some(Match {
+                        range: start..end,
+                        value,
+                        text: &self.text[start..end],
+                    });
+                }
+                None => self.offset = start + run.chars().next()?.len_utf8(),
+            }
+        }
+    }
+}
+
+/// Whether a character can be part of a numeral.
+fn is_numeral(ch: char) -> bool {
+    digit_value(ch).is_some()
+        || unit_value(ch).is_some()
+        || myriad_value(ch).is_some()
+        || tens_value(ch).is_some()
+        || arabic::digit_value(ch).is_some()
+}
+
+/// Find the japanese numerals in a text.
+///
+/// Numerals are the longest runs of kanji, possibly mixed with arabic
+/// digits, that form a valid numeral. The numbers written in arabic digits
+/// only, and the myriads and daiji by themselves, as in 京都 or 参加, are left
+/// out. Note that words such as 一緒 or 万一 are found as numerals.
+///
+///     use numerals::japanese::find_all;
+///
+///     let values: Vec<u64> = find_all("第三章は二千二十四年、1億2000万円の話。")
+///         .map(|m| m.value)
+///         .collect();
+///     assert_eq!(values, vec![3, 2024, 120_000_000]);
+pub fn find_all(text: &str) -> Matches<'_> {
+    Matches { text, offset: 0 }
+}
+
+/// The japanese numeral system, for use through `NumeralSystem`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Japanese;
+
+impl NumeralSystem for Japanese {
+    fn name(&self) -> &'static str {
+        "japanese"
+    }
+
+    fn parse(&self, input: &str) -> Result<u64, NumeralError> {
+        to_arabic(input)
+    }
+
+    /// Separators are skipped when parsing leniently.
+    fn parse_with(&self, input: &str, options: &Options) -> Result<u64, NumeralError> {
+        match options.strictness {
+            Strictness::Lenient => to_arabic_with_separators(input, &SEPARATORS),
+            _ => to_arabic(input),
+        }
+    }
+
+    fn format(&self, value: u64, _options: &Options) -> Result<String, NumeralError> {
+        Ok(to_japanese(value))
+    }
+}
+
+#[cfg(test)]
+mod test_to_japanese {
+    use crate::japanese::to_japanese;
+
+    #[test]
+    fn test_small_values() {
+        assert_eq!(to_japanese(0), "零");
+        assert_eq!(to_japanese(1), "一");
+        assert_eq!(to_japanese(10), "十");
+        assert_eq!(to_japanese(11), "十一");
+        assert_eq!(to_japanese(20), "二十");
+        assert_eq!(to_japanese(105), "百五");
+        assert_eq!(to_japanese(1994), "千九百九十四");
+        assert_eq!(to_japanese(2024), "二千二十四");
+    }
+
+    #[test]
+    fn test_myriads() {
+        assert_eq!(to_japanese(10_000), "万");
+        assert_eq!(to_japanese(11_111), "万千百十一");
+        assert_eq!(to_japanese(12_300), "万二千三百");
+        assert_eq!(to_japanese(123_000_000), "億二千三百万");
+        assert_eq!(to_japanese(323_000_000), "三億二千三百万");
+        assert_eq!(to_japanese(100_000_001), "億一");
+        assert_eq!(to_japanese(3_000_000_000_000), "三兆");
+    }
+
+    #[test]
+    fn test_greatest_value() {
+        assert_eq!(
+            to_japanese(u64::MAX),
+            "千八百四十四京六千七百四十四兆七百三十七億九百五十五万千六百十五"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_to_japanese_with {
+    use crate::japanese::{to_arabic, to_japanese, to_japanese_with, JapaneseOptions, Style, Zero};
+
+    #[test]
+    fn test_common() {
+        let options = JapaneseOptions::new();
+        for &value in &[0, 1, 11_111, 1994, u64::MAX] {
+            assert_eq!(to_japanese_with(value, &options), to_japanese(value));
+        }
+    }
+
+    #[test]
+    fn test_daiji() {
+        let options = JapaneseOptions::new().daiji();
+        assert_eq!(to_japanese_with(0, &options), "零");
+        assert_eq!(to_japanese_with(1, &options), "壱");
+        assert_eq!(to_japanese_with(10, &options), "壱拾");
+        assert_eq!(to_japanese_with(1994, &options), "壱仟玖佰玖拾肆");
+        assert_eq!(to_japanese_with(11_111, &options), "壱萬壱仟壱佰壱拾壱");
+        assert_eq!(to_japanese_with(30_000, &options), "参萬");
+        assert_eq!(
+            to_japanese_with(123_456_789, &options),
+            "壱億弐仟参佰肆拾伍萬陸仟漆佰捌拾玖"
+        );
+        assert_eq!(
+            to_japanese_with(100_000_000, &JapaneseOptions::new().style(Style::Daiji)),
+            "壱億"
+        );
+    }
+
+    #[test]
+    fn test_positional() {
+        let options = JapaneseOptions::new().style(Style::Positional);
+        assert_eq!(to_japanese_with(0, &options), "〇");
+        assert_eq!(to_japanese_with(7, &options), "七");
+        assert_eq!(to_japanese_with(1994, &options), "一九九四");
+        assert_eq!(to_japanese_with(2024, &options), "二〇二四");
+        assert_eq!(to_japanese_with(1_000_000, &options), "一〇〇〇〇〇〇");
+        assert_eq!(
+            to_japanese_with(u64::MAX, &options),
+            "一八四四六七四四〇七三七〇九五五一六一五"
+        );
+    }
+
+    #[test]
+    fn test_zero() {
+        let options = JapaneseOptions::new().zero(Zero::Circle);
+        assert_eq!(to_japanese_with(0, &options), "〇");
+        assert_eq!(to_japanese_with(0, &options.daiji()), "〇");
+        assert_eq!(to_japanese_with(2024, &options), "二千二十四");
+
+        let options = JapaneseOptions::new()
+            .style(Style::Positional)
+            .zero(Zero::Rei);
+        assert_eq!(to_japanese_with(0, &options), "零");
+        assert_eq!(to_japanese_with(2024, &options), "二零二四");
+        assert_eq!(to_arabic(&to_japanese_with(2024, &options)), Ok(2024));
+
+        let options = JapaneseOptions::new()
+            .style(Style::Mixed)
+            .zero(Zero::Circle);
+        assert_eq!(to_japanese_with(0, &options), "0");
+    }
+}
+
+#[cfg(test)]
+mod test_signed {
+    use crate::japanese::{to_japanese_i128, to_japanese_signed, JapaneseOptions, Negative, Style};
+
+    #[test]
+    fn test_to_japanese_signed() {
+        let options = JapaneseOptions::new();
+        assert_eq!(to_japanese_signed(-5, &options), "マイナス五");
+        assert_eq!(to_japanese_signed(0, &options), "零");
+        assert_eq!(to_japanese_signed(1994, &options), "千九百九十四");
+        assert_eq!(
+            to_japanese_signed(i64::MIN, &options),
+            "マイナス九百二十二京三千三百七十二兆三百六十八億五千四百七十七万五千八百八"
+        );
+
+        let options = options.negative(Negative::Fu);
+        assert_eq!(to_japanese_signed(-12, &options), "負の十二");
+
+        let options = JapaneseOptions::new()
+            .style(Style::Mixed)
+            .negative(Negative::Sign);
+        assert_eq!(to_japanese_signed(-120_000, &options), "-12万");
+        assert_eq!(
+            to_japanese_signed(-120_000, &options.fullwidth(true)),
+            "－１２万"
+        );
+
+        let options = JapaneseOptions::new().daiji();
+        assert_eq!(to_japanese_signed(-20, &options), "マイナス弐拾");
+    }
+
+    #[test]
+    fn test_to_japanese_i128() {
+        assert_eq!(to_japanese_i128(-5, Negative::Minus), "マイナス五");
+        assert_eq!(to_japanese_i128(5, Negative::Minus), "五");
+        assert_eq!(to_japanese_i128(-10i128.pow(20), Negative::Fu), "負の垓");
+        assert_eq!(to_japanese_i128(-1, Negative::Sign), "-一");
+    }
+}
+
+#[cfg(test)]
+mod test_explicit_one {
+    use crate::japanese::{to_arabic, to_japanese_with, ExplicitOne, JapaneseOptions};
+
+    fn write(input: u64, explicit_one: ExplicitOne) -> String {
+        to_japanese_with(input, &JapaneseOptions::new().explicit_one(explicit_one))
+    }
+
+    #[test]
+    fn test_explicit_one() {
+        assert_eq!(write(11_111, ExplicitOne::Never), "万千百十一");
+        assert_eq!(write(11_111, ExplicitOne::Myriads), "一万千百十一");
+        assert_eq!(write(11_111, ExplicitOne::Thousands), "一万一千百十一");
+        assert_eq!(write(11_111, ExplicitOne::Always), "一万一千一百一十一");
+
+        assert_eq!(write(10_000_000, ExplicitOne::Myriads), "千万");
+        assert_eq!(write(10_000_000, ExplicitOne::Thousands), "一千万");
+        assert_eq!(write(100_010_000, ExplicitOne::Myriads), "一億一万");
+        assert_eq!(write(1, ExplicitOne::Always), "一");
+        assert_eq!(write(0, ExplicitOne::Always), "零");
+    }
+
+    #[test]
+    fn test_daiji() {
+        let options = JapaneseOptions::new()
+            .daiji()
+            .explicit_one(ExplicitOne::Never);
+        assert_eq!(to_japanese_with(11_111, &options), "壱萬壱仟壱佰壱拾壱");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let styles = [
+            ExplicitOne::Never,
+            ExplicitOne::Myriads,
+            ExplicitOne::Thousands,
+            ExplicitOne::Always,
+        ];
+        for &explicit_one in &styles {
+            let values = (0..=20_000).chain((14..64).map(|shift| 1u64 << shift));
+            for value in values.chain(Some(u64::MAX)) {
+                assert_eq!(to_arabic(&write(value, explicit_one)), Ok(value));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_to_reading {
+    use crate::japanese::{to_reading, to_romaji, DIGIT_READINGS, MYRIAD_READINGS, ROMAJI};
+
+    #[test]
+    fn test_to_reading() {
+        assert_eq!(to_reading(0), "ぜろ");
+        assert_eq!(to_reading(4), "よん");
+        assert_eq!(to_reading(7), "なな");
+        assert_eq!(to_reading(10), "じゅう");
+        assert_eq!(to_reading(11), "じゅういち");
+        assert_eq!(to_reading(49), "よんじゅうきゅう");
+        assert_eq!(to_reading(100), "ひゃく");
+        assert_eq!(to_reading(1000), "せん");
+        assert_eq!(to_reading(1994), "せんきゅうひゃくきゅうじゅうよん");
+        assert_eq!(to_reading(2024), "にせんにじゅうよん");
+        assert_eq!(to_reading(20_000), "にまん");
+        assert_eq!(to_reading(10_000_000), "せんまん");
+        assert_eq!(to_reading(100_000_000), "いちおく");
+    }
+
+    #[test]
+    fn test_irregular_readings() {
+        assert_eq!(to_reading(300), "さんびゃく");
+        assert_eq!(to_reading(600), "ろっぴゃく");
+        assert_eq!(to_reading(800), "はっぴゃく");
+        assert_eq!(to_reading(3000), "さんぜん");
+        assert_eq!(to_reading(8000), "はっせん");
+        assert_eq!(to_reading(3_800_600), "さんびゃくはちじゅうまんろっぴゃく");
+    }
+
+    #[test]
+    fn test_myriad_sound_changes() {
+        let trillion = 1_000_000_000_000;
+        assert_eq!(to_reading(trillion), "いっちょう");
+        assert_eq!(to_reading(6 * trillion), "ろくちょう");
+        assert_eq!(to_reading(8 * trillion), "はっちょう");
+        assert_eq!(to_reading(10 * trillion), "じゅっちょう");
+        assert_eq!(to_reading(100 * trillion), "ひゃくちょう");
+
+        let quadrillion = 10_000_000_000_000_000;
+        assert_eq!(to_reading(quadrillion), "いっけい");
+        assert_eq!(to_reading(6 * quadrillion), "ろっけい");
+        assert_eq!(to_reading(8 * quadrillion), "はっけい");
+        assert_eq!(to_reading(10 * quadrillion), "じゅっけい");
+        assert_eq!(to_reading(100 * quadrillion), "ひゃっけい");
+        assert_eq!(to_reading(1000 * quadrillion), "せんけい");
+
+        assert_eq!(to_reading(10_000), "いちまん");
+        assert_eq!(to_reading(80_000), "はちまん");
+    }
+
+    #[test]
+    fn test_to_romaji() {
+        assert_eq!(to_romaji(0), "zero");
+        assert_eq!(to_romaji(1994), "senkyūhyakukyūjūyon");
+        assert_eq!(to_romaji(600), "roppyaku");
+        assert_eq!(to_romaji(300), "sanbyaku");
+        assert_eq!(to_romaji(8000), "hassen");
+        assert_eq!(to_romaji(1004), "sen'yon");
+        assert_eq!(to_romaji(300_000_000), "san'oku");
+        assert_eq!(to_romaji(1_000_000_000_000), "itchō");
+        assert_eq!(to_romaji(10_000_000_000_000), "jutchō");
+        assert_eq!(to_romaji(10_000_000_000_000_000), "ikkei");
+        assert_eq!(to_romaji(70_000), "nanaman");
+    }
+
+    #[test]
+    fn test_every_kana_romanized() {
+        let readings = DIGIT_READINGS.iter().chain(MYRIAD_READINGS.iter());
+        let values = (0..=20_000).chain((14..64).map(|shift| 1u64 << shift));
+        for reading in readings
+            .map(|&reading| reading.into())
+            .chain(values.map(to_reading))
+        {
+            let mut rest = reading.as_str();
+            while let Some((kana, _)) = ROMAJI.iter().find(|(kana, _)| rest.starts_with(kana)) {
+                rest = &rest[kana.len()..];
+            }
+            assert_eq!(rest, "", "{}", reading);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_ruby {
+    use crate::japanese::{to_japanese, to_reading, to_ruby, to_ruby_html};
+
+    #[test]
+    fn test_to_ruby() {
+        assert_eq!(to_ruby(0), [('零', "ぜろ".into())]);
+        assert_eq!(
+            to_ruby(1994),
+            [
+                ('千', "せん".into()),
+                ('九', "きゅう".into()),
+                ('百', "ひゃく".into()),
+                ('九', "きゅう".into()),
+                ('十', "じゅう".into()),
+                ('四', "よん".into()),
+            ]
+        );
+        assert_eq!(
+            to_ruby(8600),
+            [
+                ('八', "はっ".into()),
+                ('千', "せん".into()),
+                ('六', "ろっ".into()),
+                ('百', "ぴゃく".into())
+            ]
+        );
+        assert_eq!(
+            to_ruby(3000),
+            [('三', "さん".into()), ('千', "ぜん".into())]
+        );
+        assert_eq!(to_ruby(1_000_000_000_000), [('兆', "いっちょう".into())]);
+        assert_eq!(
+            to_ruby(100_000_000_000_000_000),
+            [('十', "じゅっ".into()), ('京', "けい".into())]
+        );
+    }
+
+    #[test]
+    fn test_matches_reading() {
+        let values = (0..=20_000).chain((0..64).map(|shift| 1u64 << shift));
+        for value in values.chain(Some(u64::MAX)) {
+            let ruby = to_ruby(value);
+            assert_eq!(
+                ruby.iter().map(|(ch, _)| ch).collect::<String>(),
+                to_japanese(value)
+            );
+            assert_eq!(
+                ruby.iter()
+                    .map(|(_, reading)| reading.as_str())
+                    .collect::<String>(),
+                to_reading(value)
+            );
+            assert!(ruby.iter().all(|(_, reading)| !reading.is_empty()));
+        }
+    }
+
+    #[test]
+    fn test_to_ruby_html() {
+        assert_eq!(to_ruby_html(0), "<ruby>零<rt>ぜろ</rt></ruby>");
+        assert_eq!(to_ruby_html(10_000), "<ruby>万<rt>いちまん</rt></ruby>");
+    }
+}
+
+#[cfg(test)]
+mod test_digits {
+    use crate::japanese::{to_digit_reading, to_digits};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_to_digits() {
+        let x = to_digits("0120");
+        assert_eq!(x.unwrap(), "〇一二〇");
+
+        let x = to_digits("1234567890");
+        assert_eq!(x.unwrap(), "一二三四五六七八九〇");
+
+        let x = to_digits("００７");
+        assert_eq!(x.unwrap(), "〇〇七");
+
+        let x = to_digits("03-1234");
+        assert_eq!(x.unwrap(), "〇三-一二三四");
+    }
+
+    #[test]
+    fn test_to_digit_reading() {
+        let x = to_digit_reading("0120");
+        assert_eq!(x.unwrap(), "ゼロいちにいゼロ");
+
+        let x = to_digit_reading("1234567890");
+        assert_eq!(x.unwrap(), "いちにいさんよんごおろくななはちきゅうゼロ");
+
+        let x = to_digit_reading("１１０");
+        assert_eq!(x.unwrap(), "いちいちゼロ");
+
+        let x = to_digit_reading("0120-444-444");
+        assert_eq!(x.unwrap(), "ゼロいちにいゼロ-よんよんよん-よんよんよん");
+
+        let x = to_digit_reading("０３‐１２３４　５６７８");
+        assert_eq!(x.unwrap(), "ゼロさん‐いちにいさんよん　ごおろくななはち");
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let x = to_digits("");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = to_digit_reading("");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = to_digits("03/1234");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: '/', index: 2 }
+        );
+
+        let x = to_digit_reading("一二");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter {
+                ch: '一', index: 0
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_from_reading {
+    use crate::japanese::{from_reading, to_reading};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_from_reading() {
+        let x = from_reading("にせんにじゅうよん");
+        assert_eq!(x.unwrap(), 2024);
+
+        let x = from_reading("せんきゅうひゃくきゅうじゅうよん");
+        assert_eq!(x.unwrap(), 1994);
+
+        let x = from_reading("ぜろ");
+        assert_eq!(x.unwrap(), 0);
+
+        let x = from_reading("いちおくにせんまん");
+        assert_eq!(x.unwrap(), 120_000_000);
+
+        let x = from_reading("にせん にじゅう よん");
+        assert_eq!(x.unwrap(), 2024);
+    }
+
+    #[test]
+    fn test_katakana() {
+        let x = from_reading("ニセンニジュウヨン");
+        assert_eq!(x.unwrap(), 2024);
+
+        let x = from_reading("キューヒャクジュー");
+        assert_eq!(x.unwrap(), 910);
+
+        let x = from_reading("イッチョウ");
+        assert_eq!(x.unwrap(), 1_000_000_000_000);
+    }
+
+    #[test]
+    fn test_variants() {
+        let x = from_reading("しちじゅう");
+        assert_eq!(x.unwrap(), 70);
+
+        let x = from_reading("しじゅうく");
+        assert_eq!(x.unwrap(), 49);
+
+        let x = from_reading("じっちょう");
+        assert_eq!(x.unwrap(), 10_000_000_000_000);
+
+        let x = from_reading("いちちょう");
+        assert_eq!(x.unwrap(), 1_000_000_000_000);
+
+        let x = from_reading("いっせんまん");
+        assert_eq!(x.unwrap(), 10_000_000);
+
+        let x = from_reading("れい");
+        assert_eq!(x.unwrap(), 0);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let values = (0..=20_000).chain((14..64).map(|shift| 1u64 << shift));
+        for value in values.chain(Some(u64::MAX)) {
+            assert_eq!(from_reading(&to_reading(value)), Ok(value));
+        }
+    }
+
+    #[test]
+    fn test_invalid_readings() {
+        let x = from_reading("");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = from_reading("にせんえん");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter {
+                ch: 'え', index: 3
+            }
+        );
+
+        let x = from_reading("ひゃくせん");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: 'せ', index: 3
+            }
+        );
+
+        let x = from_reading("ニニ");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: 'ニ', index: 1
+            }
+        );
+
+        let x = from_reading("いちがい");
+        assert_eq!(x.unwrap_err(), NumeralError::Overflow);
+    }
+}
+
+#[cfg(test)]
+mod test_mixed {
+    use crate::japanese::{to_japanese_with, JapaneseOptions, Style};
+
+    fn mixed() -> JapaneseOptions {
+        JapaneseOptions::new().style(Style::Mixed)
+    }
+
+    #[test]
+    fn test_mixed() {
+        assert_eq!(to_japanese_with(0, &mixed()), "0");
+        assert_eq!(to_japanese_with(1994, &mixed()), "1994");
+        assert_eq!(to_japanese_with(10_000, &mixed()), "1万");
+        assert_eq!(to_japanese_with(12_000, &mixed()), "1万2000");
+        assert_eq!(to_japanese_with(120_000, &mixed()), "12万");
+        assert_eq!(to_japanese_with(100_000_005, &mixed()), "1億5");
+        assert_eq!(to_japanese_with(123_456_789, &mixed()), "1億2345万6789");
+        assert_eq!(
+            to_japanese_with(u64::MAX, &mixed()),
+            "1844京6744兆737億955万1615"
+        );
+    }
+
+    #[test]
+    fn test_separator() {
+        let options = mixed().separator(Some(','));
+        assert_eq!(to_japanese_with(999, &options), "999");
+        assert_eq!(to_japanese_with(1994, &options), "1,994");
+        assert_eq!(to_japanese_with(123_456_789, &options), "1億2,345万6,789");
+        assert_eq!(
+            to_japanese_with(12_345_678, &mixed().separator(None)),
+            "1234万5678"
+        );
+    }
+
+    #[test]
+    fn test_rounded() {
+        let options = mixed().rounded(1);
+        assert_eq!(to_japanese_with(1994, &options), "1994");
+        assert_eq!(to_japanese_with(12_345, &options), "1.2万");
+        assert_eq!(to_japanese_with(12_500, &options), "1.3万");
+        assert_eq!(to_japanese_with(120_000, &options), "12万");
+        assert_eq!(to_japanese_with(123_456_789, &options), "1.2億");
+        assert_eq!(to_japanese_with(99_990_000, &options), "9999万");
+        assert_eq!(to_japanese_with(99_999_999, &options), "1億");
+        assert_eq!(to_japanese_with(u64::MAX, &options), "1844.7京");
+
+        assert_eq!(to_japanese_with(123_456_789, &mixed().rounded(0)), "1億");
+        assert_eq!(
+            to_japanese_with(123_456_789, &mixed().rounded(3)),
+            "1.235億"
+        );
+        assert_eq!(
+            to_japanese_with(12_345_678, &mixed().rounded(2)),
+            "1234.57万"
+        );
+        assert_eq!(to_japanese_with(10_000, &mixed().rounded(40)), "1万");
+        assert_eq!(
+            to_japanese_with(u64::MAX, &mixed().rounded(40)),
+            "1844.6744073709551615京"
+        );
+
+        let options = options.separator(Some(','));
+        assert_eq!(to_japanese_with(12_345_678, &options), "1,234.6万");
+    }
+
+    #[test]
+    fn test_fullwidth() {
+        let options = mixed().fullwidth(true);
+        assert_eq!(to_japanese_with(0, &options), "０");
+        assert_eq!(
+            to_japanese_with(123_456_789, &options),
+            "１億２３４５万６７８９"
+        );
+        assert_eq!(
+            to_japanese_with(12_345_678, &options.rounded(1).separator(Some('，'))),
+            "１，２３４.６万"
+        );
+        assert_eq!(
+            to_japanese_with(1994, &JapaneseOptions::new().fullwidth(true)),
+            "千九百九十四"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_to_arabic {
+    use crate::japanese::{to_arabic, to_japanese, to_japanese_with, JapaneseOptions};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_to_arabic() {
+        let x = to_arabic("千九百九十四");
+        assert_eq!(x.unwrap(), 1994);
+
+        let x = to_arabic("一万二千三百");
+        assert_eq!(x.unwrap(), 12_300);
+
+        let x = to_arabic("一千九百九十四");
+        assert_eq!(x.unwrap(), 1994);
+
+        let x = to_arabic("万千百十一");
+        assert_eq!(x.unwrap(), 11_111);
+
+        let x = to_arabic("一億二千三百万");
+        assert_eq!(x.unwrap(), 123_000_000);
+
+        let x = to_arabic("十");
+        assert_eq!(x.unwrap(), 10);
+    }
+
+    #[test]
+    fn test_daiji() {
+        let x = to_arabic("壱仟玖佰玖拾肆");
+        assert_eq!(x.unwrap(), 1994);
+
+        let x = to_arabic("壱萬弐仟参佰");
+        assert_eq!(x.unwrap(), 12_300);
+
+        let x = to_arabic("壹萬貳阡參陌");
+        assert_eq!(x.unwrap(), 12_300);
+
+        let x = to_arabic("伍拾萬");
+        assert_eq!(x.unwrap(), 500_000);
+
+        let x = to_arabic("弐千参百拾");
+        assert_eq!(x.unwrap(), 2310);
+
+        let x = to_arabic("拾十");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '十', index: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_daiji_round_trip() {
+        let options = JapaneseOptions::new().daiji();
+        let values = (0..=20_000).chain((0..64).map(|shift| 1u64 << shift));
+        for value in values.chain(Some(u64::MAX)) {
+            assert_eq!(to_arabic(&to_japanese_with(value, &options)), Ok(value));
+        }
+    }
+
+    #[test]
+    fn test_zero() {
+        let x = to_arabic("零");
+        assert_eq!(x.unwrap(), 0);
+
+        let x = to_arabic("〇");
+        assert_eq!(x.unwrap(), 0);
+
+        let x = to_arabic("百〇");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '〇', index: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let values = (0..=20_000).chain((0..64).map(|shift| 1u64 << shift));
+        for value in values.chain(Some(u64::MAX)) {
+            assert_eq!(to_arabic(&to_japanese(value)), Ok(value));
+        }
+    }
+
+    #[test]
+    fn test_invalid_sequences() {
+        let x = to_arabic("九十九十");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '十', index: 3
+            }
+        );
+
+        let x = to_arabic("百千");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '千', index: 1
+            }
+        );
+
+        let x = to_arabic("一二百");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '二', index: 1
+            }
+        );
+
+        let x = to_arabic("万億");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '億', index: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let x = to_arabic("");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = to_arabic("千九百X");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: 'X', index: 3 }
+        );
+
+        let x = to_arabic("二千京");
+        assert_eq!(x.unwrap_err(), NumeralError::Overflow);
+    }
+}
+
+#[cfg(test)]
+mod test_to_arabic_with_style {
+    use crate::japanese::{to_arabic_with_style, to_japanese_with, JapaneseOptions, Style};
+    use crate::NumeralError;
+
+    fn parse(input: &str) -> (u64, Style) {
+        let parsed = to_arabic_with_style(input).unwrap();
+        (parsed.value, parsed.style)
+    }
+
+    #[test]
+    fn test_styles() {
+        assert_eq!(parse("二〇二四"), (2024, Style::Positional));
+        assert_eq!(parse("一九九四"), (1994, Style::Positional));
+        assert_eq!(parse("〇一二〇"), (120, Style::Positional));
+        assert_eq!(parse("零零"), (0, Style::Positional));
+        assert_eq!(parse("二千二十四"), (2024, Style::Common));
+        assert_eq!(parse("五"), (5, Style::Common));
+        assert_eq!(parse("〇"), (0, Style::Common));
+        assert_eq!(parse("弐千弐拾四"), (2024, Style::Daiji));
+        assert_eq!(parse("壱萬"), (10_000, Style::Daiji));
+        assert_eq!(parse("参拾"), (30, Style::Daiji));
+        assert_eq!(parse("3万5000"), (35_000, Style::Mixed));
+        assert_eq!(parse("3萬"), (30_000, Style::Mixed));
+    }
+
+    #[test]
+    fn test_positional_round_trip() {
+        let options = JapaneseOptions::new().style(Style::Positional);
+        let values = (10..=20_000).chain((4..64).map(|shift| 1u64 << shift));
+        for value in values.chain(Some(u64::MAX)) {
+            let numeral = to_japanese_with(value, &options);
+            assert_eq!(parse(&numeral), (value, Style::Positional));
+        }
+    }
+
+    #[test]
+    fn test_invalid_positional() {
+        let x = to_arabic_with_style("一八四四六七四四〇七三七〇九五五一六一六");
+        assert_eq!(x.unwrap_err(), NumeralError::Overflow);
+
+        let x = to_arabic_with_style("二〇二四年");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '〇', index: 1
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_archaic_tens {
+    use crate::japanese::{to_arabic, to_arabic_with_style};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_archaic_tens() {
+        let x = to_arabic("廿一");
+        assert_eq!(x.unwrap(), 21);
+
+        let x = to_arabic("卅");
+        assert_eq!(x.unwrap(), 30);
+
+        let x = to_arabic("丗六");
+        assert_eq!(x.unwrap(), 36);
+
+        let x = to_arabic("卌五");
+        assert_eq!(x.unwrap(), 45);
+
+        let x = to_arabic("二百廿");
+        assert_eq!(x.unwrap(), 220);
+
+        let x = to_arabic("廿万");
+        assert_eq!(x.unwrap(), 200_000);
+    }
+
+    #[test]
+    fn test_archaic_flag() {
+        let x = to_arabic_with_style("廿一");
+        assert!(x.unwrap().archaic);
+
+        let x = to_arabic_with_style("二十一");
+        assert!(!x.unwrap().archaic);
+    }
+
+    #[test]
+    fn test_invalid_archaic_tens() {
+        let x = to_arabic("二廿");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '廿', index: 1
+            }
+        );
+
+        let x = to_arabic("廿十");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '十', index: 1
+            }
+        );
+
+        let x = to_arabic("廿百");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '百', index: 1
+            }
+        );
+
+        let x = to_arabic("十廿");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '廿', index: 1
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_mixed_input {
+    use crate::japanese::{to_arabic, to_japanese_with, JapaneseOptions, Style};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_mixed_input() {
+        let x = to_arabic("3万5000");
+        assert_eq!(x.unwrap(), 35_000);
+
+        let x = to_arabic("1億2000万");
+        assert_eq!(x.unwrap(), 120_000_000);
+
+        let x = to_arabic("2千500");
+        assert_eq!(x.unwrap(), 2500);
+
+        let x = to_arabic("2千5百万3");
+        assert_eq!(x.unwrap(), 25_000_003);
+
+        let x = to_arabic("12万");
+        assert_eq!(x.unwrap(), 120_000);
+
+        let x = to_arabic("三億2000万");
+        assert_eq!(x.unwrap(), 320_000_000);
+
+        let x = to_arabic("1844京6744兆737億955万1615");
+        assert_eq!(x.unwrap(), u64::MAX);
+
+        let x = to_arabic("３万５０００");
+        assert_eq!(x.unwrap(), 35_000);
+
+        let x = to_arabic("２千5百");
+        assert_eq!(x.unwrap(), 2500);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let options = JapaneseOptions::new().style(Style::Mixed);
+        let values = (10_000..=30_000).chain((14..64).map(|shift| 1u64 << shift));
+        for value in values.chain(Some(u64::MAX)) {
+            let numeral = to_japanese_with(value, &options);
+            assert_eq!(to_arabic(&numeral), Ok(value));
+
+            let numeral = to_japanese_with(value, &options.fullwidth(true));
+            assert_eq!(to_arabic(&numeral), Ok(value));
+        }
+    }
+
+    #[test]
+    fn test_invalid_mixed_input() {
+        let x = to_arabic("1994");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: '1', index: 0 }
+        );
+
+        let x = to_arabic("１９９４");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter {
+                ch: '１', index: 0
+            }
+        );
+
+        let x = to_arabic("2千1500");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: '0', index: 5 }
+        );
+
+        let x = to_arabic("12000万");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: '0', index: 4 }
+        );
+
+        let x = to_arabic("25百");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '百', index: 2
+            }
+        );
+
+        let x = to_arabic("3万0500");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: '0', index: 2 }
+        );
+
+        let x = to_arabic("五3");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: '3', index: 1 }
+        );
+
+        let x = to_arabic("3五");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '五', index: 1
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_separators {
+    use crate::japanese::{to_arabic_with_separators, Japanese, SEPARATORS};
+    use crate::{NumeralError, NumeralSystem, Options, Strictness};
+
+    #[test]
+    fn test_separators() {
+        let x = to_arabic_with_separators("１，２３４万", &SEPARATORS);
+        assert_eq!(x.unwrap(), 12_340_000);
+
+        let x = to_arabic_with_separators("1,234億5,678万", &SEPARATORS);
+        assert_eq!(x.unwrap(), 123_456_780_000);
+
+        let x = to_arabic_with_separators("一億・二千万", &SEPARATORS);
+        assert_eq!(x.unwrap(), 120_000_000);
+
+        let x = to_arabic_with_separators("千九百九十四", &SEPARATORS);
+        assert_eq!(x.unwrap(), 1994);
+
+        let x = to_arabic_with_separators("1 234万", &[' ']);
+        assert_eq!(x.unwrap(), 12_340_000);
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let x = to_arabic_with_separators("", &SEPARATORS);
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = to_arabic_with_separators("，，", &SEPARATORS);
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = to_arabic_with_separators("1,234万", &[]);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: ',', index: 1 }
+        );
+
+        let x = to_arabic_with_separators("一億, 二億", &SEPARATORS);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '億', index: 5
+            }
+        );
+
+        let x = to_arabic_with_separators("1,234", &SEPARATORS);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: '1', index: 0 }
+        );
+    }
+
+    #[test]
+    fn test_parse_with() {
+        let options = Options {
+            strictness: Strictness::Lenient,
+            ..Options::default()
+        };
+        let x = Japanese.parse_with("１，２３４万", &options);
+        assert_eq!(x.unwrap(), 12_340_000);
+
+        let x = Japanese.parse_with("１，２３４万", &Options::default());
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter {
+                ch: '，', index: 1
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_find_all {
+    use crate::japanese::{find_all, Match};
+
+    #[test]
+    fn test_matches() {
+        let text = "本日、三百二十五名が参加し、費用は１億２千万円でした。";
+        let matches: Vec<Match> = find_all(text).collect();
+        let values: Vec<u64> = matches.iter().map(|m| m.value).collect();
+        assert_eq!(values, vec![325, 120_000_000]);
+
+        assert_eq!(matches[0].text, "三百二十五");
+        assert_eq!(matches[0].range, 9..24);
+        assert_eq!(&text[matches[1].range.clone()], "１億２千万");
+    }
+
+    #[test]
+    fn test_longest_numeral() {
+        // The run 九十九十 is not a numeral, but starts with 九十九
+        let values: Vec<u64> = find_all("九十九十").map(|m| m.value).collect();
+        assert_eq!(values, vec![99, 10]);
+
+        let values: Vec<u64> = find_all("一九九四年と廿日").map(|m| m.value).collect();
+        assert_eq!(values, vec![1994, 20]);
+    }
+
+    #[test]
+    fn test_left_out() {
+        assert_eq!(find_all("").count(), 0);
+        assert_eq!(find_all("数字はありません").count(), 0);
+        assert_eq!(find_all("2024年").count(), 0);
+        assert_eq!(find_all("京都と億").count(), 0);
+        assert_eq!(find_all("参加する").count(), 0);
+
+        let values: Vec<u64> = find_all("京都の三万人").map(|m| m.value).collect();
+        assert_eq!(values, vec![30_000]);
+    }
+}
+
+#[cfg(test)]
+mod test_u128 {
+    use crate::japanese::{to_arabic, to_arabic_u128, to_japanese, to_japanese_u128};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_to_japanese_u128() {
+        assert_eq!(to_japanese_u128(1994), "千九百九十四");
+        assert_eq!(
+            to_japanese_u128(u128::from(u64::MAX)),
+            to_japanese(u64::MAX)
+        );
+        assert_eq!(to_japanese_u128(10u128.pow(20)), "垓");
+        assert_eq!(to_japanese_u128(10u128.pow(24)), "𥝱");
+        assert_eq!(to_japanese_u128(10u128.pow(28) * 12), "十二穣");
+        assert_eq!(to_japanese_u128(10u128.pow(32) + 1), "溝一");
+        assert_eq!(
+            to_japanese_u128(u128::MAX),
+            "三百四十澗二千八百二十三溝六千六百九十二穣九百三十八𥝱四千六百三十四垓\
+             六千三百三十七京四千六百七兆四千三百十七億六千八百二十一万千四百五十五"
+        );
+    }
+
+    #[test]
+    fn test_to_arabic_u128() {
+        let x = to_arabic_u128("一垓");
+        assert_eq!(x.unwrap(), 10u128.pow(20));
+
+        let x = to_arabic_u128("五秭");
+        assert_eq!(x.unwrap(), 5 * 10u128.pow(24));
+
+        let x = to_arabic_u128("穰三京");
+        assert_eq!(x.unwrap(), 10u128.pow(28) + 3 * 10u128.pow(16));
+
+        let x = to_arabic_u128("1844京6744兆737億955万1616");
+        assert_eq!(x.unwrap(), u128::from(u64::MAX) + 1);
+
+        let x = to_arabic_u128("一〇〇〇〇〇〇〇〇〇〇〇〇〇〇〇〇〇〇〇〇");
+        assert_eq!(x.unwrap(), 10u128.pow(20));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let values = (0..=20_000).chain((0..128).map(|shift| 1u128 << shift));
+        for value in values.chain(Some(u128::MAX)) {
+            assert_eq!(to_arabic_u128(&to_japanese_u128(value)), Ok(value));
+        }
+    }
+
+    #[test]
+    fn test_overflow() {
+        let x = to_arabic("一垓");
+        assert_eq!(x.unwrap_err(), NumeralError::Overflow);
+
+        let x = to_arabic_u128("一正");
+        assert_eq!(x.unwrap_err(), NumeralError::Overflow);
+
+        let x = to_arabic_u128("千澗");
+        assert_eq!(x.unwrap_err(), NumeralError::Overflow);
+
+        let x = to_arabic_u128("極");
+        assert_eq!(x.unwrap_err(), NumeralError::Overflow);
+
+        let x = to_arabic_u128("一京一垓");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '垓', index: 3
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_numeral_system {
+    use crate::japanese::Japanese;
+    use crate::{NumeralSystem, Options};
+
+    #[test]
+    fn test_japanese_system() {
+        assert_eq!(Japanese.name(), "japanese");
+
+        let x = Japanese.parse("千九百九十四");
+        assert_eq!(x.unwrap(), 1994);
+
+        let x = Japanese.format(1994, &Options::default());
+        assert_eq!(x.unwrap(), "千九百九十四");
+
+        let x = Japanese.format(0, &Options::default());
+        assert_eq!(x.unwrap(), "零");
+    }
+}
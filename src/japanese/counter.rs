@@ -0,0 +1,280 @@
+/*!
+Count things with the japanese counter words.
+
+Things are counted with a word depending on their kind, as in 三本 for three
+pens. The reading of the number and of the counter change when they meet, as
+in いっぽん for 一本, which `count` follows for the common counters.
+*/
+
+use alloc::string::String;
+
+use crate::japanese::{to_japanese, to_reading};
+
+/// The common counter words.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Counter {
+    /// 本, for long and thin things, such as pens or bottles.
+    Hon,
+    /// 枚, for flat things, such as sheets or plates.
+    Mai,
+    /// 匹, for small animals.
+    Hiki,
+    /// 冊, for books.
+    Satsu,
+    /// 人, for people.
+    Nin,
+    /// 回, for times.
+    Kai,
+    /// 個, for small things.
+    Ko,
+    /// つ, for things in general, counted with the native numbers up to ten.
+    Tsu,
+}
+
+impl Counter {
+    /// The counter word, in kanji.
+    pub fn kanji(self) -> &'static str {
+        match self {
+            Counter::Hon => "本",
+            Counter::Mai => "枚",
+            Counter::Hiki => "匹",
+            Counter::Satsu => "冊",
+            Counter::Nin => "人",
+            Counter::Kai => "回",
+            Counter::Ko => "個",
+            Counter::Tsu => "つ",
+        }
+    }
+}
+
+/// A number of things, with its counter word.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Count {
+    /// The number and its counter, in kanji, as in 三本.
+    pub numeral: String,
+    /// How they are read, in hiragana, as in さんぼん.
+    pub reading: String,
+}
+
+/// The native numbers counted with つ, from 1 to 9.
+const NATIVE_READINGS: [&str; 10] = [
+    "",
+    "ひとつ",
+    "ふたつ",
+    "みっつ",
+    "よっつ",
+    "いつつ",
+    "むっつ",
+    "ななつ",
+    "やっつ",
+    "ここのつ",
+];
+
+/// Count `input` things with the given counter.
+///
+///     use numerals::japanese::counter::{count, Counter};
+///
+///     let x = count(3, Counter::Hon);
+///     assert_eq!(x.numeral, "三本");
+///     assert_eq!(x.reading, "さんぼん");
+///
+///     assert_eq!(count(1, Counter::Hon).reading, "いっぽん");
+///     assert_eq!(count(6, Counter::Hiki).reading, "ろっぴき");
+///     assert_eq!(count(10, Counter::Satsu).reading, "じゅっさつ");
+pub fn count(input: u64, counter: Counter) -> Count {
+    let mut numeral = to_japanese(input);
+    let mut reading = to_reading(input);
+    match counter {
+        Counter::Tsu => {
+            // Beyond ten, things are counted with the bare numbers
+            match input {
+                1..=9 => {
+                    numeral.push_str(counter.kanji());
+                    reading = NATIVE_READINGS[input as usize].into();
+                }
+                10 => reading = "とお".into(),
+                _ => (),
+            }
+            return Count { numeral, reading };
+        }
+        Counter::Nin => match input {
+            1 => reading = "ひとり".into(),
+            2 => reading = "ふたり".into(),
+            _ => {
+                // Four people are よにん
+                if reading.ends_with("よん") {
+                    reading.pop();
+                }
+                reading.push_str("にん");
+            }
+        },
+        Counter::Hon | Counter::Hiki => {
+            let (plain, voiced, plosive) = match counter {
+                Counter::Hon => ("ほん", "ぼん", "ぽん"),
+                _ => ("ひき", "びき", "ぴき"),
+            };
+            if geminate(&mut reading, &["いち", "ろく", "はち", "じゅう", "ゃく"]) {
+                reading.push_str(plosive);
+            } else if reading.ends_with('ん') && !reading.ends_with("よん") {
+                reading.push_str(voiced);
+            } else {
+                reading.push_str(plain);
+            }
+        }
+        Counter::Kai | Counter::Ko => {
+            geminate(&mut reading, &["いち", "ろく", "はち", "じゅう", "ゃく"]);
+            reading.push_str(if counter == Counter::Kai {
+                "かい"
+            } else {
+                "こ"
+            });
+        }
+        Counter::Satsu => {
+            geminate(&mut reading, &["いち", "はち", "じゅう"]);
+            reading.push_str("さつ");
+        }
+        Counter::Mai => reading.push_str("まい"),
+    }
+    numeral.push_str(counter.kanji());
+    Count { numeral, reading }
+}
+
+/// Shorten the last sound of a reading to a small tsu, if it is one of the
+/// given endings, and tell whether it was.
+fn geminate(reading: &mut String, endings: &[&str]) -> bool {
+    let geminated = endings.iter().any(|ending| reading.ends_with(ending));
+    if geminated {
+        reading.pop();
+        reading.push('っ');
+    }
+    geminated
+}
+
+#[cfg(test)]
+mod test_count {
+    use crate::japanese::counter::{count, Counter};
+
+    fn readings(counter: Counter) -> [String; 11] {
+        [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 100].map(|value| count(value, counter).reading)
+    }
+
+    #[test]
+    fn test_numeral() {
+        assert_eq!(count(3, Counter::Hon).numeral, "三本");
+        assert_eq!(count(1, Counter::Nin).numeral, "一人");
+        assert_eq!(count(25, Counter::Mai).numeral, "二十五枚");
+        assert_eq!(count(3, Counter::Tsu).numeral, "三つ");
+        assert_eq!(count(10, Counter::Tsu).numeral, "十");
+        assert_eq!(count(12, Counter::Tsu).numeral, "十二");
+    }
+
+    #[test]
+    fn test_hon() {
+        assert_eq!(
+            readings(Counter::Hon),
+            [
+                "いっぽん",
+                "にほん",
+                "さんぼん",
+                "よんほん",
+                "ごほん",
+                "ろっぽん",
+                "ななほん",
+                "はっぽん",
+                "きゅうほん",
+                "じゅっぽん",
+                "ひゃっぽん",
+            ]
+        );
+        assert_eq!(count(1000, Counter::Hon).reading, "せんぼん");
+        assert_eq!(count(10_000, Counter::Hon).reading, "いちまんぼん");
+        assert_eq!(count(300, Counter::Hon).reading, "さんびゃっぽん");
+    }
+
+    #[test]
+    fn test_hiki() {
+        assert_eq!(
+            readings(Counter::Hiki),
+            [
+                "いっぴき",
+                "にひき",
+                "さんびき",
+                "よんひき",
+                "ごひき",
+                "ろっぴき",
+                "ななひき",
+                "はっぴき",
+                "きゅうひき",
+                "じゅっぴき",
+                "ひゃっぴき",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_satsu() {
+        assert_eq!(
+            readings(Counter::Satsu),
+            [
+                "いっさつ",
+                "にさつ",
+                "さんさつ",
+                "よんさつ",
+                "ごさつ",
+                "ろくさつ",
+                "ななさつ",
+                "はっさつ",
+                "きゅうさつ",
+                "じゅっさつ",
+                "ひゃくさつ",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_kai_and_ko() {
+        assert_eq!(
+            readings(Counter::Kai),
+            [
+                "いっかい",
+                "にかい",
+                "さんかい",
+                "よんかい",
+                "ごかい",
+                "ろっかい",
+                "ななかい",
+                "はっかい",
+                "きゅうかい",
+                "じゅっかい",
+                "ひゃっかい",
+            ]
+        );
+        assert_eq!(count(6, Counter::Ko).reading, "ろっこ");
+        assert_eq!(count(2, Counter::Ko).reading, "にこ");
+    }
+
+    #[test]
+    fn test_mai() {
+        assert_eq!(count(1, Counter::Mai).reading, "いちまい");
+        assert_eq!(count(8, Counter::Mai).reading, "はちまい");
+    }
+
+    #[test]
+    fn test_nin() {
+        assert_eq!(count(1, Counter::Nin).reading, "ひとり");
+        assert_eq!(count(2, Counter::Nin).reading, "ふたり");
+        assert_eq!(count(3, Counter::Nin).reading, "さんにん");
+        assert_eq!(count(4, Counter::Nin).reading, "よにん");
+        assert_eq!(count(14, Counter::Nin).reading, "じゅうよにん");
+        assert_eq!(count(21, Counter::Nin).reading, "にじゅういちにん");
+    }
+
+    #[test]
+    fn test_tsu() {
+        assert_eq!(count(1, Counter::Tsu).reading, "ひとつ");
+        assert_eq!(count(4, Counter::Tsu).reading, "よっつ");
+        assert_eq!(count(9, Counter::Tsu).reading, "ここのつ");
+        assert_eq!(count(10, Counter::Tsu).reading, "とお");
+        assert_eq!(count(11, Counter::Tsu).reading, "じゅういち");
+    }
+}
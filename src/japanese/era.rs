@@ -0,0 +1,417 @@
+/*!
+Convert gregorian years to the years of the japanese eras, and vice-versa.
+
+Years are counted from the start of each era, or nengō, the first one being
+written 元年 rather than 一年, so that 2024 is 令和六年. The modern eras are
+supported, from 明治 in 1868 onwards.
+
+A new era starts within the year, which then belongs to both eras: 2019 is
+both 平成三十一年 and 令和元年. Such years are written in the newer era, and
+parsed in either.
+*/
+
+use alloc::string::{String, ToString};
+use core::fmt;
+
+use crate::arabic;
+use crate::error::NumeralError;
+use crate::japanese::to_japanese;
+
+/// The modern japanese eras.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Era {
+    Meiji,
+    Taisho,
+    Showa,
+    Heisei,
+    Reiwa,
+}
+
+/// The eras, with their names and the years they started in.
+const ERAS: [(Era, &str, u64); 5] = [
+    (Era::Meiji, "明治", 1868),
+    (Era::Taisho, "大正", 1912),
+    (Era::Showa, "昭和", 1926),
+    (Era::Heisei, "平成", 1989),
+    (Era::Reiwa, "令和", 2019),
+];
+
+impl Era {
+    /// The name of the era, in kanji.
+    pub fn name(self) -> &'static str {
+        ERAS[self as usize].1
+    }
+
+    /// The gregorian year the era started in, which is its first year.
+    pub fn first_year(self) -> u64 {
+        ERAS[self as usize].2
+    }
+
+    /// The gregorian year the era ended in, unless it is the current one.
+    pub fn last_year(self) -> Option<u64> {
+        ERAS.get(self as usize + 1).map(|&(_, _, year)| year)
+    }
+}
+
+/// A year, counted from the start of its era.
+///
+///     use numerals::japanese::era::{Era, EraYear};
+///
+///     let year = EraYear { era: Era::Reiwa, year: 6 };
+///     assert_eq!(year.gregorian(), Ok(2024));
+///     assert_eq!(year.to_string(), "令和六年");
+///     assert_eq!(year.format(true), "令和6年");
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EraYear {
+    pub era: Era,
+    pub year: u64,
+}
+
+impl EraYear {
+    /// The gregorian year.
+    pub fn gregorian(&self) -> Result<u64, NumeralError> {
+        self.year
+            .checked_add(self.era.first_year() - 1)
+            .ok_or(NumeralError::Overflow)
+    }
+
+    /// Write the year with its era, in kanji or in arabic digits.
+    /// The first year is written 元年 either way.
+    pub fn format(&self, arabic_digits: bool) -> String {
+        let mut ret = String::from(self.era.name());
+        match self.year {
+            1 => ret.push('元'),
+            year if arabic_digits => ret.push_str(&year.to_string()),
+            year => ret.push_str(&to_japanese(year)),
+        }
+        ret.push('年');
+        ret
+    }
+}
+
+impl fmt::Display for EraYear {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.format(false))
+    }
+}
+
+/// Convert a gregorian year to the year of its era.
+///
+///     use numerals::japanese::era::{to_era, Era, EraYear};
+///
+///     assert_eq!(to_era(2024), Ok(EraYear { era: Era::Reiwa, year: 6 }));
+///     assert_eq!(to_era(1989), Ok(EraYear { era: Era::Heisei, year: 1 }));
+///     assert!(to_era(1603).is_err());
+pub fn to_era(year: u64) -> Result<EraYear, NumeralError> {
+    let &(era, _, first_year) = ERAS
+        .iter()
+        .rev()
+        .find(|&&(_, _, first_year)| first_year <= year)
+        .ok_or(NumeralError::OutOfRange {
+            value: year,
+            min: Era::Meiji.first_year(),
+            max: u64::MAX,
+        })?;
+    Ok(EraYear {
+        era,
+        year: year - first_year + 1,
+    })
+}
+
+/// Parse a year with its era, as in 令和六年.
+///
+/// The year is written in kanji, or in arabic digits, and its first one can
+/// be written 元. The trailing 年 can be left out.
+///
+///     use numerals::japanese::era::{parse, Era, EraYear};
+///
+///     assert_eq!(parse("令和六年"), Ok(EraYear { era: Era::Reiwa, year: 6 }));
+///     assert_eq!(parse("平成31年"), Ok(EraYear { era: Era::Heisei, year: 31 }));
+///     assert_eq!(parse("昭和元年"), Ok(EraYear { era: Era::Showa, year: 1 }));
+pub fn parse(input: &str) -> Result<EraYear, NumeralError> {
+    if input.is_empty() {
+        return Err(NumeralError::Empty);
+    }
+
+    let (era, numeral) = ERAS
+        .iter()
+        .find_map(|&(era, name, _)| Some((era, input.strip_prefix(name)?)))
+        .ok_or(NumeralError::InvalidCharacter {
+            ch: input.chars().next().unwrap_or_default(),
+            index: 0,
+        })?;
+    let numeral = numeral.strip_suffix('年').unwrap_or(numeral);
+    // The errors are found in the year, which follows the two kanji of the era
+    let year = match numeral {
+        "" => Err(NumeralError::Empty),
+        "元" => Ok(1),
+        _ => arabic::parse(numeral).or_else(|_| super::to_arabic(numeral)),
+    }
+    .map_err(|error| match error {
+        NumeralError::InvalidCharacter { ch, index } => NumeralError::InvalidCharacter {
+            ch,
+            index: index + 2,
+        },
+        NumeralError::InvalidSequence { ch, index } => NumeralError::InvalidSequence {
+            ch,
+            index: index + 2,
+        },
+        error => error,
+    })?;
+
+    let max = match era.last_year() {
+        Some(last_year) => last_year - era.first_year() + 1,
+        None => u64::MAX - era.first_year() + 1,
+    };
+    if year == 0 || year > max {
+        return Err(NumeralError::OutOfRange {
+            value: year,
+            min: 1,
+            max,
+        });
+    }
+    Ok(EraYear { era, year })
+}
+
+/// Convert a year with its era to the gregorian year.
+///
+///     use numerals::japanese::era::to_gregorian;
+///
+///     assert_eq!(to_gregorian("令和六年"), Ok(2024));
+///     assert_eq!(to_gregorian("明治元年"), Ok(1868));
+pub fn to_gregorian(input: &str) -> Result<u64, NumeralError> {
+    parse(input)?.gregorian()
+}
+
+#[cfg(test)]
+mod test_to_era {
+    use crate::japanese::era::{to_era, Era, EraYear};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_to_era() {
+        let x = to_era(2024);
+        assert_eq!(
+            x.unwrap(),
+            EraYear {
+                era: Era::Reiwa,
+                year: 6
+            }
+        );
+
+        let x = to_era(2018);
+        assert_eq!(
+            x.unwrap(),
+            EraYear {
+                era: Era::Heisei,
+                year: 30
+            }
+        );
+
+        let x = to_era(1945);
+        assert_eq!(
+            x.unwrap(),
+            EraYear {
+                era: Era::Showa,
+                year: 20
+            }
+        );
+
+        let x = to_era(1920);
+        assert_eq!(
+            x.unwrap(),
+            EraYear {
+                era: Era::Taisho,
+                year: 9
+            }
+        );
+
+        let x = to_era(1868);
+        assert_eq!(
+            x.unwrap(),
+            EraYear {
+                era: Era::Meiji,
+                year: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_first_years() {
+        for &era in &[Era::Taisho, Era::Showa, Era::Heisei, Era::Reiwa] {
+            let x = to_era(era.first_year());
+            assert_eq!(x.unwrap(), EraYear { era, year: 1 });
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for year in 1868..=2200 {
+            assert_eq!(to_era(year).unwrap().gregorian(), Ok(year));
+        }
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let x = to_era(1867);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::OutOfRange {
+                value: 1867,
+                min: 1868,
+                max: u64::MAX
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_format {
+    use crate::japanese::era::{Era, EraYear};
+
+    #[test]
+    fn test_format() {
+        let year = EraYear {
+            era: Era::Heisei,
+            year: 31,
+        };
+        assert_eq!(year.to_string(), "平成三十一年");
+        assert_eq!(year.format(true), "平成31年");
+
+        let year = EraYear {
+            era: Era::Reiwa,
+            year: 1,
+        };
+        assert_eq!(year.to_string(), "令和元年");
+        assert_eq!(year.format(true), "令和元年");
+
+        let year = EraYear {
+            era: Era::Meiji,
+            year: 45,
+        };
+        assert_eq!(year.to_string(), "明治四十五年");
+    }
+}
+
+#[cfg(test)]
+mod test_parse {
+    use crate::japanese::era::{parse, to_era, to_gregorian, Era, EraYear};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_parse() {
+        let x = parse("令和六年");
+        assert_eq!(
+            x.unwrap(),
+            EraYear {
+                era: Era::Reiwa,
+                year: 6
+            }
+        );
+
+        let x = parse("令和6年");
+        assert_eq!(
+            x.unwrap(),
+            EraYear {
+                era: Era::Reiwa,
+                year: 6
+            }
+        );
+
+        let x = parse("令和６");
+        assert_eq!(
+            x.unwrap(),
+            EraYear {
+                era: Era::Reiwa,
+                year: 6
+            }
+        );
+
+        let x = parse("大正元年");
+        assert_eq!(
+            x.unwrap(),
+            EraYear {
+                era: Era::Taisho,
+                year: 1
+            }
+        );
+
+        let x = parse("昭和六十四年");
+        assert_eq!(
+            x.unwrap(),
+            EraYear {
+                era: Era::Showa,
+                year: 64
+            }
+        );
+    }
+
+    #[test]
+    fn test_to_gregorian() {
+        let x = to_gregorian("令和六年");
+        assert_eq!(x.unwrap(), 2024);
+
+        let x = to_gregorian("平成三十一年");
+        assert_eq!(x.unwrap(), 2019);
+
+        let x = to_gregorian("令和元年");
+        assert_eq!(x.unwrap(), 2019);
+
+        let x = to_gregorian("明治45年");
+        assert_eq!(x.unwrap(), 1912);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for year in 1868..=2200 {
+            let era_year = to_era(year).unwrap();
+            assert_eq!(parse(&era_year.to_string()), Ok(era_year));
+            assert_eq!(parse(&era_year.format(true)), Ok(era_year));
+        }
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let x = parse("");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = parse("令和年");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = parse("慶応三年");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter {
+                ch: '慶', index: 0
+            }
+        );
+
+        let x = parse("令和六月");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter {
+                ch: '月', index: 3
+            }
+        );
+
+        let x = parse("平成三十二年");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::OutOfRange {
+                value: 32,
+                min: 1,
+                max: 31
+            }
+        );
+
+        let x = parse("令和0年");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::OutOfRange {
+                value: 0,
+                min: 1,
+                max: u64::MAX - 2018
+            }
+        );
+    }
+}
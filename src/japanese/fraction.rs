@@ -0,0 +1,206 @@
+/*!
+Convert the fractions written with 分の, and vice-versa.
+
+The denominator is written first, followed by 分の and the numerator, so that
+two thirds are written 三分の二, literally "of three parts, two".
+*/
+
+use alloc::string::String;
+
+use crate::arabic;
+use crate::error::NumeralError;
+use crate::japanese::{to_arabic, to_japanese};
+
+/// Write the fraction `numerator` / `denominator`, as in 三分の二.
+///
+/// The fraction is written as given, without being reduced.
+///
+///     use numerals::japanese::fraction::to_fraction;
+///
+///     assert_eq!(to_fraction(2, 3).unwrap(), "三分の二");
+///     assert_eq!(to_fraction(1, 100).unwrap(), "百分の一");
+///     assert!(to_fraction(1, 0).is_err());
+pub fn to_fraction(numerator: u64, denominator: u64) -> Result<String, NumeralError> {
+    if denominator == 0 {
+        return Err(NumeralError::OutOfRange {
+            value: 0,
+            min: 1,
+            max: u64::MAX,
+        });
+    }
+
+    let mut ret = to_japanese(denominator);
+    ret.push_str("分の");
+    ret.push_str(&to_japanese(numerator));
+    Ok(ret)
+}
+
+/// Convert a fraction written with 分の to its numerator and denominator.
+///
+/// The numbers can be written in kanji or in arabic digits, as in 3分の2.
+///
+///     use numerals::japanese::fraction::from_fraction;
+///
+///     assert_eq!(from_fraction("三分の二"), Ok((2, 3)));
+///     assert_eq!(from_fraction("100分の1"), Ok((1, 100)));
+pub fn from_fraction(input: &str) -> Result<(u64, u64), NumeralError> {
+    if input.is_empty() {
+        return Err(NumeralError::Empty);
+    }
+
+    let (denominator, numerator) = input.split_once("分の").ok_or_else(|| {
+        // Either the 分 or the の is missing, or there is neither
+        let (index, ch) = input
+            .chars()
+            .enumerate()
+            .find(|&(_, ch)| ch == '分' || ch == 'の')
+            .unwrap_or((0, input.chars().next().unwrap_or_default()));
+        NumeralError::InvalidSequence { ch, index }
+    })?;
+    // The numerator follows the denominator and the two kana of 分の
+    let start = denominator.chars().count() + 2;
+    let denominator = parse_number(denominator, 0)?;
+    let numerator = parse_number(numerator, start)?;
+
+    if denominator == 0 {
+        return Err(NumeralError::OutOfRange {
+            value: 0,
+            min: 1,
+            max: u64::MAX,
+        });
+    }
+    Ok((numerator, denominator))
+}
+
+/// Parse a number in kanji or in arabic digits, found at `start` in the input.
+fn parse_number(input: &str, start: usize) -> Result<u64, NumeralError> {
+    if input.is_empty() {
+        return Err(NumeralError::Empty);
+    }
+
+    arabic::parse(input)
+        .or_else(|_| to_arabic(input))
+        .map_err(|error| match error {
+            NumeralError::InvalidCharacter { ch, index } => NumeralError::InvalidCharacter {
+                ch,
+                index: start + index,
+            },
+            NumeralError::InvalidSequence { ch, index } => NumeralError::InvalidSequence {
+                ch,
+                index: start + index,
+            },
+            error => error,
+        })
+}
+
+#[cfg(test)]
+mod test_to_fraction {
+    use crate::japanese::fraction::to_fraction;
+    use crate::NumeralError;
+
+    #[test]
+    fn test_to_fraction() {
+        let x = to_fraction(2, 3);
+        assert_eq!(x.unwrap(), "三分の二");
+
+        let x = to_fraction(1, 2);
+        assert_eq!(x.unwrap(), "二分の一");
+
+        let x = to_fraction(3, 10_000);
+        assert_eq!(x.unwrap(), "万分の三");
+
+        let x = to_fraction(2, 4);
+        assert_eq!(x.unwrap(), "四分の二");
+
+        let x = to_fraction(0, 5);
+        assert_eq!(x.unwrap(), "五分の零");
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let x = to_fraction(1, 0);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::OutOfRange {
+                value: 0,
+                min: 1,
+                max: u64::MAX
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_from_fraction {
+    use crate::japanese::fraction::{from_fraction, to_fraction};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_from_fraction() {
+        let x = from_fraction("三分の二");
+        assert_eq!(x.unwrap(), (2, 3));
+
+        let x = from_fraction("3分の2");
+        assert_eq!(x.unwrap(), (2, 3));
+
+        let x = from_fraction("１００分の１");
+        assert_eq!(x.unwrap(), (1, 100));
+
+        let x = from_fraction("十二分の十一");
+        assert_eq!(x.unwrap(), (11, 12));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for denominator in 1..=100 {
+            for numerator in 0..=denominator {
+                let x = to_fraction(numerator, denominator).unwrap();
+                assert_eq!(from_fraction(&x), Ok((numerator, denominator)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let x = from_fraction("");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = from_fraction("分の二");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = from_fraction("三分の");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = from_fraction("三分二");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '分', index: 1
+            }
+        );
+
+        let x = from_fraction("三");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '三', index: 0
+            }
+        );
+
+        let x = from_fraction("三分の二x");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: 'x', index: 4 }
+        );
+
+        let x = from_fraction("零分の一");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::OutOfRange {
+                value: 0,
+                min: 1,
+                max: u64::MAX
+            }
+        );
+    }
+}
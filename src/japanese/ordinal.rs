@@ -0,0 +1,294 @@
+/*!
+Write and parse the japanese ordinals, as in 第三, 三番目 or 三つ目.
+
+The ordinals are formed from the numerals, either with the prefix 第, as in
+第三章 for the third chapter, or with the suffixes 番目, or つ目 after the
+digits one to nine.
+*/
+
+use alloc::string::{String, ToString};
+
+use crate::arabic;
+use crate::error::NumeralError;
+use crate::japanese::{to_arabic, to_japanese};
+
+/// How an ordinal is formed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Ordinal {
+    /// With the prefix 第, as in 第三.
+    #[default]
+    Dai,
+    /// With the suffix 番目, as in 三番目.
+    Banme,
+    /// With the suffix つ目, as in 三つ目, from one to nine only.
+    Tsume,
+}
+
+/// An ordinal, as parsed by `from_ordinal`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OrdinalValue {
+    pub value: u64,
+    /// How the ordinal was formed.
+    pub ordinal: Ordinal,
+}
+
+/// Write `input` as an ordinal, in kanji or in arabic digits.
+///
+/// There is no zeroth, and つ目 only follows the digits one to nine.
+///
+///     use numerals::japanese::ordinal::{to_ordinal, Ordinal};
+///
+///     assert_eq!(to_ordinal(3, Ordinal::Dai, false).unwrap(), "第三");
+///     assert_eq!(to_ordinal(3, Ordinal::Banme, false).unwrap(), "三番目");
+///     assert_eq!(to_ordinal(3, Ordinal::Tsume, false).unwrap(), "三つ目");
+///     assert_eq!(to_ordinal(12, Ordinal::Dai, true).unwrap() + "章", "第12章");
+///     assert!(to_ordinal(12, Ordinal::Tsume, false).is_err());
+pub fn to_ordinal(
+    input: u64,
+    ordinal: Ordinal,
+    arabic_digits: bool,
+) -> Result<String, NumeralError> {
+    let max = match ordinal {
+        Ordinal::Tsume => 9,
+        Ordinal::Dai | Ordinal::Banme => u64::MAX,
+    };
+    if input == 0 || input > max {
+        return Err(NumeralError::OutOfRange {
+            value: input,
+            min: 1,
+            max,
+        });
+    }
+
+    let numeral = if arabic_digits {
+        input.to_string()
+    } else {
+        to_japanese(input)
+    };
+    Ok(match ordinal {
+        Ordinal::Dai => String::from("第") + &numeral,
+        Ordinal::Banme => numeral + "番目",
+        Ordinal::Tsume => numeral + "つ目",
+    })
+}
+
+/// Parse an ordinal, written in kanji or in arabic digits, and tell how it was
+/// formed.
+///
+///     use numerals::japanese::ordinal::{from_ordinal, Ordinal, OrdinalValue};
+///
+///     let x = from_ordinal("第三");
+///     assert_eq!(x, Ok(OrdinalValue { value: 3, ordinal: Ordinal::Dai }));
+///
+///     let x = from_ordinal("12番目");
+///     assert_eq!(x, Ok(OrdinalValue { value: 12, ordinal: Ordinal::Banme }));
+pub fn from_ordinal(input: &str) -> Result<OrdinalValue, NumeralError> {
+    if input.is_empty() {
+        return Err(NumeralError::Empty);
+    }
+
+    let (numeral, ordinal, offset) = if let Some(numeral) = input.strip_prefix('第') {
+        (numeral, Ordinal::Dai, 1)
+    } else if let Some(numeral) = input.strip_suffix("番目") {
+        (numeral, Ordinal::Banme, 0)
+    } else if let Some(numeral) = input.strip_suffix("つ目") {
+        (numeral, Ordinal::Tsume, 0)
+    } else {
+        let (index, ch) = input
+            .chars()
+            .enumerate()
+            .find(|&(_, ch)| {
+                arabic::digit_value(ch).is_none() && to_arabic(&ch.to_string()).is_err()
+            })
+            .unwrap_or((0, input.chars().next().unwrap_or_default()));
+        return Err(NumeralError::InvalidCharacter { ch, index });
+    };
+
+    if numeral.is_empty() {
+        return Err(NumeralError::Empty);
+    }
+    let value = arabic::parse(numeral)
+        .or_else(|_| to_arabic(numeral))
+        .map_err(|error| match error {
+            NumeralError::InvalidCharacter { ch, index } => NumeralError::InvalidCharacter {
+                ch,
+                index: index + offset,
+            },
+            NumeralError::InvalidSequence { ch, index } => NumeralError::InvalidSequence {
+                ch,
+                index: index + offset,
+            },
+            error => error,
+        })?;
+
+    let max = match ordinal {
+        Ordinal::Tsume => 9,
+        Ordinal::Dai | Ordinal::Banme => u64::MAX,
+    };
+    if value == 0 || value > max {
+        return Err(NumeralError::OutOfRange { value, min: 1, max });
+    }
+    Ok(OrdinalValue { value, ordinal })
+}
+
+#[cfg(test)]
+mod test_to_ordinal {
+    use crate::japanese::ordinal::{to_ordinal, Ordinal};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_to_ordinal() {
+        let x = to_ordinal(1, Ordinal::Dai, false);
+        assert_eq!(x.unwrap(), "第一");
+
+        let x = to_ordinal(1994, Ordinal::Dai, false);
+        assert_eq!(x.unwrap(), "第千九百九十四");
+
+        let x = to_ordinal(25, Ordinal::Banme, false);
+        assert_eq!(x.unwrap(), "二十五番目");
+
+        let x = to_ordinal(9, Ordinal::Tsume, false);
+        assert_eq!(x.unwrap(), "九つ目");
+
+        let x = to_ordinal(25, Ordinal::Banme, true);
+        assert_eq!(x.unwrap(), "25番目");
+
+        let x = to_ordinal(1, Ordinal::Tsume, true);
+        assert_eq!(x.unwrap(), "1つ目");
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let x = to_ordinal(0, Ordinal::Dai, false);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::OutOfRange {
+                value: 0,
+                min: 1,
+                max: u64::MAX
+            }
+        );
+
+        let x = to_ordinal(10, Ordinal::Tsume, false);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::OutOfRange {
+                value: 10,
+                min: 1,
+                max: 9
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_from_ordinal {
+    use crate::japanese::ordinal::{from_ordinal, to_ordinal, Ordinal, OrdinalValue};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_from_ordinal() {
+        let x = from_ordinal("第三");
+        assert_eq!(
+            x.unwrap(),
+            OrdinalValue {
+                value: 3,
+                ordinal: Ordinal::Dai
+            }
+        );
+
+        let x = from_ordinal("第12");
+        assert_eq!(
+            x.unwrap(),
+            OrdinalValue {
+                value: 12,
+                ordinal: Ordinal::Dai
+            }
+        );
+
+        let x = from_ordinal("三番目");
+        assert_eq!(
+            x.unwrap(),
+            OrdinalValue {
+                value: 3,
+                ordinal: Ordinal::Banme
+            }
+        );
+
+        let x = from_ordinal("３つ目");
+        assert_eq!(
+            x.unwrap(),
+            OrdinalValue {
+                value: 3,
+                ordinal: Ordinal::Tsume
+            }
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let ordinals = [Ordinal::Dai, Ordinal::Banme, Ordinal::Tsume];
+        for &ordinal in &ordinals {
+            for &arabic_digits in &[false, true] {
+                for value in 1..=2000 {
+                    if let Ok(numeral) = to_ordinal(value, ordinal, arabic_digits) {
+                        assert_eq!(from_ordinal(&numeral), Ok(OrdinalValue { value, ordinal }));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let x = from_ordinal("");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = from_ordinal("第");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = from_ordinal("三");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter {
+                ch: '三', index: 0
+            }
+        );
+
+        let x = from_ordinal("三番");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter {
+                ch: '番', index: 1
+            }
+        );
+
+        let x = from_ordinal("第三章");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter {
+                ch: '章', index: 2
+            }
+        );
+
+        let x = from_ordinal("十つ目");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::OutOfRange {
+                value: 10,
+                min: 1,
+                max: 9
+            }
+        );
+
+        let x = from_ordinal("第〇");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::OutOfRange {
+                value: 0,
+                min: 1,
+                max: u64::MAX
+            }
+        );
+    }
+}
@@ -0,0 +1,211 @@
+/*!
+Convert the traditional decimal fractions, as used for rates and batting
+averages, and vice-versa.
+
+A tenth is written 割, a hundredth 分, a thousandth 厘 and a ten-thousandth
+毛, each after its digit, so that 0.325 is written 三割二分五厘. The values
+are fixed-point, counted in ten-thousandths: 0.325 is 3250.
+*/
+
+use alloc::string::String;
+
+use crate::arabic;
+use crate::error::NumeralError;
+use crate::japanese::{to_arabic, to_japanese, COMMON};
+
+/// The number of ten-thousandths in one, by which the values are scaled.
+pub const SCALE: u64 = 10_000;
+
+/// The units, with their values in ten-thousandths.
+const UNITS: [(char, u64); 4] = [('割', 1000), ('分', 100), ('厘', 10), ('毛', 1)];
+
+/// Write a value given in ten-thousandths with the decimal fractions.
+///
+/// Values of one or more are written as a number of tenths, as in 十割.
+///
+///     use numerals::japanese::wari::to_wari;
+///
+///     assert_eq!(to_wari(3250), "三割二分五厘");
+///     assert_eq!(to_wari(305), "三分五毛");
+///     assert_eq!(to_wari(10_000), "十割");
+pub fn to_wari(input: u64) -> String {
+    if input == 0 {
+        return COMMON.digits[0].into();
+    }
+
+    let mut ret = String::new();
+    let mut rest = input;
+    for &(ch, unit) in UNITS.iter() {
+        let digit = rest / unit;
+        if digit > 0 {
+            ret.push_str(&to_japanese(digit));
+            ret.push(ch);
+        }
+        rest %= unit;
+    }
+    ret
+}
+
+/// Convert decimal fractions to their value, in ten-thousandths.
+///
+/// The digits can be written in kanji or in arabic digits, as in 3割2分5厘.
+/// The units should come in decreasing order, each after its digit.
+///
+///     use numerals::japanese::wari::{from_wari, SCALE};
+///
+///     assert_eq!(from_wari("三割二分五厘"), Ok(3250));
+///     assert_eq!(from_wari("3割2分5厘"), Ok(3250));
+///     assert_eq!(from_wari("十割"), Ok(SCALE));
+pub fn from_wari(input: &str) -> Result<u64, NumeralError> {
+    if input.is_empty() {
+        return Err(NumeralError::Empty);
+    }
+
+    if input.chars().count() == 1 && to_arabic(input) == Ok(0) {
+        return Ok(0);
+    }
+
+    let mut total: u64 = 0;
+    let mut last_unit = u64::MAX;
+    // The number before the next unit, and where it starts
+    let mut number = String::new();
+    let mut start = 0;
+    for (index, ch) in input.chars().enumerate() {
+        let unit = match UNITS.iter().find(|&&(unit, _)| unit == ch) {
+            Some(&(_, unit)) => unit,
+            None => {
+                number.push(ch);
+                continue;
+            }
+        };
+
+        let invalid = NumeralError::InvalidSequence { ch, index };
+        if unit >= last_unit || number.is_empty() {
+            return Err(invalid);
+        }
+        let value = arabic::parse(&number)
+            .or_else(|_| to_arabic(&number))
+            .map_err(|error| match error {
+                NumeralError::InvalidCharacter { ch, index } => NumeralError::InvalidCharacter {
+                    ch,
+                    index: start + index,
+                },
+                NumeralError::InvalidSequence { ch, index } => NumeralError::InvalidSequence {
+                    ch,
+                    index: start + index,
+                },
+                error => error,
+            })?;
+        // Only the tenths can be counted beyond nine
+        if value == 0 || (unit < 1000 && value > 9) {
+            return Err(invalid);
+        }
+        total = value
+            .checked_mul(unit)
+            .and_then(|value| total.checked_add(value))
+            .ok_or(NumeralError::Overflow)?;
+        last_unit = unit;
+        number.clear();
+        start = index + 1;
+    }
+
+    match number.chars().next() {
+        Some(ch) => Err(NumeralError::InvalidSequence { ch, index: start }),
+        None => Ok(total),
+    }
+}
+
+#[cfg(test)]
+mod test_to_wari {
+    use crate::japanese::wari::to_wari;
+
+    #[test]
+    fn test_to_wari() {
+        assert_eq!(to_wari(0), "零");
+        assert_eq!(to_wari(1), "一毛");
+        assert_eq!(to_wari(3250), "三割二分五厘");
+        assert_eq!(to_wari(3333), "三割三分三厘三毛");
+        assert_eq!(to_wari(3005), "三割五毛");
+        assert_eq!(to_wari(500), "五分");
+        assert_eq!(to_wari(10_000), "十割");
+        assert_eq!(to_wari(12_345), "十二割三分四厘五毛");
+    }
+}
+
+#[cfg(test)]
+mod test_from_wari {
+    use crate::japanese::wari::{from_wari, to_wari};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_from_wari() {
+        let x = from_wari("三割二分五厘");
+        assert_eq!(x.unwrap(), 3250);
+
+        let x = from_wari("3割2分5厘");
+        assert_eq!(x.unwrap(), 3250);
+
+        let x = from_wari("３割");
+        assert_eq!(x.unwrap(), 3000);
+
+        let x = from_wari("五毛");
+        assert_eq!(x.unwrap(), 5);
+
+        let x = from_wari("零");
+        assert_eq!(x.unwrap(), 0);
+
+        let x = from_wari("十二割");
+        assert_eq!(x.unwrap(), 12_000);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for value in 0..=20_000 {
+            assert_eq!(from_wari(&to_wari(value)), Ok(value));
+        }
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let x = from_wari("");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = from_wari("割");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '割', index: 0
+            }
+        );
+
+        let x = from_wari("二分三割");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '割', index: 3
+            }
+        );
+
+        let x = from_wari("十二分");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '分', index: 2
+            }
+        );
+
+        let x = from_wari("三割二");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '二', index: 2
+            }
+        );
+
+        let x = from_wari("三割x分");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: 'x', index: 2 }
+        );
+    }
+}
@@ -0,0 +1,928 @@
+/*!
+Convert korean numerals to arabic, and vice-versa.
+
+The Sino-Korean numerals, used for most counting, are spelled in Hangul with
+the digits 일 to 구, each followed by the unit it multiplies: 십, 백 or 천. As in
+japanese, 일 is left out before a unit, so that 1994 is written 천구백구십사.
+
+Larger values are grouped by myriads: 만 for 10^4, 억 for 10^8, 조 for 10^12
+and 경 for 10^16, with 일 left out before 만 only, as in 만 or 일억. Following
+the korean orthography, a space is written after each myriad, as in
+삼억 이천삼백만 for 323,000,000.
+
+Zero is written 영, or 공 when reading out the digits of a phone number.
+
+Legal and ceremonial documents still write the numerals in Hanja, with the
+chinese characters of the digits and units and the myriads 萬, 億, 兆 and 京,
+following the same rules without the spaces, as in 三億二千三百萬. Which one is
+written is selected with `Script`, and either is parsed.
+
+The native korean numerals, used to count hours, ages or things with counters,
+only go up to 99, as in 아흔아홉. Before a counter, one to four and twenty take
+their attributive forms 한, 두, 세, 네 and 스무, as in 한 개 or 스무 살. They are
+converted by `to_native` and `from_native`, and `parse_any` tells which of the
+two systems a numeral belongs to.
+*/
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::cjk::{self, UNIT_VALUES};
+use crate::error::NumeralError;
+use crate::system::{NumeralSystem, Options, Strictness};
+
+/// The characters of a script.
+struct Characters {
+    digits: [char; 10],
+    units: [char; 3],
+    myriads: [char; 4],
+    /// Whether a space is written after each myriad.
+    spaced: bool,
+}
+
+/// The Hangul spellings.
+const HANGUL: Characters = Characters {
+    digits: ['영', '일', '이', '삼', '사', '오', '육', '칠', '팔', '구'],
+    units: ['천', '백', '십'],
+    myriads: ['경', '조', '억', '만'],
+    spaced: true,
+};
+
+/// The Hanja, sharing their digits and units with the chinese and japanese
+/// numerals.
+const HANJA: Characters = Characters {
+    digits: cjk::DIGITS,
+    units: cjk::UNITS,
+    myriads: ['京', '兆', '億', '萬'],
+    spaced: false,
+};
+
+/// The values of the myriads, from the largest.
+const MYRIAD_VALUES: [u64; 4] = [
+    10_000_000_000_000_000,
+    1_000_000_000_000,
+    100_000_000,
+    10_000,
+];
+
+/// The native units, from 하나 to 아홉.
+const NATIVE_UNITS: [&str; 9] = [
+    "하나", "둘", "셋", "넷", "다섯", "여섯", "일곱", "여덟", "아홉",
+];
+
+/// The attributive forms of the native units, from 한 to 네, the others being
+/// the same.
+const ATTRIBUTIVE_UNITS: [&str; 4] = ["한", "두", "세", "네"];
+
+/// The native tens, from 열 to 아흔.
+const NATIVE_TENS: [&str; 9] = [
+    "열", "스물", "서른", "마흔", "쉰", "예순", "일흔", "여든", "아흔",
+];
+
+/// The attributive form of 스물, only written by itself.
+const SEUMU: &str = "스무";
+
+/// The korean numeral systems.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum KoreanSystem {
+    /// The Sino-Korean numerals, as in 삼십 or 三十.
+    Sino,
+    /// The native korean numerals, as in 서른.
+    Native,
+}
+
+/// The script the Sino-Korean numerals are written in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Script {
+    /// Hangul, as in 천구백구십사.
+    #[default]
+    Hangul,
+    /// Hanja, as in 千九百九十四.
+    Hanja,
+}
+
+impl Script {
+    /// The characters of the script.
+    fn characters(self) -> &'static Characters {
+        match self {
+            Script::Hangul => &HANGUL,
+            Script::Hanja => &HANJA,
+        }
+    }
+}
+
+/// A korean numeral, as parsed by `parse_any`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct KoreanValue {
+    pub value: u64,
+    /// The system the numeral belongs to.
+    pub system: KoreanSystem,
+    /// Whether the numeral is in its attributive form, as in 한 or 스무.
+    pub attributive: bool,
+}
+
+/// The value of a digit, in either script, zero being written 영 or 공, and 零
+/// or 〇.
+fn digit_value(ch: char) -> Option<u64> {
+    match ch {
+        '공' => Some(0),
+        _ => HANGUL
+            .digits
+            .iter()
+            .position(|&digit| digit == ch)
+            .map(|digit| digit as u64)
+            .or_else(|| cjk::digit_value(ch)),
+    }
+}
+
+/// The value of a unit, in either script.
+fn unit_value(ch: char) -> Option<u64> {
+    HANGUL
+        .units
+        .iter()
+        .position(|&unit| unit == ch)
+        .map(|position| UNIT_VALUES[position])
+        .or_else(|| cjk::unit_value(ch))
+}
+
+/// The value of a myriad, in either script, 萬 being written 万 as well.
+fn myriad_value(ch: char) -> Option<u64> {
+    let ch = if ch == '万' { '萬' } else { ch };
+    HANGUL
+        .myriads
+        .iter()
+        .position(|&myriad| myriad == ch)
+        .or_else(|| HANJA.myriads.iter().position(|&myriad| myriad == ch))
+        .map(|position| MYRIAD_VALUES[position])
+}
+
+/// Convert `input` to its Sino-Korean numeral, in Hangul.
+///
+///     use numerals::korean::to_korean;
+///
+///     assert_eq!(to_korean(1994), "천구백구십사");
+///     assert_eq!(to_korean(10_000), "만");
+///     assert_eq!(to_korean(323_000_000), "삼억 이천삼백만");
+///     assert_eq!(to_korean(0), "영");
+pub fn to_korean(input: u64) -> String {
+    to_korean_with(input, Script::Hangul)
+}
+
+/// Convert `input` to its Sino-Korean numeral, in the given script.
+///
+///     use numerals::korean::{to_korean_with, Script};
+///
+///     assert_eq!(to_korean_with(1994, Script::Hanja), "千九百九十四");
+///     assert_eq!(to_korean_with(323_000_000, Script::Hanja), "三億二千三百萬");
+pub fn to_korean_with(input: u64, script: Script) -> String {
+    let characters = script.characters();
+    if input == 0 {
+        return String::from(characters.digits[0]);
+    }
+
+    let mut groups = Vec::new();
+    let mut rest = input;
+    for (&myriad, &value) in characters.myriads.iter().zip(MYRIAD_VALUES.iter()) {
+        let group = rest / value;
+        rest %= value;
+        if group == 0 {
+            continue;
+        }
+        // 일 is only left out before 만
+        let mut ret = if group == 1 && value == 10_000 {
+            String::new()
+        } else {
+            write_group(group, characters)
+        };
+        ret.push(myriad);
+        groups.push(ret);
+    }
+    if rest > 0 {
+        groups.push(write_group(rest, characters));
+    }
+    groups.join(if characters.spaced { " " } else { "" })
+}
+
+/// Write a value below 10^4, without its myriad.
+fn write_group(input: u64, characters: &Characters) -> String {
+    let mut ret = String::new();
+    let mut rest = input;
+    for (&unit, &value) in characters.units.iter().zip(UNIT_VALUES.iter()) {
+        let digit = rest / value;
+        rest %= value;
+        if digit > 1 {
+            ret.push(characters.digits[digit as usize]);
+        }
+        if digit > 0 {
+            ret.push(unit);
+        }
+    }
+    if rest > 0 {
+        ret.push(characters.digits[rest as usize]);
+    }
+    ret
+}
+
+/// Convert a Sino-Korean numeral to its value.
+///
+/// The 일 left out before the units and 만 is accepted as well, as in 일만 or
+/// 일백, and so are the numerals written without spaces, and in Hanja.
+///
+///     use numerals::korean::to_arabic;
+///
+///     assert_eq!(to_arabic("천구백구십사"), Ok(1994));
+///     assert_eq!(to_arabic("삼억 이천삼백만"), Ok(323_000_000));
+///     assert_eq!(to_arabic("일만이천"), Ok(12_000));
+///     assert_eq!(to_arabic("三億二千三百萬"), Ok(323_000_000));
+///     assert!(to_arabic("십천").is_err());
+pub fn to_arabic(input: &str) -> Result<u64, NumeralError> {
+    to_arabic_with_strictness(input, Strictness::Standard)
+}
+
+/// `to_arabic`, following the rules as closely as asked.
+///
+/// Strictly, only the numerals written as by `to_korean_with` are accepted, in
+/// either script, with their spaces in Hangul, and with 일 left out before the
+/// units and 만.
+///
+///     use numerals::korean::to_arabic_with_strictness;
+///     use numerals::Strictness;
+///
+///     assert_eq!(to_arabic_with_strictness("만 이천", Strictness::Strict), Ok(12_000));
+///     assert!(to_arabic_with_strictness("일만 이천", Strictness::Strict).is_err());
+pub fn to_arabic_with_strictness(input: &str, strictness: Strictness) -> Result<u64, NumeralError> {
+    if input.is_empty() {
+        return Err(NumeralError::Empty);
+    }
+
+    let value = from_sino(input)?;
+    if strictness == Strictness::Strict {
+        if let Some((index, ch)) = find_non_canonical(input, value) {
+            return Err(NumeralError::InvalidSequence { ch, index });
+        }
+    }
+    Ok(value)
+}
+
+/// Find where a numeral differs from the one written by `to_korean_with` for
+/// its value, in either script.
+fn find_non_canonical(input: &str, value: u64) -> Option<(usize, char)> {
+    // The length of the longest prefix shared with a canonical numeral
+    let length = [Script::Hangul, Script::Hanja]
+        .iter()
+        .map(|&script| {
+            let canonical = to_korean_with(value, script);
+            input
+                .chars()
+                .zip(canonical.chars())
+                .take_while(|(a, b)| a == b)
+                .count()
+                + usize::from(canonical == input)
+        })
+        .max()?;
+    let count = input.chars().count();
+    if length > count {
+        return None;
+    }
+    let index = length.min(count - 1);
+    Some((index, input.chars().nth(index)?))
+}
+
+/// The value of a Sino-Korean numeral, in either script.
+fn from_sino(input: &str) -> Result<u64, NumeralError> {
+    let mut chars = input.chars();
+    if let (Some(ch), None) = (chars.next(), chars.next()) {
+        if digit_value(ch) == Some(0) {
+            return Ok(0);
+        }
+    }
+
+    // The value of the groups completed by their myriad, and of the units of
+    // the current group
+    let mut total: u64 = 0;
+    let mut group: u64 = 0;
+    let mut digit: Option<u64> = None;
+    let mut last_unit = 10_000;
+    let mut last_myriad = u64::MAX;
+    let mut last: Option<char> = None;
+    let checked = |value: Option<u64>| value.ok_or(NumeralError::Overflow);
+
+    for (index, ch) in input.chars().enumerate() {
+        let invalid = NumeralError::InvalidSequence { ch, index };
+        if ch == ' ' {
+            // A space only follows a myriad
+            if last.and_then(myriad_value).is_none() {
+                return Err(invalid);
+            }
+        } else if let Some(value) = digit_value(ch) {
+            // Zero is only written by itself
+            if value == 0 || digit.is_some() {
+                return Err(invalid);
+            }
+            digit = Some(value);
+        } else if let Some(unit) = unit_value(ch) {
+            if unit >= last_unit {
+                return Err(invalid);
+            }
+            group += digit.take().unwrap_or(1) * unit;
+            last_unit = unit;
+        } else if let Some(myriad) = myriad_value(ch) {
+            if myriad >= last_myriad {
+                return Err(invalid);
+            }
+            let value = match group + digit.take().unwrap_or(0) {
+                // 일 is only left out before 만
+                0 if myriad == 10_000 => 1,
+                0 => return Err(invalid),
+                value => value,
+            };
+            total = checked(
+                value
+                    .checked_mul(myriad)
+                    .and_then(|value| total.checked_add(value)),
+            )?;
+            group = 0;
+            last_unit = 10_000;
+            last_myriad = myriad;
+        } else {
+            return Err(NumeralError::InvalidCharacter { ch, index });
+        }
+        last = Some(ch);
+    }
+
+    if last == Some(' ') {
+        let index = input.chars().count() - 1;
+        return Err(NumeralError::InvalidSequence { ch: ' ', index });
+    }
+    checked(total.checked_add(group + digit.unwrap_or(0)))
+}
+
+/// Convert `input` to its native korean numeral, in its attributive form if
+/// asked, from 1 to 99.
+///
+///     use numerals::korean::to_native;
+///
+///     assert_eq!(to_native(1, false).unwrap(), "하나");
+///     assert_eq!(to_native(1, true).unwrap(), "한");
+///     assert_eq!(to_native(20, true).unwrap(), "스무");
+///     assert_eq!(to_native(99, false).unwrap(), "아흔아홉");
+///     assert!(to_native(100, false).is_err());
+pub fn to_native(input: u64, attributive: bool) -> Result<String, NumeralError> {
+    if input == 0 || input > 99 {
+        return Err(NumeralError::OutOfRange {
+            value: input,
+            min: 1,
+            max: 99,
+        });
+    }
+
+    let (tens, units) = ((input / 10) as usize, (input % 10) as usize);
+    if attributive && input == 20 {
+        return Ok(String::from(SEUMU));
+    }
+    let mut ret = String::new();
+    if tens > 0 {
+        ret.push_str(NATIVE_TENS[tens - 1]);
+    }
+    if units > 0 {
+        ret.push_str(match ATTRIBUTIVE_UNITS.get(units - 1) {
+            Some(unit) if attributive => unit,
+            _ => NATIVE_UNITS[units - 1],
+        });
+    }
+    Ok(ret)
+}
+
+/// Convert a native korean numeral to its value, in either form.
+///
+///     use numerals::korean::from_native;
+///
+///     assert_eq!(from_native("서른다섯"), Ok(35));
+///     assert_eq!(from_native("스물한"), Ok(21));
+///     assert_eq!(from_native("스무"), Ok(20));
+///     assert!(from_native("하나열").is_err());
+pub fn from_native(input: &str) -> Result<u64, NumeralError> {
+    native_value(input).map(|(value, _)| value)
+}
+
+/// The value of a native korean numeral, and whether it is attributive.
+fn native_value(input: &str) -> Result<(u64, bool), NumeralError> {
+    if input.is_empty() {
+        return Err(NumeralError::Empty);
+    }
+    if input == SEUMU {
+        return Ok((20, true));
+    }
+
+    // The index of the next character, and the rest of the numeral
+    let mut index = 0;
+    let mut rest = input;
+    let mut value = 0;
+    if let Some((tens, after)) = strip_word(rest, &NATIVE_TENS) {
+        value = 10 * tens;
+        index += rest.chars().count() - after.chars().count();
+        rest = after;
+    }
+    let mut attributive = false;
+    if let Some((units, after)) = strip_word(rest, &NATIVE_UNITS) {
+        value += units;
+        index += rest.chars().count() - after.chars().count();
+        rest = after;
+    } else if let Some((units, after)) = strip_word(rest, &ATTRIBUTIVE_UNITS) {
+        value += units;
+        attributive = true;
+        index += rest.chars().count() - after.chars().count();
+        rest = after;
+    }
+
+    if let Some(ch) = rest.chars().next() {
+        // A word out of place, as in 하나열, or which cannot follow, as in 스무하나
+        let words = NATIVE_TENS
+            .iter()
+            .chain(NATIVE_UNITS.iter())
+            .chain(ATTRIBUTIVE_UNITS.iter());
+        let misplaced = words.clone().any(|word| rest.starts_with(word))
+            || rest.starts_with(SEUMU)
+            || input.starts_with(SEUMU);
+        if misplaced {
+            // Nothing is stripped before what follows 스무
+            let index = if index == 0 {
+                SEUMU.chars().count()
+            } else {
+                index
+            };
+            let ch = input.chars().nth(index).unwrap_or(ch);
+            return Err(NumeralError::InvalidSequence { ch, index });
+        }
+        return Err(NumeralError::InvalidCharacter { ch, index });
+    }
+    Ok((value, attributive))
+}
+
+/// Strip the word of `words` beginning the input, if any, and give its value,
+/// the first word being worth one.
+fn strip_word<'a>(input: &'a str, words: &[&str]) -> Option<(u64, &'a str)> {
+    words
+        .iter()
+        .enumerate()
+        .find_map(|(position, word)| Some((position as u64 + 1, input.strip_prefix(word)?)))
+}
+
+/// Parse a korean numeral of either system, and tell which one it belongs to.
+///
+///     use numerals::korean::{parse_any, KoreanSystem};
+///
+///     let x = parse_any("서른").unwrap();
+///     assert_eq!((x.value, x.system), (30, KoreanSystem::Native));
+///
+///     let x = parse_any("삼십").unwrap();
+///     assert_eq!((x.value, x.system), (30, KoreanSystem::Sino));
+pub fn parse_any(input: &str) -> Result<KoreanValue, NumeralError> {
+    let native = match native_value(input) {
+        Ok((value, attributive)) => {
+            return Ok(KoreanValue {
+                value,
+                system: KoreanSystem::Native,
+                attributive,
+            })
+        }
+        Err(error) => error,
+    };
+    let sino = match to_arabic(input) {
+        Ok(value) => {
+            return Ok(KoreanValue {
+                value,
+                system: KoreanSystem::Sino,
+                attributive: false,
+            })
+        }
+        Err(error) => error,
+    };
+
+    // The error of the system the numeral seems to belong to, from its first
+    // character
+    let first = input.chars().next().unwrap_or_default();
+    let is_sino = digit_value(first).is_some()
+        || unit_value(first).is_some()
+        || myriad_value(first).is_some();
+    Err(if is_sino { sino } else { native })
+}
+
+/// The Sino-Korean numeral system, for use through `NumeralSystem`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Korean;
+
+impl NumeralSystem for Korean {
+    fn name(&self) -> &'static str {
+        "korean"
+    }
+
+    fn parse(&self, input: &str) -> Result<u64, NumeralError> {
+        to_arabic(input)
+    }
+
+    fn parse_with(&self, input: &str, options: &Options) -> Result<u64, NumeralError> {
+        to_arabic_with_strictness(input, options.strictness)
+    }
+
+    fn format(&self, value: u64, _options: &Options) -> Result<String, NumeralError> {
+        Ok(to_korean(value))
+    }
+}
+
+#[cfg(test)]
+mod test_to_korean {
+    use crate::korean::to_korean;
+
+    #[test]
+    fn test_to_korean() {
+        assert_eq!(to_korean(0), "영");
+        assert_eq!(to_korean(1), "일");
+        assert_eq!(to_korean(10), "십");
+        assert_eq!(to_korean(11), "십일");
+        assert_eq!(to_korean(20), "이십");
+        assert_eq!(to_korean(100), "백");
+        assert_eq!(to_korean(1994), "천구백구십사");
+        assert_eq!(to_korean(2024), "이천이십사");
+        assert_eq!(to_korean(10_000), "만");
+        assert_eq!(to_korean(10_001), "만 일");
+        assert_eq!(to_korean(110_000), "십일만");
+        assert_eq!(to_korean(12_345_678), "천이백삼십사만 오천육백칠십팔");
+        assert_eq!(to_korean(100_000_000), "일억");
+        assert_eq!(to_korean(100_010_000), "일억 만");
+        assert_eq!(
+            to_korean(1_234_567_898),
+            "십이억 삼천사백오십육만 칠천팔백구십팔"
+        );
+        assert_eq!(to_korean(1_000_000_000_000), "일조");
+        assert_eq!(
+            to_korean(u64::MAX),
+            "천팔백사십사경 육천칠백사십사조 칠백삼십칠억 구백오십오만 천육백십오"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_to_arabic {
+    use crate::korean::{to_arabic, to_arabic_with_strictness, to_korean};
+    use crate::{NumeralError, Strictness};
+
+    #[test]
+    fn test_to_arabic() {
+        let x = to_arabic("천구백구십사");
+        assert_eq!(x.unwrap(), 1994);
+
+        let x = to_arabic("영");
+        assert_eq!(x.unwrap(), 0);
+
+        let x = to_arabic("공");
+        assert_eq!(x.unwrap(), 0);
+
+        let x = to_arabic("만");
+        assert_eq!(x.unwrap(), 10_000);
+
+        let x = to_arabic("일만");
+        assert_eq!(x.unwrap(), 10_000);
+
+        let x = to_arabic("일백일십");
+        assert_eq!(x.unwrap(), 110);
+
+        let x = to_arabic("천이백삼십사만오천육백칠십팔");
+        assert_eq!(x.unwrap(), 12_345_678);
+
+        let x = to_arabic("십이억 삼천사백오십육만 칠천팔백구십팔");
+        assert_eq!(x.unwrap(), 1_234_567_898);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for value in (0..=20_000).chain((0..64).map(|shift| 1u64 << shift)) {
+            assert_eq!(to_arabic(&to_korean(value)), Ok(value));
+            assert_eq!(
+                to_arabic_with_strictness(&to_korean(value), Strictness::Strict),
+                Ok(value)
+            );
+        }
+        assert_eq!(to_arabic(&to_korean(u64::MAX)), Ok(u64::MAX));
+    }
+
+    #[test]
+    fn test_strict() {
+        let x = to_arabic_with_strictness("일만", Strictness::Strict);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '일', index: 0
+            }
+        );
+
+        let x = to_arabic_with_strictness("만이천", Strictness::Strict);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '이', index: 1
+            }
+        );
+
+        let x = to_arabic_with_strictness("억", Strictness::Strict);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '억', index: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let x = to_arabic("");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = to_arabic("십천");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '천', index: 1
+            }
+        );
+
+        let x = to_arabic("이삼");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '삼', index: 1
+            }
+        );
+
+        let x = to_arabic("십영");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '영', index: 1
+            }
+        );
+
+        let x = to_arabic("만억");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '억', index: 1
+            }
+        );
+
+        let x = to_arabic("억");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '억', index: 0
+            }
+        );
+
+        let x = to_arabic("삼천 사백");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: ' ', index: 2 }
+        );
+
+        let x = to_arabic("삼만 ");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: ' ', index: 2 }
+        );
+
+        let x = to_arabic("삼만x");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: 'x', index: 2 }
+        );
+
+        let x = to_arabic("이만경");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '경', index: 2
+            }
+        );
+
+        let x = to_arabic("이천경");
+        assert_eq!(x.unwrap_err(), NumeralError::Overflow);
+    }
+}
+
+#[cfg(test)]
+mod test_hanja {
+    use crate::korean::{to_arabic, to_arabic_with_strictness, to_korean_with, Script};
+    use crate::{NumeralError, Strictness};
+
+    #[test]
+    fn test_to_korean_with() {
+        assert_eq!(to_korean_with(0, Script::Hanja), "零");
+        assert_eq!(to_korean_with(10, Script::Hanja), "十");
+        assert_eq!(to_korean_with(1994, Script::Hanja), "千九百九十四");
+        assert_eq!(to_korean_with(10_001, Script::Hanja), "萬一");
+        assert_eq!(
+            to_korean_with(1_234_567_898, Script::Hanja),
+            "十二億三千四百五十六萬七千八百九十八"
+        );
+        assert_eq!(
+            to_korean_with(1_234_567_898, Script::Hangul),
+            "십이억 삼천사백오십육만 칠천팔백구십팔"
+        );
+    }
+
+    #[test]
+    fn test_to_arabic() {
+        let x = to_arabic("千九百九十四");
+        assert_eq!(x.unwrap(), 1994);
+
+        let x = to_arabic("一萬");
+        assert_eq!(x.unwrap(), 10_000);
+
+        let x = to_arabic("三億 二千三百万");
+        assert_eq!(x.unwrap(), 323_000_000);
+
+        let x = to_arabic("〇");
+        assert_eq!(x.unwrap(), 0);
+
+        for value in (0..=20_000).chain((0..64).map(|shift| 1u64 << shift)) {
+            let x = to_korean_with(value, Script::Hanja);
+            assert_eq!(to_arabic_with_strictness(&x, Strictness::Strict), Ok(value));
+        }
+    }
+
+    #[test]
+    fn test_strict() {
+        let x = to_arabic_with_strictness("三億 二千三百萬", Strictness::Strict);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: ' ', index: 2 }
+        );
+
+        let x = to_arabic_with_strictness("一千", Strictness::Strict);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '一', index: 0
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_native {
+    use crate::korean::{from_native, parse_any, to_native, KoreanSystem, KoreanValue};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_to_native() {
+        let x = to_native(1, false);
+        assert_eq!(x.unwrap(), "하나");
+
+        let x = to_native(4, true);
+        assert_eq!(x.unwrap(), "네");
+
+        let x = to_native(5, true);
+        assert_eq!(x.unwrap(), "다섯");
+
+        let x = to_native(10, false);
+        assert_eq!(x.unwrap(), "열");
+
+        let x = to_native(20, false);
+        assert_eq!(x.unwrap(), "스물");
+
+        let x = to_native(20, true);
+        assert_eq!(x.unwrap(), "스무");
+
+        let x = to_native(21, true);
+        assert_eq!(x.unwrap(), "스물한");
+
+        let x = to_native(73, true);
+        assert_eq!(x.unwrap(), "일흔세");
+
+        let x = to_native(0, false);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::OutOfRange {
+                value: 0,
+                min: 1,
+                max: 99
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_native() {
+        let x = from_native("아흔아홉");
+        assert_eq!(x.unwrap(), 99);
+
+        let x = from_native("열두");
+        assert_eq!(x.unwrap(), 12);
+
+        let x = from_native("예순");
+        assert_eq!(x.unwrap(), 60);
+
+        for value in 1..=99 {
+            for &attributive in &[false, true] {
+                let x = to_native(value, attributive).unwrap();
+                assert_eq!(from_native(&x), Ok(value));
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let x = from_native("");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = from_native("하나열");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '열', index: 2
+            }
+        );
+
+        let x = from_native("열열");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '열', index: 1
+            }
+        );
+
+        let x = from_native("스무하나");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '하', index: 2
+            }
+        );
+
+        let x = from_native("열x");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: 'x', index: 1 }
+        );
+    }
+
+    #[test]
+    fn test_parse_any() {
+        let x = parse_any("스무");
+        assert_eq!(
+            x.unwrap(),
+            KoreanValue {
+                value: 20,
+                system: KoreanSystem::Native,
+                attributive: true
+            }
+        );
+
+        let x = parse_any("일곱");
+        assert_eq!(
+            x.unwrap(),
+            KoreanValue {
+                value: 7,
+                system: KoreanSystem::Native,
+                attributive: false
+            }
+        );
+
+        let x = parse_any("일");
+        assert_eq!(
+            x.unwrap(),
+            KoreanValue {
+                value: 1,
+                system: KoreanSystem::Sino,
+                attributive: false
+            }
+        );
+
+        let x = parse_any("삼십x");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: 'x', index: 2 }
+        );
+
+        let x = parse_any("서른x");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: 'x', index: 2 }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_numeral_system {
+    use crate::korean::Korean;
+    use crate::{NumeralSystem, Options};
+
+    #[test]
+    fn test_numeral_system() {
+        assert_eq!(Korean.name(), "korean");
+        assert_eq!(Korean.parse("천구백구십사").unwrap(), 1994);
+        assert_eq!(
+            Korean.format(1994, &Options::default()).unwrap(),
+            "천구백구십사"
+        );
+    }
+}
@@ -0,0 +1,80 @@
+/*!
+Convert numerals between numeral systems and arabic numbers.
+
+The conversion logic lives in one module per numeral system, so that it can be
+used as a dependency as well as from the `numerals` command line utility:
+
+    # #[cfg(feature = "roman")] {
+    use numerals::roman::{to_arabic, to_roman};
+
+    assert_eq!(to_roman(1994, false).unwrap(), "MCMXCIV");
+    assert_eq!(to_arabic("MCMXCIV").unwrap(), 1994);
+    # }
+
+Each system also implements the `NumeralSystem` trait, for code that should
+work regardless of the numeral system:
+
+    # #[cfg(feature = "roman")] {
+    use numerals::{roman::Roman, NumeralSystem, Options};
+
+    fn round_trip(system: &dyn NumeralSystem, value: u64) -> u64 {
+        let numeral = system.format(value, &Options::default()).unwrap();
+        system.parse(&numeral).unwrap()
+    }
+
+    assert_eq!(round_trip(&Roman, 1994), 1994);
+    # }
+
+The systems can also be looked up by name at runtime:
+
+    # #[cfg(feature = "roman")] {
+    let roman = numerals::registry().get("roman").unwrap();
+    assert_eq!(roman.parse("XIV").unwrap(), 14);
+    # }
+
+and guessed from an input of unknown origin with `detect::detect`.
+
+Each numeral system is compiled in only if its cargo feature is enabled.
+The features are named after the modules, and all of them are enabled by
+default.
+
+Without the default `std` feature the crate is `no_std`, and only needs the
+`alloc` crate for the conversions.
+*/
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+pub mod arabic;
+#[cfg(feature = "chinese")]
+pub mod chinese;
+#[cfg(any(feature = "chinese", feature = "japanese", feature = "korean"))]
+pub mod cjk;
+#[cfg(feature = "alloc")]
+pub mod detect;
+#[cfg(feature = "alloc")]
+mod error;
+#[cfg(feature = "greek")]
+pub mod greek;
+#[cfg(feature = "japanese")]
+pub mod japanese;
+#[cfg(feature = "korean")]
+pub mod korean;
+#[cfg(feature = "alloc")]
+mod registry;
+#[cfg(feature = "roman")]
+pub mod roman;
+#[cfg(feature = "alloc")]
+mod system;
+#[cfg(feature = "vietnamese")]
+pub mod vietnamese;
+
+#[cfg(feature = "alloc")]
+pub use crate::error::NumeralError;
+#[cfg(feature = "alloc")]
+pub use crate::registry::{registry, Registry, System};
+#[cfg(feature = "alloc")]
+pub use crate::system::{NumeralSystem, Options, Strictness};
@@ -1,41 +1,89 @@
-#[macro_use]
-extern crate lazy_static;
 use std::env;
+use std::process;
 
-mod roman;
-use crate::roman::{to_arabic, to_roman};
+use numerals::detect::detect;
+use numerals::{arabic, registry, NumeralError, Options, Strictness};
 
 fn main() {
     let mut input = String::new();
-    let mut use_unicode = false;
-    for arg in env::args().skip(1).take(2) {
+    let mut system_name = None;
+    let mut options = Options::default();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
         match arg.as_ref() {
-            "-u" => use_unicode = true,
-            "--unicode" => use_unicode = true,
+            "-u" | "--unicode" => options.unicode = true,
+            "--lowercase" => options.lowercase = true,
+            "--compact" => options.compact = true,
+            "-v" | "--vinculum" => options.vinculum = true,
+            "-n" | "--nulla" => options.nulla = true,
+            "--signed" => options.signed = true,
+            "-c" | "--clock" => options.clock = true,
+            "-a" | "--additive" => options.additive = true,
+            "--strict" => options.strictness = Strictness::Strict,
+            "--lenient" => options.strictness = Strictness::Lenient,
+            "-w" | "--fullwidth" => options.fullwidth = true,
+            "-s" | "--system" => system_name = Some(args.next().unwrap_or_default()),
+            "-j" | "--japanese" => system_name = Some(String::from("japanese")),
+            "--chinese" => system_name = Some(String::from("chinese")),
+            "--greek" => system_name = Some(String::from("greek")),
+            "--korean" => system_name = Some(String::from("korean")),
+            "--vietnamese" => system_name = Some(String::from("vietnamese")),
+            "-l" | "--list" => {
+                for name in registry().names() {
+                    println!("{}", name);
+                }
+                return;
+            }
             _ => input = arg,
         };
     }
 
+    // Numerals are parsed by the system they most likely belong to, and values
+    // are written in roman numerals, unless a system is given
+    let system_name = match system_name {
+        Some(name) => name,
+        None => detect(&input)
+            .iter()
+            .find(|guess| guess.system != "arabic")
+            .map_or("roman", |guess| guess.system)
+            .to_string(),
+    };
+
+    let system = match registry().get(&system_name) {
+        Some(system) => system,
+        None => {
+            eprintln!("Unknown numeral system: {}", system_name);
+            process::exit(1);
+        }
+    };
+
     if input.is_empty() {
         return;
     }
 
-    let is_arabic: bool = match input.parse::<u64>() {
-        Ok(_) => true,
-        Err(_) => false,
+    let (sign, input) = match input.strip_prefix('-') {
+        Some(unsigned) if options.signed => ("-", unsigned),
+        _ => ("", input.as_str()),
     };
 
-    let ret: String;
-    if is_arabic {
-        ret = match to_roman(input.parse::<u64>().unwrap(), use_unicode) {
-            Ok(val) => val,
+    // Values are written in ascii or full-width digits
+    let negative = input
+        .strip_prefix('-')
+        .is_some_and(|unsigned| arabic::parse(unsigned).is_ok());
+    let ret = match arabic::parse(input) {
+        Ok(value) => match system.format(value, &options) {
+            Ok(val) => format!("{}{}", sign, val),
             Err(e) => e.to_string(),
-        };
-    } else {
-        ret = match to_arabic(input) {
-            Ok(val) => val.to_string(),
-            Err(e) => e.to_string(),
-        };
-    }
+        },
+        Err(_) if negative => NumeralError::Negative.to_string(),
+        Err(_) => match system.parse_with(input, &options) {
+            Ok(val) if options.fullwidth => arabic::to_fullwidth(&format!("{}{}", sign, val)),
+            Ok(val) => format!("{}{}", sign, val),
+            Err(e) => match system.suggest(input) {
+                Some(suggestion) => format!("{}, did you mean {}?", e, suggestion),
+                None => e.to_string(),
+            },
+        },
+    };
     println!("{}", ret);
 }
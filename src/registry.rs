@@ -0,0 +1,103 @@
+/*!
+Registry of the available numeral systems.
+
+The registry maps the name of every numeral system compiled in the crate to
+its implementation, so that systems can be enumerated and selected at runtime.
+*/
+
+#[cfg(feature = "chinese")]
+use crate::chinese::Chinese;
+#[cfg(feature = "greek")]
+use crate::greek::Greek;
+#[cfg(feature = "japanese")]
+use crate::japanese::Japanese;
+#[cfg(feature = "korean")]
+use crate::korean::Korean;
+#[cfg(feature = "roman")]
+use crate::roman::Roman;
+use crate::system::NumeralSystem;
+#[cfg(feature = "vietnamese")]
+use crate::vietnamese::Vietnamese;
+
+pub type System = &'static (dyn NumeralSystem + Sync);
+
+pub struct Registry {
+    // Kept in alphabetical order of the system names
+    systems: &'static [System],
+}
+
+impl Registry {
+    /// Look up a numeral system by name.
+    pub fn get(&self, name: &str) -> Option<System> {
+        self.iter().find(|system| system.name() == name)
+    }
+
+    /// The names of the available systems, in alphabetical order.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.iter().map(|system| system.name())
+    }
+
+    /// The available systems, in alphabetical order of their names.
+    pub fn iter(&self) -> impl Iterator<Item = System> + '_ {
+        self.systems.iter().cloned()
+    }
+}
+
+static REGISTRY: Registry = Registry {
+    systems: &[
+        #[cfg(feature = "chinese")]
+        &Chinese,
+        #[cfg(feature = "greek")]
+        &Greek,
+        #[cfg(feature = "japanese")]
+        &Japanese,
+        #[cfg(feature = "korean")]
+        &Korean,
+        #[cfg(feature = "roman")]
+        &Roman,
+        #[cfg(feature = "vietnamese")]
+        &Vietnamese,
+    ],
+};
+
+/// The registry of all the numeral systems compiled in the crate.
+pub fn registry() -> &'static Registry {
+    &REGISTRY
+}
+
+#[cfg(all(test, feature = "roman"))]
+mod test_registry {
+    use crate::registry;
+
+    #[test]
+    fn test_lookup() {
+        let roman = registry().get("roman");
+        assert!(roman.is_some());
+        assert_eq!(roman.unwrap().parse("XIV").unwrap(), 14);
+
+        assert!(registry().get("klingon").is_none());
+    }
+
+    #[test]
+    fn test_enumeration() {
+        let names: Vec<&str> = registry().names().collect();
+        let expected = vec![
+            #[cfg(feature = "chinese")]
+            "chinese",
+            #[cfg(feature = "greek")]
+            "greek",
+            #[cfg(feature = "japanese")]
+            "japanese",
+            #[cfg(feature = "korean")]
+            "korean",
+            "roman",
+            #[cfg(feature = "vietnamese")]
+            "vietnamese",
+        ];
+        assert_eq!(names, expected);
+
+        for system in registry().iter() {
+            assert_eq!(registry().get(system.name()).unwrap().name(), system.name());
+        }
+    }
+}
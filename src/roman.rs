@@ -14,339 +14,5213 @@ There are a few rules to observe in checking the validity of a roman number:
         LL should be C; DD should be M
     - If a certain sequence can be represented with another symbol, it is illegal:
         LC should be L;
+    - A numeral cannot be written again around a subtraction:
+        IVI should be V; LXL should be XC
 
-Although unicode caracters exist, Apostrophus and Vinculum are not fully supported.
+Apostrophus numerals can be parsed, including when spelled with letters as in
+CIƆ, and written up to 399,999 with `to_apostrophus`. Larger values can be
+written and parsed in the vinculum notation, where an overline (U+0305)
+multiplies a numeral by 1000, or in the bracket notation, where parentheses or
+bars do, as in (IV)CCXXI.
 */
 
-use std::collections::{BTreeSet, HashMap, VecDeque};
-use std::error::Error;
-
-lazy_static! {
-    static ref ROMAN_TO_ARABIC: HashMap<char, u64> = [
-        ('I', 1),  // ascii
-        ('Ⅰ', 1),  // unicode
-        ('Ⅱ', 2),
-        ('Ⅲ', 3),
-        ('Ⅳ', 4),
-        ('V', 5),  // ascii
-        ('Ⅴ', 5),  // unicode
-        ('Ⅵ', 6),
-        ('ↅ', 6),
-        ('Ⅶ', 7),
-        ('Ⅷ', 8),
-        ('Ⅸ', 9),
-        ('X', 10),  // ascii
-        ('Ⅹ', 10),  // unicode
-        ('Ⅺ', 11),
-        ('L', 50),  // ascii
-        ('Ⅼ', 50),  // unicode
-        ('ↆ', 50),
-        ('C', 100),  // ascii
-        ('Ⅽ', 100),  // unicode
-        ('D', 500),  // ascii
-        ('Ⅾ', 500),  // unicode
-        ('M', 1000),  // ascii
-        ('Ⅿ', 1000),  // unicode
-        ('ↀ', 1000),
-        ('ↁ', 5000),
-        ('ↂ', 10000),
-        ('ↇ', 50000),
-        ('ↈ', 100000),
-    ]
-    .iter()
-    .cloned()
-    .collect();
-    static ref NUMERALS: BTreeSet<char> = ROMAN_TO_ARABIC.keys().cloned().collect();
-    static ref ARABIC_TO_ASCII: Vec<(u64, &'static str)> = vec![
-        (1000, "M"),
-        (900, "CM"),
-        (500, "D"),
-        (400, "CD"),
-        (100, "C"),
-        (90, "XC"),
-        (50, "L"),
-        (40, "XL"),
-        (10, "X"),
-        (9, "IX"),
-        (5, "V"),
-        (4, "IV"),
-        (1, "I"),
-    ];
-    static ref ARABIC_TO_UNICODE: Vec<(u64, &'static str)> = vec![
-        (1000, "Ⅿ"),
-        (900, "ⅭⅯ"),
-        (500, "Ⅾ"),
-        (400, "ⅭⅮ"),
-        (100, "Ⅽ"),
-        (90, "ⅩⅭ"),
-        (50, "Ⅼ"),
-        (40, "ⅩⅬ"),
-        (10, "Ⅹ"),
-        (9, "ⅠⅩ"),
-        (5, "V"),
-        (4, "ⅠV"),
-        (1, "Ⅰ"),
-    ];
-}
-
-pub fn to_roman(input: u64, use_unicode: bool) -> Result<String, Box<dyn Error>> {
+use alloc::collections::VecDeque;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::{Add, Bound, Mul, Range, RangeBounds, RangeInclusive, Sub};
+use core::str::FromStr;
+use num_traits::{Bounded, FromPrimitive, ToPrimitive};
+
+use crate::error::NumeralError;
+use crate::system::{NumeralSystem, Options, Strictness};
+
+const ARABIC_TO_ASCII: [(u64, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+const ARABIC_TO_UNICODE: [(u64, &str); 13] = [
+    (1000, "Ⅿ"),
+    (900, "ⅭⅯ"),
+    (500, "Ⅾ"),
+    (400, "ⅭⅮ"),
+    (100, "Ⅽ"),
+    (90, "ⅩⅭ"),
+    (50, "Ⅼ"),
+    (40, "ⅩⅬ"),
+    (10, "Ⅹ"),
+    (9, "ⅠⅩ"),
+    (5, "Ⅴ"),
+    (4, "ⅠⅤ"),
+    (1, "Ⅰ"),
+];
+
+const ARABIC_TO_APOSTROPHUS: [(u64, &str); 21] = [
+    (100000, "ↈ"),
+    (90000, "ↂↈ"),
+    (50000, "ↇ"),
+    (40000, "ↂↇ"),
+    (10000, "ↂ"),
+    (9000, "ↀↂ"),
+    (5000, "ↁ"),
+    (4000, "ↀↁ"),
+    (1000, "ↀ"),
+    (900, "Ⅽↀ"),
+    (500, "Ⅾ"),
+    (400, "ⅭⅮ"),
+    (100, "Ⅽ"),
+    (90, "ⅩⅭ"),
+    (50, "Ⅼ"),
+    (40, "ⅩⅬ"),
+    (10, "Ⅹ"),
+    (9, "ⅠⅩ"),
+    (5, "Ⅴ"),
+    (4, "ⅠⅤ"),
+    (1, "Ⅰ"),
+];
+
+/// The greatest value that can be written with the standard numerals.
+pub const MAX: u64 = 3999;
+
+/// The greatest value that can be written with the apostrophus numerals.
+pub const MAX_APOSTROPHUS: u64 = 399_999;
+
+/// The value of an upper case numeral, ascii or unicode.
+const fn value_of(c: char) -> Option<u64> {
+    match c {
+        'I' | 'Ⅰ' => Some(1),
+        'Ⅱ' => Some(2),
+        'Ⅲ' => Some(3),
+        'Ⅳ' => Some(4),
+        'V' | 'Ⅴ' => Some(5),
+        'Ⅵ' | 'ↅ' => Some(6),
+        'Ⅶ' => Some(7),
+        'Ⅷ' => Some(8),
+        'Ⅸ' => Some(9),
+        'X' | 'Ⅹ' => Some(10),
+        'Ⅺ' => Some(11),
+        'Ⅻ' => Some(12),
+        'L' | 'Ⅼ' | 'ↆ' => Some(50),
+        'C' | 'Ⅽ' => Some(100),
+        'D' | 'Ⅾ' => Some(500),
+        'M' | 'Ⅿ' | 'ↀ' => Some(1000),
+        'ↁ' => Some(5000),
+        'ↂ' => Some(10000),
+        'ↇ' => Some(50000),
+        'ↈ' => Some(100000),
+        _ => None,
+    }
+}
+
+pub fn to_roman(input: u64, use_unicode: bool) -> Result<String, NumeralError> {
+    let mut ret = String::new();
+    to_roman_into(input, use_unicode, &mut ret)?;
+    Ok(ret)
+}
+
+/// The numeral for zero, from the latin nulla.
+const NULLA: &str = "N";
+
+/// `to_roman`, writing zero as N, as found in medieval computus tables.
+///
+///     use numerals::roman::to_roman_with_zero;
+///
+///     assert_eq!(to_roman_with_zero(0, false).unwrap(), "N");
+///     assert_eq!(to_roman_with_zero(10, false).unwrap(), "X");
+pub fn to_roman_with_zero(input: u64, use_unicode: bool) -> Result<String, NumeralError> {
+    match input {
+        0 => Ok(NULLA.into()),
+        _ => to_roman(input, use_unicode),
+    }
+}
+
+/// `to_roman` for signed values, writing a minus sign before the negative
+/// ones, as in bookkeeping.
+///
+///     use numerals::roman::to_roman_signed;
+///
+///     assert_eq!(to_roman_signed(-14, false).unwrap(), "-XIV");
+///     assert_eq!(to_roman_signed(14, false).unwrap(), "XIV");
+pub fn to_roman_signed(input: i64, use_unicode: bool) -> Result<String, NumeralError> {
+    let numeral = to_roman(input.unsigned_abs(), use_unicode)?;
+    match input < 0 {
+        true => Ok(String::from("-") + &numeral),
+        false => Ok(numeral),
+    }
+}
+
+/// `to_roman` for any primitive integer type.
+///
+/// Negative inputs are reported as `Negative`, and inputs too large for a
+/// `u64` as `Overflow`.
+///
+///     use numerals::roman::to_roman_from;
+///     use numerals::NumeralError;
+///
+///     assert_eq!(to_roman_from(14u8, false).unwrap(), "XIV");
+///     assert_eq!(to_roman_from(-14i32, false), Err(NumeralError::Negative));
+pub fn to_roman_from<T: ToPrimitive>(input: T, use_unicode: bool) -> Result<String, NumeralError> {
+    let value = match input.to_u64() {
+        Some(value) => value,
+        None if input.to_i128().is_some_and(|value| value < 0) => {
+            return Err(NumeralError::Negative)
+        }
+        None => return Err(NumeralError::Overflow),
+    };
+    to_roman(value, use_unicode)
+}
+
+/// Write the roman numeral for `input` to `out`.
+///
+/// This is `to_roman`, without allocating a new string for every numeral:
+///
+///     use numerals::roman::to_roman_into;
+///
+///     let mut page = String::new();
+///     for number in 1..=3 {
+///         page.clear();
+///         to_roman_into(number, false, &mut page).unwrap();
+///     }
+///     assert_eq!(page, "III");
+pub fn to_roman_into<W: fmt::Write>(
+    input: u64,
+    use_unicode: bool,
+    out: &mut W,
+) -> Result<(), NumeralError> {
+    write_roman(input, use_unicode, Style::Subtractive, out)
+}
+
+/// How the roman numerals are written.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Style {
+    /// The usual numerals, as in IV and XC.
+    #[default]
+    Subtractive,
+    /// The numerals found on clock faces, where 4 is written IIII.
+    Clock,
+    /// The numerals of ancient inscriptions, without any subtraction, where
+    /// 1999 is written MDCCCCLXXXXVIIII. These are parsed by
+    /// `to_arabic_lenient`.
+    Additive,
+}
+
+impl Style {
+    /// Whether the numerals for `value` are used in this style.
+    fn uses(self, value: u64) -> bool {
+        match self {
+            Style::Subtractive => true,
+            Style::Clock => value != 4,
+            Style::Additive => matches!(value, 1 | 5 | 10 | 50 | 100 | 500 | 1000),
+        }
+    }
+}
+
+/// `to_roman`, writing the numerals in the given style.
+///
+///     use numerals::roman::{to_roman_styled, Style};
+///
+///     assert_eq!(to_roman_styled(4, false, Style::Clock).unwrap(), "IIII");
+///     assert_eq!(to_roman_styled(9, false, Style::Clock).unwrap(), "IX");
+pub fn to_roman_styled(
+    input: u64,
+    use_unicode: bool,
+    style: Style,
+) -> Result<String, NumeralError> {
+    let mut ret = String::new();
+    write_roman(input, use_unicode, style, &mut ret)?;
+    Ok(ret)
+}
+
+/// The unicode characters for the numerals from 1 to 12.
+const PRECOMPOSED: [char; 12] = ['Ⅰ', 'Ⅱ', 'Ⅲ', 'Ⅳ', 'Ⅴ', 'Ⅵ', 'Ⅶ', 'Ⅷ', 'Ⅸ', 'Ⅹ', 'Ⅺ', 'Ⅻ'];
+
+/// `to_roman` with unicode characters, ending with a single character for the
+/// units, or for 11 and 12, as found on clocks and calendars.
+///
+/// The units are only shortened if the style spells them as the character
+/// does, so that 4 stays ⅠⅠⅠⅠ in the clock style.
+///
+///     use numerals::roman::{to_roman_compact, Style};
+///
+///     assert_eq!(to_roman_compact(12, Style::Subtractive).unwrap(), "Ⅻ");
+///     assert_eq!(to_roman_compact(1994, Style::Subtractive).unwrap(), "ⅯⅭⅯⅩⅭⅣ");
+///     assert_eq!(to_roman_compact(4, Style::Clock).unwrap(), "ⅠⅠⅠⅠ");
+pub fn to_roman_compact(input: u64, style: Style) -> Result<String, NumeralError> {
+    let mut ret = to_roman_styled(input, true, style)?;
+    let units = match input % 100 {
+        11 | 12 => input % 100,
+        _ => input % 10,
+    };
+    if units > 1 {
+        let spelling = to_roman(units, true)?;
+        if ret.ends_with(&spelling) {
+            ret.truncate(ret.len() - spelling.len());
+            ret.push(PRECOMPOSED[units as usize - 1]);
+        }
+    }
+    Ok(ret)
+}
+
+/// `to_roman`, writing `separator` between the numerals of each decimal
+/// place, as in inscription transcriptions.
+///
+///     use numerals::roman::to_roman_separated;
+///
+///     assert_eq!(to_roman_separated(1999, false, '·').unwrap(), "M·CM·XC·IX");
+///     assert_eq!(to_roman_separated(3008, false, ' ').unwrap(), "MMM VIII");
+pub fn to_roman_separated(
+    input: u64,
+    use_unicode: bool,
+    separator: char,
+) -> Result<String, NumeralError> {
+    write_places(input, separator, |place| to_roman(place, use_unicode))
+}
+
+/// Join the numerals of each decimal place of `input`, written by `write`,
+/// with `separator`.
+fn write_places<F>(input: u64, separator: char, write: F) -> Result<String, NumeralError>
+where
+    F: Fn(u64) -> Result<String, NumeralError>,
+{
+    if !(1..=MAX).contains(&input) {
+        return Err(NumeralError::OutOfRange {
+            value: input,
+            min: 1,
+            max: MAX,
+        });
+    }
+
+    let mut ret = String::new();
+    for place in [1000, 100, 10, 1] {
+        let digit = input / place % 10;
+        if digit == 0 {
+            continue;
+        }
+        if !ret.is_empty() {
+            ret.push(separator);
+        }
+        ret.push_str(&write(digit * place)?);
+    }
+    Ok(ret)
+}
+
+fn write_roman<W: fmt::Write>(
+    input: u64,
+    use_unicode: bool,
+    style: Style,
+    out: &mut W,
+) -> Result<(), NumeralError> {
+    if !(1..=MAX).contains(&input) {
+        return Err(NumeralError::OutOfRange {
+            value: input,
+            min: 1,
+            max: MAX,
+        });
+    }
+
+    #[cfg(feature = "table")]
+    if !use_unicode && style == Style::Subtractive {
+        out.write_str(TABLE[input as usize - 1].as_str())?;
+        return Ok(());
+    }
+
+    let list: &'static [(u64, &str)] = match use_unicode {
+        true => &ARABIC_TO_UNICODE,
+        false => &ARABIC_TO_ASCII,
+    };
+    let terms = list.iter().filter(|&&(arabic, _)| style.uses(arabic));
+    write_terms(input, terms, out)
+}
+
+/// Write `input` greedily as the terms of `list`, ordered by decreasing value.
+fn write_terms<'a, I, W>(input: u64, list: I, out: &mut W) -> Result<(), NumeralError>
+where
+    I: IntoIterator<Item = &'a (u64, &'a str)>,
+    W: fmt::Write,
+{
+    let mut input = input;
+    for (arabic, roman) in list {
+        while input % arabic < input {
+            out.write_str(roman)?;
+            input -= arabic;
+        }
+    }
+    Ok(())
+}
+
+/// The combining overline multiplying the numeral it is written over by 1000.
+const VINCULUM: char = '\u{305}';
+
+/// Format `input` in the vinculum notation.
+///
+/// Values below 4000 are written as usual. Beyond, the thousands are written
+/// with an overline over each numeral, which multiplies it by 1000, and so on
+/// with a second overline for the millions:
+///
+///     use numerals::roman::to_vinculum;
+///
+///     assert_eq!(to_vinculum(5000).unwrap(), "V\u{305}");
+///     assert_eq!(to_vinculum(4221).unwrap(), "I\u{305}V\u{305}CCXXI");
+///     assert_eq!(to_vinculum(1_000_000).unwrap(), "M\u{305}");
+///     assert_eq!(to_vinculum(5_000_000).unwrap(), "V\u{305}\u{305}");
+pub fn to_vinculum(input: u64) -> Result<String, NumeralError> {
+    if input == 0 {
+        return Err(NumeralError::OutOfRange {
+            value: input,
+            min: 1,
+            max: u64::MAX,
+        });
+    }
+
+    let mut ret = String::new();
+    write_vinculum(input, 0, &mut ret)?;
+    Ok(ret)
+}
+
+fn write_vinculum(input: u64, bars: usize, out: &mut String) -> Result<(), NumeralError> {
+    let (thousands, rest) = match input {
+        0..=MAX => (0, input),
+        _ => (input / 1000, input % 1000),
+    };
+    if thousands > 0 {
+        write_vinculum(thousands, bars + 1, out)?;
+    }
+
+    let mut numeral = String::new();
+    write_terms(rest, &ARABIC_TO_ASCII, &mut numeral)?;
+    for ch in numeral.chars() {
+        out.push(ch);
+        for _ in 0..bars {
+            out.push(VINCULUM);
+        }
+    }
+    Ok(())
+}
+
+/// Format `input` in the bracket notation.
+///
+/// This is the vinculum notation in plain ascii, where parentheses enclose
+/// the numerals multiplied by 1000:
+///
+///     use numerals::roman::{to_arabic, to_brackets};
+///
+///     assert_eq!(to_brackets(4221).unwrap(), "(IV)CCXXI");
+///     assert_eq!(to_brackets(5_000_000).unwrap(), "((V))");
+///     assert_eq!(to_arabic("|IV|CCXXI").unwrap(), 4221);
+pub fn to_brackets(input: u64) -> Result<String, NumeralError> {
+    if input == 0 {
+        return Err(NumeralError::OutOfRange {
+            value: input,
+            min: 1,
+            max: u64::MAX,
+        });
+    }
+
+    let mut ret = String::new();
+    write_brackets(input, &mut ret)?;
+    Ok(ret)
+}
+
+fn write_brackets(input: u64, out: &mut String) -> Result<(), NumeralError> {
+    let rest = match input {
+        0..=MAX => input,
+        _ => {
+            out.push('(');
+            write_brackets(input / 1000, out)?;
+            out.push(')');
+            input % 1000
+        }
+    };
+    write_terms(rest, &ARABIC_TO_ASCII, out)
+}
+
+/// The fraction numerals: S for a half, and a dot for each twelfth, or uncia.
+const SEMIS: char = 'S';
+const UNCIA: char = '•';
+
+/// Format a value counted in twelfths, with the roman fractions.
+///
+/// The whole part is followed by S for half a unit, and a dot for each
+/// remaining twelfth, or uncia, so that 2½ is IIS and 1¼ is I•••.
+///
+///     use numerals::roman::to_roman_twelfths;
+///
+///     assert_eq!(to_roman_twelfths(30, false).unwrap(), "IIS");
+///     assert_eq!(to_roman_twelfths(15, false).unwrap(), "I•••");
+///     assert_eq!(to_roman_twelfths(11, false).unwrap(), "S•••••");
+pub fn to_roman_twelfths(twelfths: u64, use_unicode: bool) -> Result<String, NumeralError> {
+    let (whole, unciae) = (twelfths / 12, twelfths % 12);
+    if twelfths == 0 || whole > MAX {
+        return Err(NumeralError::OutOfRange {
+            value: twelfths,
+            min: 1,
+            max: MAX * 12 + 11,
+        });
+    }
+
+    let mut ret = String::new();
+    if whole > 0 {
+        to_roman_into(whole, use_unicode, &mut ret)?;
+    }
+    if unciae >= 6 {
+        ret.push(SEMIS);
+    }
+    for _ in 0..unciae % 6 {
+        ret.push(UNCIA);
+    }
+    Ok(ret)
+}
+
+/// Format `input` with the apostrophus numerals for the thousands.
+///
+/// The unicode characters ↀ (1000), ↁ (5000), ↂ (10000), ↇ (50000) and
+/// ↈ (100000) extend the numerals up to `MAX_APOSTROPHUS`:
+///
+///     use numerals::roman::{to_apostrophus, to_arabic};
+///
+///     assert_eq!(to_apostrophus(1994).unwrap(), "ↀⅭↀⅩⅭⅠⅤ");
+///     assert_eq!(to_apostrophus(250_000).unwrap(), "ↈↈↇ");
+///     assert_eq!(to_arabic("ↈↈↇ").unwrap(), 250_000);
+pub fn to_apostrophus(input: u64) -> Result<String, NumeralError> {
+    if !(1..=MAX_APOSTROPHUS).contains(&input) {
+        return Err(NumeralError::OutOfRange {
+            value: input,
+            min: 1,
+            max: MAX_APOSTROPHUS,
+        });
+    }
+
+    let mut ret = String::new();
+    write_terms(input, &ARABIC_TO_APOSTROPHUS, &mut ret)?;
+    Ok(ret)
+}
+
+/// A roman numeral formatted by `roman_const`, stored inline.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RomanBuf {
+    // MMMDCCCLXXXVIII is the longest numeral
+    bytes: [u8; 15],
+    len: usize,
+}
+
+impl RomanBuf {
+    pub const fn as_str(&self) -> &str {
+        let (numeral, _) = self.bytes.split_at(self.len);
+        match core::str::from_utf8(numeral) {
+            Ok(numeral) => numeral,
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+impl fmt::Display for RomanBuf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Format `input` as an ascii roman numeral at compile time.
+///
+/// This panics, and so fails to compile in a const context, if `input` is not
+/// between 1 and `MAX` inclusive. The `roman!` macro wraps it to get a
+/// `&'static str`:
+///
+///     use numerals::roman;
+///     use numerals::roman::{roman_const, RomanBuf};
+///
+///     const YEAR: RomanBuf = roman_const(2024);
+///     assert_eq!(YEAR.as_str(), "MMXXIV");
+///
+///     const CHAPTER: &str = roman!(14);
+///     assert_eq!(CHAPTER, "XIV");
+pub const fn roman_const(input: u64) -> RomanBuf {
+    if input < 1 || input > MAX {
+        panic!("The value should be between 1 and 3999 inclusive");
+    }
+
     let mut input = input;
-    if input < 1 || input > 3999 {
-        return Err(format!(
-            "The value should be between 1 and 3999 inclusive, not {}",
-            input
-        )
-        .into());
+    let mut buf = RomanBuf {
+        bytes: [0; 15],
+        len: 0,
+    };
+    let mut i = 0;
+    while i < ARABIC_TO_ASCII.len() {
+        let (arabic, roman) = ARABIC_TO_ASCII[i];
+        while input >= arabic {
+            let roman = roman.as_bytes();
+            let mut j = 0;
+            while j < roman.len() {
+                buf.bytes[buf.len] = roman[j];
+                buf.len += 1;
+                j += 1;
+            }
+            input -= arabic;
+        }
+        i += 1;
+    }
+    buf
+}
+
+/// The ascii numerals of all the values from 1 to `MAX`, computed at compile
+/// time, so that `to_roman` is a lookup.
+#[cfg(feature = "table")]
+static TABLE: [RomanBuf; MAX as usize] = table();
+
+#[cfg(feature = "table")]
+const fn table() -> [RomanBuf; MAX as usize] {
+    let mut table = [RomanBuf {
+        bytes: [0; 15],
+        len: 0,
+    }; MAX as usize];
+    let mut i = 0;
+    while i < table.len() {
+        table[i] = roman_const(i as u64 + 1);
+        i += 1;
+    }
+    table
+}
+
+/// Format a roman numeral at compile time, as a `&'static str`.
+///
+/// See `roman_const`.
+#[macro_export]
+macro_rules! roman {
+    ($value:expr) => {{
+        const NUMERAL: $crate::roman::RomanBuf = $crate::roman::roman_const($value);
+        NUMERAL.as_str()
+    }};
+}
+
+const fn ascii_value(c: u8) -> u64 {
+    match c.to_ascii_uppercase() {
+        b'I' => 1,
+        b'V' => 5,
+        b'X' => 10,
+        b'L' => 50,
+        b'C' => 100,
+        b'D' => 500,
+        b'M' => 1000,
+        _ => panic!("Input contains invalid characters"),
+    }
+}
+
+/// Convert an ascii roman numeral at compile time.
+///
+/// The rules are the same as for `to_arabic`, but an invalid input panics, and
+/// so fails to compile in a const context.
+///
+///     use numerals::roman::arabic_const;
+///
+///     const YEAR: u64 = arabic_const("MMXXIV");
+///     assert_eq!(YEAR, 2024);
+pub const fn arabic_const(input: &str) -> u64 {
+    let roman = input.as_bytes();
+    if roman.is_empty() {
+        panic!("Invalid empty string");
+    }
+
+    if roman.len() == 4 {
+        let mut ones = 0;
+        while ones < 4 && ascii_value(roman[ones]) == 1 {
+            ones += 1;
+        }
+        if ones == 4 {
+            // Having four additions in a row is illegal, short of the sequence IIII
+            return 4;
+        }
+    }
+
+    // The last four values, from oldest to current, as in to_arabic
+    let mut buffer: [u64; 4] = [0; 4];
+    let mut value: u64 = 0;
+    let mut i = roman.len();
+    while i > 0 {
+        i -= 1;
+        let current = ascii_value(roman[i]);
+        buffer = [buffer[1], buffer[2], buffer[3], current];
+
+        if current < buffer[1]
+            || (buffer[0] == current && buffer[1] == current && buffer[2] == current)
+            || (current == buffer[2] && (current == 50 || current == 500))
+        {
+            panic!("Invalid sequence");
+        } else if current < buffer[2] {
+            if buffer[2] - current == current {
+                panic!("Invalid sequence");
+            }
+            value -= current;
+        } else {
+            value += current;
+        }
+    }
+    value
+}
+
+/// The numerals of the input, along with their position and character.
+fn numerals(input: &str) -> Result<Vec<(usize, char, u64)>, NumeralError> {
+    if input.contains(is_reversed_c) {
+        return apostrophus_numerals(input);
+    }
+
+    let mut numerals = Vec::new();
+    for (index, ch) in input.chars().enumerate() {
+        match numeral_value(ch) {
+            Some(value) => push_decomposed(&mut numerals, (index, ch, value)),
+            None => return Err(NumeralError::InvalidCharacter { ch, index }),
+        }
+    }
+    Ok(numerals)
+}
+
+/// The reversed C closing the apostrophus numerals, as in CIƆ.
+fn is_reversed_c(ch: char) -> bool {
+    matches!(ch, 'Ɔ' | 'ɔ' | 'Ↄ' | 'ↄ')
+}
+
+/// The numerals of an input spelling the apostrophus numerals with letters.
+///
+/// CIƆ stands for 1000, and IƆ for 500. Each additional pair of C and Ɔ
+/// multiplies the former by 10, and each additional Ɔ the latter: CCIƆƆ is
+/// 10000, and IƆƆ is 5000. Each group is reported at the position of its
+/// first character.
+fn apostrophus_numerals(input: &str) -> Result<Vec<(usize, char, u64)>, NumeralError> {
+    let chars: Vec<char> = input.chars().collect();
+    let run = |start: usize, is: fn(char) -> bool| {
+        chars[start.min(chars.len())..]
+            .iter()
+            .take_while(|&&ch| is(ch))
+            .count()
+    };
+
+    let mut numerals = Vec::new();
+    let mut index = 0;
+    while index < chars.len() {
+        let ch = chars[index];
+        let cs = run(index, |ch| matches!(ch, 'C' | 'c' | 'Ⅽ' | 'ⅽ'));
+        let closing = match chars.get(index + cs) {
+            Some('I' | 'i' | 'Ⅰ' | 'ⅰ') => run(index + cs + 1, is_reversed_c),
+            _ => 0,
+        };
+        let (length, value) = if closing == 0 {
+            match numeral_value(ch) {
+                Some(value) => (1, value),
+                None => return Err(NumeralError::InvalidCharacter { ch, index }),
+            }
+        } else if cs > closing {
+            // The leading C is a numeral on its own, as in CCIƆ
+            (1, 100)
+        } else if cs == 0 && closing <= 3 {
+            // IƆ, IƆƆ, IƆƆƆ
+            (closing + 1, 5 * 10u64.pow(closing as u32 + 1))
+        } else if cs == closing && cs <= 3 {
+            // CIƆ, CCIƆƆ, CCCIƆƆƆ
+            (2 * cs + 1, 10u64.pow(cs as u32 + 2))
+        } else {
+            return Err(NumeralError::InvalidSequence { ch, index });
+        };
+        push_decomposed(&mut numerals, (index, ch, value));
+        index += length;
+    }
+    Ok(numerals)
+}
+
+/// Add a numeral, split into the numerals it stands for if it is precomposed.
+///
+/// Characters such as Ⅻ stand for several numerals, here XII, which are
+/// checked against the rules on their own. They keep the position and
+/// character of the precomposed numeral.
+fn push_decomposed(numerals: &mut Vec<(usize, char, u64)>, (index, ch, value): (usize, char, u64)) {
+    if is_power_of_ten(value) || is_power_of_ten(2 * value) {
+        numerals.push((index, ch, value));
+        return;
+    }
+
+    let mut rest = value;
+    for &(arabic, roman) in ARABIC_TO_ASCII.iter() {
+        while rest >= arabic {
+            numerals.extend(
+                roman
+                    .chars()
+                    .filter_map(value_of)
+                    .map(|value| (index, ch, value)),
+            );
+            rest -= arabic;
+        }
+    }
+}
+
+/// The value of a numeral, in upper or lower case.
+fn numeral_value(ch: char) -> Option<u64> {
+    let mut upper = ch.to_uppercase();
+    match (upper.next(), upper.next()) {
+        (Some(c), None) => value_of(c),
+        _ => None,
+    }
+}
+
+pub fn to_arabic<S: AsRef<str>>(input: S) -> Result<u64, NumeralError> {
+    to_arabic_with_rules(input.as_ref(), &RuleSet::default())
+}
+
+/// The rules that the numerals should follow, each of which can be disabled.
+///
+/// The default rules are those checked by `to_arabic`.
+///
+///     use numerals::roman::{to_arabic_with_rules, RuleSet};
+///
+///     let rules = RuleSet {
+///         four_in_a_row: false,
+///         ..RuleSet::default()
+///     };
+///     assert_eq!(to_arabic_with_rules("XXXX", &rules), Ok(40));
+///     assert!(to_arabic_with_rules("IIX", &rules).is_err());
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RuleSet {
+    /// Reject two subtractions in a row, as in IXC.
+    pub double_subtraction: bool,
+    /// Reject four similar numerals in a row, as in XXXX.
+    pub four_in_a_row: bool,
+    /// Accept IIII despite the previous rule.
+    pub iiii_exception: bool,
+    /// Reject repeated V, L and D, as in LL.
+    pub repeated_five: bool,
+    /// Reject subtractions of half the value, as in VX.
+    pub redundant_subtraction: bool,
+    /// Only subtract I, X or C from the next two numerals, as in IV or XC,
+    /// rejecting IM.
+    pub value_order: bool,
+    /// Reject a numeral written again around a subtraction, as in IVI, XCX
+    /// or LXL.
+    pub added_back: bool,
+}
+
+impl RuleSet {
+    /// Every rule, without the IIII exception.
+    pub const fn strict() -> RuleSet {
+        RuleSet {
+            double_subtraction: true,
+            four_in_a_row: true,
+            iiii_exception: false,
+            repeated_five: true,
+            redundant_subtraction: true,
+            value_order: true,
+            added_back: true,
+        }
+    }
+
+    /// No rule at all.
+    pub const fn none() -> RuleSet {
+        RuleSet {
+            double_subtraction: false,
+            four_in_a_row: false,
+            iiii_exception: false,
+            repeated_five: false,
+            redundant_subtraction: false,
+            value_order: false,
+            added_back: false,
+        }
+    }
+}
+
+impl Default for RuleSet {
+    fn default() -> RuleSet {
+        RuleSet {
+            double_subtraction: true,
+            four_in_a_row: true,
+            iiii_exception: true,
+            repeated_five: true,
+            redundant_subtraction: true,
+            value_order: false,
+            added_back: true,
+        }
+    }
+}
+
+/// `to_arabic`, checking the given rules only.
+pub fn to_arabic_with_rules(input: &str, rules: &RuleSet) -> Result<u64, NumeralError> {
+    if input.is_empty() {
+        return Err(NumeralError::Empty);
+    }
+
+    if rules.iiii_exception
+        && (input.eq_ignore_ascii_case("IIII")
+            || (input.chars().count() == 4 && input.chars().all(|c| c == 'Ⅰ' || c == 'ⅰ')))
+    {
+        // Having four additions in a row is illegal, short of the sequence IIII
+        return Ok(4u64);
+    }
+
+    if input.contains(VINCULUM) {
+        return from_vinculum(input, rules);
+    }
+
+    if input.contains(['(', ')', '|']) {
+        return from_brackets(input, rules);
+    }
+
+    evaluate(&numerals(input)?, rules)
+}
+
+/// Numerals multiplied by 1000 the same number of times, along with that number.
+type Group = (u32, Vec<(usize, char, u64)>);
+
+/// The value of a numeral written in the vinculum notation.
+///
+/// The numerals are grouped by their number of overlines, the groups with the
+/// most overlines coming first. Each group is checked as a numeral on its own,
+/// then multiplied by 1000 for each overline.
+fn from_vinculum(input: &str, rules: &RuleSet) -> Result<u64, NumeralError> {
+    let mut groups: Vec<Group> = Vec::new();
+    let mut last: Option<(usize, char, u64, u32)> = None;
+    for (index, ch) in input.chars().enumerate() {
+        if ch == VINCULUM {
+            match last.as_mut() {
+                Some((_, _, _, bars)) => *bars += 1,
+                None => return Err(NumeralError::InvalidCharacter { ch, index }),
+            }
+            continue;
+        }
+        if let Some(numeral) = last.take() {
+            push_multiplied(&mut groups, numeral)?;
+        }
+        match numeral_value(ch) {
+            Some(value) => last = Some((index, ch, value, 0)),
+            None => return Err(NumeralError::InvalidCharacter { ch, index }),
+        }
+    }
+    if let Some(numeral) = last {
+        push_multiplied(&mut groups, numeral)?;
+    }
+    evaluate_groups(&groups, rules)
+}
+
+/// The value of a numeral written in the bracket notation.
+///
+/// Parentheses multiply the numerals they enclose by 1000, and can be nested.
+/// Vertical bars can be used instead of a single pair of parentheses. The
+/// groups are checked as in the vinculum notation.
+fn from_brackets(input: &str, rules: &RuleSet) -> Result<u64, NumeralError> {
+    let mut groups: Vec<Group> = Vec::new();
+    let mut opened: Vec<(usize, char)> = Vec::new();
+    for (index, ch) in input.chars().enumerate() {
+        let closes = match (ch, opened.last()) {
+            (')', Some(&(_, '('))) | ('|', Some(&(_, '|'))) => true,
+            ('(', _) | ('|', _) => false,
+            _ => {
+                match numeral_value(ch) {
+                    Some(value) => {
+                        let numeral = (index, ch, value, opened.len() as u32);
+                        push_multiplied(&mut groups, numeral)?;
+                    }
+                    None => return Err(NumeralError::InvalidCharacter { ch, index }),
+                }
+                continue;
+            }
+        };
+        match closes {
+            true => {
+                opened.pop();
+            }
+            false => opened.push((index, ch)),
+        }
+    }
+    if let Some(&(index, ch)) = opened.last() {
+        return Err(NumeralError::InvalidSequence { ch, index });
+    }
+    evaluate_groups(&groups, rules)
+}
+
+/// The sum of the values of the groups, each multiplied by 1000 as needed.
+fn evaluate_groups(groups: &[Group], rules: &RuleSet) -> Result<u64, NumeralError> {
+    let mut value: u64 = 0;
+    for (thousands, numerals) in groups.iter() {
+        let multiplier = 1000u64
+            .checked_pow(*thousands)
+            .ok_or(NumeralError::Overflow)?;
+        value = evaluate(numerals, rules)?
+            .checked_mul(multiplier)
+            .and_then(|group| value.checked_add(group))
+            .ok_or(NumeralError::Overflow)?;
+    }
+    Ok(value)
+}
+
+/// Add a numeral to its group, which must not be multiplied fewer times
+/// than the previous group.
+fn push_multiplied(
+    groups: &mut Vec<Group>,
+    (index, ch, value, bars): (usize, char, u64, u32),
+) -> Result<(), NumeralError> {
+    match groups.last_mut() {
+        Some((last, numerals)) if *last == bars => push_decomposed(numerals, (index, ch, value)),
+        Some((last, _)) if *last < bars => {
+            return Err(NumeralError::InvalidSequence { ch, index });
+        }
+        _ => {
+            let mut numerals = Vec::new();
+            push_decomposed(&mut numerals, (index, ch, value));
+            groups.push((bars, numerals));
+        }
+    }
+    Ok(())
+}
+
+/// The value of a sequence of numerals, checked against the rules.
+fn evaluate(numerals: &[(usize, char, u64)], rules: &RuleSet) -> Result<u64, NumeralError> {
+    let mut violation = None;
+    let value = check(numerals, rules, |first| {
+        violation = Some(first);
+        false
+    });
+    match (violation, value) {
+        (Some(violation), _) => Err(violation.into()),
+        (None, Some(value)) => Ok(value),
+        (None, None) => Err(NumeralError::Overflow),
+    }
+}
+
+/// Check a sequence of numerals against the rules, and compute its value.
+///
+/// Every violation is passed to `report`, which returns whether to carry on.
+/// The value is `None` if the check was stopped, or on overflow.
+fn check<F>(numerals: &[(usize, char, u64)], rules: &RuleSet, mut report: F) -> Option<u64>
+where
+    F: FnMut(Violation) -> bool,
+{
+    // This buffer is used to check that not 4 elements in a row are similar.
+    let mut buffer: VecDeque<u64> = VecDeque::with_capacity(4);
+    buffer.push_back(0); // oldest
+    buffer.push_back(0); // preprevious
+    buffer.push_back(0); // previous
+    buffer.push_back(0); // current
+
+    let mut value: u64 = 0;
+    for &(index, ch, current) in numerals.iter().rev() {
+        buffer.pop_front();
+        buffer.push_back(current);
+
+        let rule = if rules.double_subtraction && current < buffer[1] {
+            // Having two subtraction in a row is illegal
+            Some(Rule::DoubleSubtraction)
+        } else if rules.four_in_a_row && buffer.iter().all(|&item| item == current) {
+            // Having four additions in a row is illegal
+            Some(Rule::FourInARow)
+        } else if rules.repeated_five
+            && current == buffer[2]
+            && (current == 5 || current == 50 || current == 500)
+        {
+            // Having two consecutive V, L or D is illegal
+            Some(Rule::RepeatedFive)
+        } else if rules.redundant_subtraction
+            && current < buffer[2]
+            && buffer[2] - current == current
+        {
+            // Having a subtraction that does nothing is illegal
+            Some(Rule::RedundantSubtraction)
+        } else if rules.value_order
+            && current < buffer[2]
+            && !(is_power_of_ten(current)
+                && (buffer[2] == 5 * current || buffer[2] == 10 * current))
+        {
+            // Only I, X and C can be subtracted, from the next two numerals
+            Some(Rule::ValueOrder)
+        } else if rules.added_back
+            && ((current < buffer[2] && current == buffer[1])
+                || (buffer[2] < buffer[1] && current == buffer[1] && is_power_of_ten(2 * current)))
+        {
+            // Writing a numeral again after subtracting it, as in IVI, or a
+            // five before subtracting from it, as in LXL, is illegal
+            Some(Rule::AddedBack)
+        } else {
+            None
+        };
+        if let Some(rule) = rule {
+            if !report(Violation {
+                rule,
+                index,
+                ch: Some(ch),
+            }) {
+                return None;
+            }
+        }
+
+        if current < buffer[2] {
+            value = value.checked_sub(current)?;
+        } else {
+            value = value.checked_add(current)?;
+        }
+    }
+    Some(value)
+}
+
+/// Whether the value of a numeral is a power of ten, as for I, X, C or M.
+fn is_power_of_ten(value: u64) -> bool {
+    matches!(value, 1 | 10 | 100 | 1000 | 10_000 | 100_000)
+}
+
+/// A rule that roman numerals should follow.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Rule {
+    /// A numeral is not empty.
+    Empty,
+    /// A numeral is only made of numerals.
+    InvalidCharacter,
+    /// Two subtractions in a row are illegal, as in IXC.
+    DoubleSubtraction,
+    /// Four similar numerals in a row are illegal, as in XXXX, short of IIII.
+    FourInARow,
+    /// L and D cannot be repeated, as in LL.
+    RepeatedFive,
+    /// A subtraction that could be written with another symbol is illegal, as in VX.
+    RedundantSubtraction,
+    /// Only I, X and C can be subtracted, from the next two numerals, as in IV.
+    ValueOrder,
+    /// A numeral is not written again around a subtraction, as in IVI.
+    AddedBack,
+    /// The apostrophus numerals close as many C as they open, as in CIƆ.
+    Apostrophus,
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rule::Empty => write!(f, "empty numeral"),
+            Rule::InvalidCharacter => write!(f, "invalid character"),
+            Rule::DoubleSubtraction => write!(f, "two subtractions in a row"),
+            Rule::FourInARow => write!(f, "four similar numerals in a row"),
+            Rule::RepeatedFive => write!(f, "repeated V, L or D"),
+            Rule::RedundantSubtraction => write!(f, "subtraction of half the value"),
+            Rule::ValueOrder => write!(f, "subtraction from a numeral too large"),
+            Rule::AddedBack => write!(f, "numeral written again around a subtraction"),
+            Rule::Apostrophus => write!(f, "unbalanced apostrophus numeral"),
+        }
+    }
+}
+
+/// A broken rule, found by `validate`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Violation {
+    pub rule: Rule,
+    /// The position of the offending numeral, counted in characters.
+    pub index: usize,
+    /// The offending numeral, as found in the input. None for an empty input.
+    pub ch: Option<char>,
+}
+
+impl From<Violation> for NumeralError {
+    fn from(violation: Violation) -> NumeralError {
+        let Violation { rule, index, ch } = violation;
+        match (rule, ch) {
+            (_, None) => NumeralError::Empty,
+            (Rule::InvalidCharacter, Some(ch)) => NumeralError::InvalidCharacter { ch, index },
+            (_, Some(ch)) => NumeralError::InvalidSequence { ch, index },
+        }
+    }
+}
+
+/// Check a roman numeral, reporting all the rules it breaks.
+///
+/// Where `to_arabic` stops at the first error, this lists every violation,
+/// ordered as found from the right end of the numeral. If the input contains
+/// invalid characters, these are the only violations reported.
+///
+///     use numerals::roman::{validate, Rule};
+///
+///     let violations = validate("LLXXXX").unwrap_err();
+///     let rules: Vec<Rule> = violations.iter().map(|v| v.rule).collect();
+///     assert_eq!(rules, vec![Rule::FourInARow, Rule::RepeatedFive]);
+///     assert_eq!(violations[1].index, 0);
+pub fn validate(input: &str) -> Result<(), Vec<Violation>> {
+    if input.is_empty() {
+        return Err(vec![Violation {
+            rule: Rule::Empty,
+            index: 0,
+            ch: None,
+        }]);
+    }
+
+    if to_arabic(input) == Ok(4) && input.chars().count() == 4 {
+        // The IIII exception
+        return Ok(());
+    }
+
+    let invalid: Vec<Violation> = input
+        .chars()
+        .enumerate()
+        .filter(|&(_, ch)| numeral_value(ch).is_none() && !is_reversed_c(ch))
+        .map(|(index, ch)| Violation {
+            rule: Rule::InvalidCharacter,
+            index,
+            ch: Some(ch),
+        })
+        .collect();
+    if !invalid.is_empty() {
+        return Err(invalid);
+    }
+
+    // The apostrophus numerals spelled with letters are read as `to_arabic`
+    // does, a reversed C standing on its own being an invalid character
+    let numerals = match numerals(input) {
+        Ok(numerals) => numerals,
+        Err(error) => {
+            let (rule, index, ch) = match error {
+                NumeralError::InvalidCharacter { ch, index } => (Rule::InvalidCharacter, index, ch),
+                NumeralError::InvalidSequence { ch, index } => (Rule::Apostrophus, index, ch),
+                _ => (
+                    Rule::Apostrophus,
+                    0,
+                    input.chars().next().unwrap_or_default(),
+                ),
+            };
+            return Err(vec![Violation {
+                rule,
+                index,
+                ch: Some(ch),
+            }]);
+        }
+    };
+
+    let mut violations = Vec::new();
+    check(&numerals, &RuleSet::default(), |violation| {
+        violations.push(violation);
+        true
+    });
+    match violations.is_empty() {
+        true => Ok(()),
+        false => Err(violations),
+    }
+}
+
+/// Parse the longest prefix of `input` that is a valid roman numeral.
+///
+/// The value is returned along with the rest of the input. As any single
+/// numeral is valid, this only fails if the input does not start with one.
+///
+///     use numerals::roman::parse_prefix;
+///
+///     assert_eq!(parse_prefix("XIV. The Sorcerer").unwrap(), (14, ". The Sorcerer"));
+///     assert_eq!(parse_prefix("XIIX").unwrap(), (12, "X"));
+pub fn parse_prefix(input: &str) -> Result<(u64, &str), NumeralError> {
+    let ends: Vec<usize> = input
+        .char_indices()
+        .take_while(|&(_, ch)| numeral_value(ch).is_some())
+        .map(|(start, ch)| start + ch.len_utf8())
+        .collect();
+
+    for &end in ends.iter().rev() {
+        if let Ok(value) = to_arabic(&input[..end]) {
+            return Ok((value, &input[end..]));
+        }
+    }
+
+    match input.chars().next() {
+        Some(ch) => Err(NumeralError::InvalidCharacter { ch, index: 0 }),
+        None => Err(NumeralError::Empty),
+    }
+}
+
+/// A term of a roman numeral, as listed by `explain` and `explain_value`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Term {
+    /// The numerals of the term, either a single numeral, or a subtraction
+    /// such as CM.
+    pub numeral: String,
+    pub value: u64,
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}={}", self.numeral, self.value)
+    }
+}
+
+/// Split a valid roman numeral into the terms adding up to its value.
+///
+/// Each term is a single numeral, or a subtraction of a numeral from the next
+/// one. A precomposed character such as Ⅻ makes a term on its own. Numerals
+/// in the vinculum or bracket notations are not split, and rejected.
+///
+///     use numerals::roman::explain;
+///
+///     let terms: Vec<String> = explain("MCMXCIX")
+///         .unwrap()
+///         .iter()
+///         .map(|term| term.to_string())
+///         .collect();
+///     assert_eq!(terms, vec!["M=1000", "CM=900", "XC=90", "IX=9"]);
+pub fn explain(input: &str) -> Result<Vec<Term>, NumeralError> {
+    to_arabic(input)?;
+    let numerals = numerals(input)?;
+
+    // The numerals starting a term, as positions in `numerals`
+    let mut starts = Vec::new();
+    let mut current = 0;
+    while current < numerals.len() {
+        starts.push(current);
+        let value = numerals[current].2;
+        current += 1;
+        if let Some(&(_, _, next)) = numerals.get(current) {
+            if value < next {
+                current += 1;
+            }
+        }
+        // The rest of a precomposed numeral belongs to the same term
+        let index = numerals[current - 1].0;
+        while numerals.get(current).map(|&(next, _, _)| next) == Some(index) {
+            current += 1;
+        }
+    }
+
+    let chars: Vec<char> = input.chars().collect();
+    let terms = starts
+        .iter()
+        .enumerate()
+        .map(|(position, &start)| {
+            let end = starts.get(position + 1).map_or(numerals.len(), |&end| end);
+            let last = match numerals.get(end) {
+                Some(&(index, _, _)) => index,
+                None => chars.len(),
+            };
+            Term {
+                numeral: chars[numerals[start].0..last].iter().collect(),
+                value: lenient_value(&numerals[start..end]).unwrap_or(0),
+            }
+        })
+        .collect();
+    Ok(terms)
+}
+
+/// Split the numeral `to_roman` writes for `input` into its terms.
+///
+/// This is `explain`, for formatting:
+///
+///     use numerals::roman::explain_value;
+///
+///     let terms: Vec<String> = explain_value(1994, false)
+///         .unwrap()
+///         .iter()
+///         .map(|term| term.to_string())
+///         .collect();
+///     assert_eq!(terms, vec!["M=1000", "CM=900", "XC=90", "IV=4"]);
+pub fn explain_value(input: u64, use_unicode: bool) -> Result<Vec<Term>, NumeralError> {
+    if !(1..=MAX).contains(&input) {
+        return Err(NumeralError::OutOfRange {
+            value: input,
+            min: 1,
+            max: MAX,
+        });
+    }
+
+    let list = match use_unicode {
+        true => &ARABIC_TO_UNICODE,
+        false => &ARABIC_TO_ASCII,
+    };
+    let mut terms = Vec::new();
+    let mut rest = input;
+    for &(arabic, roman) in list.iter() {
+        while rest >= arabic {
+            terms.push(Term {
+                numeral: roman.into(),
+                value: arabic,
+            });
+            rest -= arabic;
+        }
+    }
+    Ok(terms)
+}
+
+/// A roman numeral found in a text by `find_all`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Match<'a> {
+    /// The position of the numeral in the text, in bytes.
+    pub range: Range<usize>,
+    pub value: u64,
+    pub text: &'a str,
+}
+
+/// Iterator over the roman numerals of a text, created by `find_all`.
+#[derive(Clone, Debug)]
+pub struct Matches<'a> {
+    text: &'a str,
+    offset: usize,
+}
+
+impl<'a> Iterator for Matches<'a> {
+    type Item = Match<'a>;
+
+    fn next(&mut self) -> Option<Match<'a>> {
+        loop {
+            let rest = &self.text[self.offset..];
+            let start = self.offset + rest.find(char::is_alphanumeric)?;
+            let end = self.text[start..]
+                .find(|c: char| !c.is_alphanumeric())
+                .map_or(self.text.len(), |end| start + end);
+            self.offset = end;
+
+            let word = &self.text[start..end];
+            if word.chars().all(|c| value_of(c).is_some()) {
+                if let Ok(value) = to_arabic(word) {
+                    return Some(Match {
+                        range: start..end,
+                        value,
+                        text: word,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Find the roman numerals in a text.
+///
+/// Numerals are whole words made of upper case numerals, that form a valid
+/// numeral. Lower case words are left out, to not mistake words such as "mix"
+/// or "civil" for numerals. Note that the pronoun "I" is found as a numeral.
+///
+///     use numerals::roman::find_all;
+///
+///     let acts: Vec<u64> = find_all("ACT III, SCENE IV. Before the palace.")
+///         .map(|m| m.value)
+///         .collect();
+///     assert_eq!(acts, vec![3, 4]);
+pub fn find_all(text: &str) -> Matches<'_> {
+    Matches { text, offset: 0 }
+}
+
+/// `to_arabic` returning any primitive integer type.
+///
+/// The value is reported as out of range if it does not fit in `T`.
+///
+///     use numerals::roman::to_arabic_as;
+///
+///     assert_eq!(to_arabic_as::<u8, _>("CCLV").unwrap(), 255);
+///     assert!(to_arabic_as::<u8, _>("CCLVI").is_err());
+pub fn to_arabic_as<T, S>(input: S) -> Result<T, NumeralError>
+where
+    T: Bounded + FromPrimitive + ToPrimitive,
+    S: AsRef<str>,
+{
+    let value = to_arabic(input)?;
+    T::from_u64(value).ok_or_else(|| NumeralError::OutOfRange {
+        value,
+        min: T::min_value().to_u64().unwrap_or(0),
+        max: T::max_value().to_u64().unwrap_or(u64::MAX),
+    })
+}
+
+/// Suggest the canonical numeral for an invalid input.
+///
+/// The value the input was probably meant to have is computed by subtracting
+/// every numeral smaller than one on its right, and adding the others, so that
+/// "XXXX" is taken as 40 and "IIX" as 8.
+/// There is no suggestion if the input is valid, contains invalid characters,
+/// or if the value is out of range.
+///
+///     use numerals::roman::suggest;
+///
+///     assert_eq!(suggest("XXXX"), Some("XL".to_string()));
+///     assert_eq!(suggest("XL"), None);
+pub fn suggest(input: &str) -> Option<String> {
+    if to_arabic(input).is_ok() {
+        return None;
+    }
+
+    let value = lenient_value(&numerals(input).ok()?)?;
+    to_roman(value, !input.is_ascii()).ok()
+}
+
+/// The value of a sequence of numerals, ignoring the rules.
+///
+/// Every numeral smaller than one on its right is subtracted, and the others
+/// are added. The value is `None` on overflow.
+fn lenient_value(numerals: &[(usize, char, u64)]) -> Option<u64> {
+    let mut value: u64 = 0;
+    let mut greatest: u64 = 0;
+    for &(_, _, current) in numerals.iter().rev() {
+        if current < greatest {
+            value = value.checked_sub(current)?;
+        } else {
+            value = value.checked_add(current)?;
+            greatest = current;
+        }
+    }
+    Some(value)
+}
+
+/// A value parsed by `to_arabic_lenient`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LenientValue {
+    pub value: u64,
+    /// Whether the input is spelled as `to_roman` writes the value.
+    pub canonical: bool,
+}
+
+/// `to_arabic`, accepting the numerals that do not follow the rules.
+///
+/// Numerals such as "VIIII" or "IM" are commonly written, and have an obvious
+/// value, computed as for `suggest`. These are reported as not canonical,
+/// as are the numerals that `to_arabic` accepts but `to_roman` spells
+/// differently, such as "IIII".
+/// Inputs that are empty or contain invalid characters are still rejected.
+///
+///     use numerals::roman::to_arabic_lenient;
+///
+///     let x = to_arabic_lenient("MDCCCCX").unwrap();
+///     assert_eq!(x.value, 1910);
+///     assert!(!x.canonical);
+///
+///     let x = to_arabic_lenient("MCMX").unwrap();
+///     assert_eq!(x.value, 1910);
+///     assert!(x.canonical);
+pub fn to_arabic_lenient(input: &str) -> Result<LenientValue, NumeralError> {
+    if let Ok(value) = to_arabic(input) {
+        return Ok(LenientValue {
+            value,
+            canonical: is_canonical(input, value),
+        });
+    }
+    if input.is_empty() {
+        return Err(NumeralError::Empty);
+    }
+
+    match lenient_value(&numerals(input)?) {
+        Some(value) => Ok(LenientValue {
+            value,
+            canonical: false,
+        }),
+        None => Err(NumeralError::Overflow),
+    }
+}
+
+/// Write any numeral accepted by `to_arabic_lenient` in its canonical form.
+///
+/// The numeral is written in upper case, with unicode numerals if the input
+/// contains any. Values beyond `MAX` are reported as out of range.
+///
+///     use numerals::roman::canonicalize;
+///
+///     assert_eq!(canonicalize("IIII").unwrap(), "IV");
+///     assert_eq!(canonicalize("lxxxx").unwrap(), "XC");
+///     assert_eq!(canonicalize("MDCCCCX").unwrap(), "MCMX");
+pub fn canonicalize(input: &str) -> Result<String, NumeralError> {
+    let parsed = to_arabic_lenient(input)?;
+    let unicode = input
+        .chars()
+        .any(|c| !c.is_ascii() && value_of(c).is_some());
+    to_roman(parsed.value, unicode)
+}
+
+/// A variant form of the roman numerals, found in historical sources.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Variant {
+    /// Four or more similar numerals in a row, as in VIIII for 9.
+    Additive,
+    /// Several similar numerals subtracted at once, as in IIX for 8, or
+    /// IIXX and XIIX for 18.
+    RepeatedSubtraction,
+    /// A numeral subtracted from one more than ten times larger, as in IC
+    /// for 99.
+    WideSubtraction,
+    /// A V, L or D subtracted, as in VL for 45.
+    SubtractedFive,
+    /// The characters ↅ for 6 and ↆ for 50.
+    ArchaicForm,
+}
+
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Variant::Additive => write!(f, "four or more similar numerals in a row"),
+            Variant::RepeatedSubtraction => write!(f, "several numerals subtracted at once"),
+            Variant::WideSubtraction => write!(f, "subtraction from a numeral too large"),
+            Variant::SubtractedFive => write!(f, "subtraction of V, L or D"),
+            Variant::ArchaicForm => write!(f, "archaic numeral character"),
+        }
+    }
+}
+
+/// A value parsed by `to_arabic_historical`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HistoricalValue {
+    pub value: u64,
+    /// The variant forms used by the numeral, as found from its right end.
+    /// This is empty for a canonical numeral.
+    pub variants: Vec<Variant>,
+}
+
+/// `to_arabic`, also accepting the variant forms attested in inscriptions
+/// and manuscripts, which are reported along with the value.
+///
+/// Numerals smaller than the greatest one on their right are subtracted, as
+/// in `to_arabic_lenient`, but only the variants listed in `Variant` are
+/// accepted: IXC or LL are still rejected.
+///
+///     use numerals::roman::{to_arabic_historical, Variant};
+///
+///     let parsed = to_arabic_historical("IIXX").unwrap();
+///     assert_eq!(parsed.value, 18);
+///     assert_eq!(parsed.variants, vec![Variant::RepeatedSubtraction]);
+///     assert_eq!(to_arabic_historical("IIII").unwrap().variants, vec![Variant::Additive]);
+///     assert!(to_arabic_historical("XIV").unwrap().variants.is_empty());
+pub fn to_arabic_historical(input: &str) -> Result<HistoricalValue, NumeralError> {
+    let mut variants = Vec::new();
+    if input.contains(['ↅ', 'ↆ']) {
+        variants.push(Variant::ArchaicForm);
+    }
+    let rules = RuleSet {
+        value_order: true,
+        ..RuleSet::default()
+    };
+    // The valid numerals not spelled as `to_roman` writes them, as IIII or
+    // VL, are looked for variants as well
+    match to_arabic_with_rules(input, &rules) {
+        Ok(value) if is_canonical(input, value) => return Ok(HistoricalValue { value, variants }),
+        Ok(_) | Err(NumeralError::InvalidSequence { .. }) => (),
+        Err(error) => return Err(error),
+    };
+
+    let mut value: u64 = 0;
+    let mut greatest: u64 = 0;
+    // The previous numeral, whether it was subtracted, and how many times
+    // it was repeated
+    let mut previous: Option<(u64, bool, usize)> = None;
+    for &(index, ch, current) in numerals(input)?.iter().rev() {
+        let subtracted = current < greatest;
+        let repeated = match previous {
+            Some((last, was_subtracted, count))
+                if last == current && was_subtracted == subtracted =>
+            {
+                count + 1
+            }
+            _ => 1,
+        };
+        let variant = match previous {
+            // Subtracting half the numeral or more, as in VX or IIIIV, is no
+            // variant
+            _ if subtracted && 2 * repeated as u64 * current >= greatest => {
+                return Err(NumeralError::InvalidSequence { ch, index });
+            }
+            Some((last, true, _)) if subtracted && last != current => {
+                return Err(NumeralError::InvalidSequence { ch, index });
+            }
+            Some((last, false, _))
+                if !subtracted && last == current && !is_power_of_ten(current) =>
+            {
+                return Err(NumeralError::InvalidSequence { ch, index });
+            }
+            Some((last, true, _)) if subtracted && last == current => {
+                Some(Variant::RepeatedSubtraction)
+            }
+            Some((last, false, 3)) if !subtracted && last == current => Some(Variant::Additive),
+            _ if subtracted && !is_power_of_ten(current) => Some(Variant::SubtractedFive),
+            _ if subtracted && greatest > 10 * current => Some(Variant::WideSubtraction),
+            _ => None,
+        };
+        if let Some(variant) = variant {
+            if !variants.contains(&variant) {
+                variants.push(variant);
+            }
+        }
+
+        previous = Some((current, subtracted, repeated));
+        value = match subtracted {
+            true => value.checked_sub(current),
+            false => {
+                greatest = current;
+                value.checked_add(current)
+            }
+        }
+        .ok_or(NumeralError::Overflow)?;
+    }
+
+    // A numeral breaking the rules in no attested way
+    if variants
+        .iter()
+        .all(|&variant| variant == Variant::ArchaicForm)
+    {
+        return to_arabic(input).map(|value| HistoricalValue { value, variants });
+    }
+    Ok(HistoricalValue { value, variants })
+}
+
+/// `to_arabic` for signed values, accepting a leading minus sign.
+///
+/// Both the ascii hyphen and the unicode minus sign are accepted.
+///
+///     use numerals::roman::to_arabic_signed;
+///
+///     assert_eq!(to_arabic_signed("-XIV"), Ok(-14));
+///     assert_eq!(to_arabic_signed("XIV"), Ok(14));
+pub fn to_arabic_signed(input: &str) -> Result<i64, NumeralError> {
+    let (negative, numeral) = match input.strip_prefix(['-', '−']) {
+        Some(numeral) => (true, numeral),
+        None => (false, input),
+    };
+    let value = to_arabic(numeral).map_err(|error| match error {
+        NumeralError::InvalidCharacter { ch, index } if negative => {
+            NumeralError::InvalidCharacter {
+                ch,
+                index: index + 1,
+            }
+        }
+        NumeralError::InvalidSequence { ch, index } if negative => NumeralError::InvalidSequence {
+            ch,
+            index: index + 1,
+        },
+        error => error,
+    })?;
+    let value = i64::try_from(value).map_err(|_| NumeralError::Overflow)?;
+    match negative {
+        true => Ok(-value),
+        false => Ok(value),
+    }
+}
+
+/// `to_arabic`, parsing N as zero, as found in medieval computus tables.
+///
+///     use numerals::roman::to_arabic_with_zero;
+///
+///     assert_eq!(to_arabic_with_zero("N"), Ok(0));
+///     assert_eq!(to_arabic_with_zero("X"), Ok(10));
+pub fn to_arabic_with_zero(input: &str) -> Result<u64, NumeralError> {
+    match input.eq_ignore_ascii_case(NULLA) {
+        true => Ok(0),
+        false => to_arabic(input),
+    }
+}
+
+/// Parse a numeral with roman fractions, as a value counted in twelfths.
+///
+/// The whole part, which can be left out, is followed by S for half a unit,
+/// and up to five dots for the twelfths. The dots can be written • or ·.
+///
+///     use numerals::roman::to_arabic_twelfths;
+///
+///     assert_eq!(to_arabic_twelfths("IIS"), Ok(30));
+///     assert_eq!(to_arabic_twelfths("S··"), Ok(8));
+///     assert_eq!(to_arabic_twelfths("XIV"), Ok(168));
+pub fn to_arabic_twelfths(input: &str) -> Result<u64, NumeralError> {
+    if input.is_empty() {
+        return Err(NumeralError::Empty);
+    }
+
+    let is_fraction = |ch: char| matches!(ch, 'S' | 's' | '•' | '·');
+    let (whole, fraction) = match input.find(is_fraction) {
+        Some(start) => input.split_at(start),
+        None => (input, ""),
+    };
+    let whole = match whole.is_empty() {
+        true => 0,
+        false => to_arabic(whole)?,
+    };
+
+    let offset = input.chars().count() - fraction.chars().count();
+    let mut unciae = 0;
+    for (index, ch) in fraction.chars().enumerate() {
+        let index = offset + index;
+        match ch {
+            'S' | 's' if index == offset => unciae += 6,
+            '•' | '·' if unciae % 6 < 5 => unciae += 1,
+            _ if is_fraction(ch) => return Err(NumeralError::InvalidSequence { ch, index }),
+            _ => return Err(NumeralError::InvalidCharacter { ch, index }),
+        }
+    }
+    whole
+        .checked_mul(12)
+        .and_then(|twelfths| twelfths.checked_add(unciae))
+        .ok_or(NumeralError::Overflow)
+}
+
+/// The separators commonly found between the numerals of inscriptions and
+/// citations: whitespace, dots and interpuncts.
+pub const SEPARATORS: [char; 7] = [' ', '\t', '\n', '\r', '.', '·', '⸱'];
+
+/// `to_arabic`, skipping the `separators` found in the input.
+///
+/// Errors report the positions in the input, separators included.
+///
+///     use numerals::roman::{to_arabic_with_separators, SEPARATORS};
+///
+///     assert_eq!(to_arabic_with_separators("M CM XC IX", &SEPARATORS), Ok(1999));
+///     assert_eq!(to_arabic_with_separators("M·CM·XC·IX\n", &SEPARATORS), Ok(1999));
+///     assert_eq!(to_arabic_with_separators("M_CM", &['_']), Ok(1900));
+pub fn to_arabic_with_separators(input: &str, separators: &[char]) -> Result<u64, NumeralError> {
+    let (indices, numeral): (Vec<usize>, String) = input
+        .chars()
+        .enumerate()
+        .filter(|(_, ch)| !separators.contains(ch))
+        .unzip();
+
+    to_arabic(&numeral).map_err(|error| match error {
+        NumeralError::InvalidCharacter { ch, index } => NumeralError::InvalidCharacter {
+            ch,
+            index: indices[index],
+        },
+        NumeralError::InvalidSequence { ch, index } => NumeralError::InvalidSequence {
+            ch,
+            index: indices[index],
+        },
+        error => error,
+    })
+}
+
+/// The ordinal markers recognized after a numeral, as in XIV., XIXe, XIXᵉ or
+/// XXIIIrd. The longer markers come first.
+pub const ORDINAL_MARKERS: [&str; 17] = [
+    "ème", "eme", "ᵉʳ", "ʳᵉ", ".º", ".ª", "er", "re", "st", "nd", "rd", "th", "e", "ᵉ", "º", "ª",
+    ".",
+];
+
+/// A value parsed by `to_arabic_ordinal`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OrdinalValue<'a> {
+    pub value: u64,
+    /// The ordinal marker found after the numeral, if any.
+    pub marker: Option<&'a str>,
+}
+
+/// `to_arabic`, accepting one of the `ORDINAL_MARKERS` after the numeral,
+/// which is reported along with the value.
+///
+///     use numerals::roman::to_arabic_ordinal;
+///
+///     let parsed = to_arabic_ordinal("XIXe").unwrap();
+///     assert_eq!(parsed.value, 19);
+///     assert_eq!(parsed.marker, Some("e"));
+///     assert_eq!(to_arabic_ordinal("XIX").unwrap().marker, None);
+pub fn to_arabic_ordinal(input: &str) -> Result<OrdinalValue<'_>, NumeralError> {
+    for marker in ORDINAL_MARKERS.iter() {
+        let numeral = match input.strip_suffix(marker) {
+            Some(numeral) => numeral,
+            None => continue,
+        };
+        if numeral.chars().last().and_then(numeral_value).is_some() {
+            return Ok(OrdinalValue {
+                value: to_arabic(numeral)?,
+                marker: Some(&input[numeral.len()..]),
+            });
+        }
+    }
+
+    Ok(OrdinalValue {
+        value: to_arabic(input)?,
+        marker: None,
+    })
+}
+
+/// The scripts the numerals of an input are written in, found by `scripts`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Scripts {
+    /// Whether the input contains numerals written with ascii letters.
+    pub ascii: bool,
+    /// Whether the input contains unicode numerals, such as Ⅽ or ↀ.
+    pub unicode: bool,
+}
+
+impl Scripts {
+    /// Whether the input mixes ascii letters and unicode numerals.
+    pub fn is_mixed(&self) -> bool {
+        self.ascii && self.unicode
+    }
+}
+
+/// Find the scripts the numerals of `input` are written in.
+///
+/// Scanned documents often mix ascii letters with the unicode numerals that
+/// look the same. These are accepted by `to_arabic`, but rejected with
+/// `Strictness::Strict`. Characters that are not numerals are ignored.
+///
+///     use numerals::roman::scripts;
+///
+///     assert!(scripts("MⅭM").is_mixed());
+///     assert!(!scripts("MCM").is_mixed());
+pub fn scripts(input: &str) -> Scripts {
+    let mut scripts = Scripts::default();
+    for ch in input.chars().filter(|&ch| numeral_value(ch).is_some()) {
+        match ch.is_ascii() {
+            true => scripts.ascii = true,
+            false => scripts.unicode = true,
+        }
+    }
+    scripts
+}
+
+/// Write all the numerals of `input` in a single script.
+///
+/// The numerals are written with unicode characters, or with ascii letters,
+/// keeping their case. Precomposed numerals such as Ⅻ are written with
+/// several letters in ascii. The numerals with no ascii counterpart, such as
+/// ↁ, and the other characters are kept as they are.
+///
+///     use numerals::roman::normalize_script;
+///
+///     assert_eq!(normalize_script("MⅭM", false), "MCM");
+///     assert_eq!(normalize_script("MⅭM", true), "ⅯⅭⅯ");
+///     assert_eq!(normalize_script("mmⅻ", false), "mmxii");
+pub fn normalize_script(input: &str, use_unicode: bool) -> String {
+    let mut ret = String::with_capacity(input.len());
+    for ch in input.chars() {
+        let value = match numeral_value(ch) {
+            Some(value) if ch.is_ascii() == use_unicode => value,
+            _ => {
+                ret.push(ch);
+                continue;
+            }
+        };
+        match to_roman(value, use_unicode) {
+            Ok(numeral) if ch.is_lowercase() => ret.push_str(&numeral.to_lowercase()),
+            Ok(numeral) => ret.push_str(&numeral),
+            Err(_) => ret.push(ch),
+        }
+    }
+    ret
+}
+
+/// Apply the unicode compatibility normalization (NFKC) to `input`.
+///
+/// Fullwidth letters are written in ascii, as are the precomposed numerals,
+/// so that ＭＣＭ and Ⅻ become MCM and XII. The spacing overline ‾ becomes
+/// the combining one of the vinculum notation.
+///
+///     use numerals::roman::normalize;
+///
+///     assert_eq!(normalize("ＭＣＭⅫ"), "MCMXII");
+///     assert_eq!(normalize("V‾"), "V\u{305}");
+#[cfg(feature = "unicode-normalization")]
+pub fn normalize(input: &str) -> String {
+    use unicode_normalization::UnicodeNormalization;
+
+    let mut ret = String::with_capacity(input.len());
+    let mut chars = input.nfkc().peekable();
+    while let Some(ch) = chars.next() {
+        // The spacing overline is decomposed into a space and the combining one
+        if ch == ' ' && chars.peek() == Some(&VINCULUM) {
+            continue;
+        }
+        ret.push(ch);
+    }
+    ret
+}
+
+/// `to_arabic`, once `input` is normalized by `normalize`.
+///
+/// The errors report the positions of the characters in the normalized input.
+///
+///     use numerals::roman::to_arabic_normalized;
+///
+///     assert_eq!(to_arabic_normalized("ＭＣＭⅩＣⅨ"), Ok(1999));
+#[cfg(feature = "unicode-normalization")]
+pub fn to_arabic_normalized(input: &str) -> Result<u64, NumeralError> {
+    to_arabic(normalize(input))
+}
+
+/// `to_arabic`, checking the numeral as closely as required.
+///
+/// The strict numerals follow every rule of `RuleSet::strict`: IIII and IM are
+/// rejected, and ascii letters cannot be mixed with unicode numerals. The
+/// standard numerals are those accepted by `to_arabic`, and the lenient ones by
+/// `to_arabic_lenient`.
+///
+///     use numerals::roman::to_arabic_with_strictness;
+///     use numerals::Strictness;
+///
+///     assert!(to_arabic_with_strictness("IIII", Strictness::Strict).is_err());
+///     assert_eq!(to_arabic_with_strictness("IIII", Strictness::Standard), Ok(4));
+///     assert_eq!(to_arabic_with_strictness("XXXX", Strictness::Lenient), Ok(40));
+pub fn to_arabic_with_strictness(input: &str, strictness: Strictness) -> Result<u64, NumeralError> {
+    match strictness {
+        Strictness::Strict => {
+            let mut scripts = input.chars().enumerate().filter(|&(_, ch)| ch != VINCULUM);
+            if let Some((_, first)) = scripts.next() {
+                let mixed = scripts.find(|(_, ch)| ch.is_ascii() != first.is_ascii());
+                if let Some((index, ch)) = mixed {
+                    return Err(NumeralError::InvalidCharacter { ch, index });
+                }
+            }
+            let value = to_arabic_with_rules(input, &RuleSet::strict())?;
+            if let Some((index, ch)) = find_non_canonical(input, value) {
+                return Err(NumeralError::InvalidSequence { ch, index });
+            }
+            Ok(value)
+        }
+        Strictness::Standard => to_arabic(input),
+        Strictness::Lenient => to_arabic_lenient(input).map(|parsed| parsed.value),
+    }
+}
+
+/// Find the first numeral of `input` differing from the spelling of its value
+/// by `to_roman`, if it is not canonical.
+fn find_non_canonical(input: &str, value: u64) -> Option<(usize, char)> {
+    if is_canonical(input, value) {
+        return None;
+    }
+    let spelling: Vec<u64> = to_roman(value, false)
+        .ok()?
+        .chars()
+        .filter_map(value_of)
+        .collect();
+    let numerals = numerals(input).ok()?;
+    let position = numerals
+        .iter()
+        .zip(spelling.iter())
+        .take_while(|&(&(_, _, numeral), &canonical)| numeral == canonical)
+        .count();
+    let &(index, ch, _) = numerals.get(position).or_else(|| numerals.last())?;
+    Some((index, ch))
+}
+
+/// Whether `input` is spelled as `to_roman` writes its value, in any case or
+/// script. Beyond `MAX`, any numeral accepted by `to_arabic` is canonical.
+fn is_canonical(input: &str, value: u64) -> bool {
+    let spelling = match to_roman(value, false) {
+        Ok(spelling) => spelling,
+        Err(_) => return true,
+    };
+    match numerals(input) {
+        Ok(numerals) => numerals
+            .iter()
+            .map(|&(_, _, value)| value)
+            .eq(spelling.chars().filter_map(value_of)),
+        Err(_) => false,
+    }
+}
+
+/// How the values beyond `MAX` are written.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Notation {
+    /// No numeral beyond `MAX`.
+    #[default]
+    Standard,
+    /// Overlines multiplying the numerals by 1000, as written by `to_vinculum`.
+    Vinculum,
+    /// Parentheses multiplying the numerals by 1000, as written by `to_brackets`.
+    Brackets,
+    /// The apostrophus numerals, as written by `to_apostrophus`.
+    Apostrophus,
+}
+
+/// The options of `to_roman_with` and `to_arabic_with`.
+///
+///     use numerals::roman::{to_arabic_with, to_roman_with, RomanOptions};
+///
+///     let options = RomanOptions::new().unicode(true).lowercase(true).clock();
+///     assert_eq!(to_roman_with(14, &options).unwrap(), "ⅹⅰⅰⅰⅰ");
+///
+///     let options = RomanOptions::new().lenient().nulla(true);
+///     assert_eq!(to_arabic_with("XXXX", &options), Ok(40));
+///     assert_eq!(to_arabic_with("N", &options), Ok(0));
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RomanOptions {
+    unicode: bool,
+    lowercase: bool,
+    compact: bool,
+    separator: Option<char>,
+    style: Style,
+    notation: Notation,
+    nulla: bool,
+    strictness: Strictness,
+    rules: RuleSet,
+}
+
+impl RomanOptions {
+    /// The default options: ascii numerals in upper case, checked by the
+    /// rules of `to_arabic`.
+    pub fn new() -> RomanOptions {
+        RomanOptions::default()
+    }
+
+    /// Write unicode numerals rather than ascii letters.
+    pub fn unicode(mut self, unicode: bool) -> RomanOptions {
+        self.unicode = unicode;
+        self
+    }
+
+    /// Write the numerals in lower case.
+    pub fn lowercase(mut self, lowercase: bool) -> RomanOptions {
+        self.lowercase = lowercase;
+        self
+    }
+
+    /// End unicode numerals with a precomposed character, as in `to_roman_compact`.
+    pub fn compact(mut self, compact: bool) -> RomanOptions {
+        self.compact = compact;
+        self
+    }
+
+    /// Write `separator` between the numerals of each decimal place, as in
+    /// M·CM·XC·IX.
+    pub fn separator(mut self, separator: Option<char>) -> RomanOptions {
+        self.separator = separator;
+        self
+    }
+
+    /// Write the numerals in the given style.
+    pub fn style(mut self, style: Style) -> RomanOptions {
+        self.style = style;
+        self
+    }
+
+    /// Write 4 as IIII, as on clock faces.
+    pub fn clock(self) -> RomanOptions {
+        self.style(Style::Clock)
+    }
+
+    /// Write the numerals without subtractions.
+    pub fn additive(self) -> RomanOptions {
+        self.style(Style::Additive)
+    }
+
+    /// Write all values in the given notation, which then ignores the other
+    /// formatting options, short of the case.
+    pub fn notation(mut self, notation: Notation) -> RomanOptions {
+        self.notation = notation;
+        self
+    }
+
+    /// Write and parse zero as N.
+    pub fn nulla(mut self, nulla: bool) -> RomanOptions {
+        self.nulla = nulla;
+        self
+    }
+
+    /// Check the numerals as closely as required.
+    pub fn strictness(mut self, strictness: Strictness) -> RomanOptions {
+        self.strictness = strictness;
+        self
+    }
+
+    /// Only accept the numerals following every rule.
+    pub fn strict(self) -> RomanOptions {
+        self.strictness(Strictness::Strict)
+    }
+
+    /// Accept any numeral with an obvious value.
+    pub fn lenient(self) -> RomanOptions {
+        self.strictness(Strictness::Lenient)
+    }
+
+    /// Check the given rules, with the standard strictness.
+    pub fn rules(mut self, rules: RuleSet) -> RomanOptions {
+        self.rules = rules;
+        self
+    }
+
+    /// The least and greatest values that can be written with these options.
+    fn bounds(&self) -> (u64, u64) {
+        let min = match self.nulla {
+            true => 0,
+            false => 1,
+        };
+        let max = match self.notation {
+            Notation::Standard => MAX,
+            Notation::Apostrophus => MAX_APOSTROPHUS,
+            Notation::Vinculum | Notation::Brackets => u64::MAX,
+        };
+        (min, max)
+    }
+}
+
+impl From<&Options> for RomanOptions {
+    fn from(options: &Options) -> RomanOptions {
+        let style = match (options.additive, options.clock) {
+            (true, _) => Style::Additive,
+            (false, true) => Style::Clock,
+            (false, false) => Style::Subtractive,
+        };
+        let notation = match options.vinculum {
+            true => Notation::Vinculum,
+            false => Notation::Standard,
+        };
+        RomanOptions::new()
+            .unicode(options.unicode)
+            .lowercase(options.lowercase)
+            .compact(options.compact)
+            .style(style)
+            .notation(notation)
+            .nulla(options.nulla)
+            .strictness(options.strictness)
+    }
+}
+
+/// `to_roman`, following the options.
+pub fn to_roman_with(input: u64, options: &RomanOptions) -> Result<String, NumeralError> {
+    let numeral = match (input, options.nulla, options.notation) {
+        (0, true, _) => Ok(NULLA.into()),
+        (_, _, Notation::Vinculum) => to_vinculum(input),
+        (_, _, Notation::Brackets) => to_brackets(input),
+        (_, _, Notation::Apostrophus) => to_apostrophus(input),
+        (_, _, Notation::Standard) => match options.separator {
+            Some(separator) => {
+                write_places(input, separator, |place| to_roman_standard(place, options))
+            }
+            None => to_roman_standard(input, options),
+        },
+    }?;
+    match options.lowercase {
+        true => Ok(numeral.to_lowercase()),
+        false => Ok(numeral),
+    }
+}
+
+/// `to_roman_with`, in the standard notation.
+fn to_roman_standard(input: u64, options: &RomanOptions) -> Result<String, NumeralError> {
+    match options.unicode && options.compact {
+        true => to_roman_compact(input, options.style),
+        false => to_roman_styled(input, options.unicode, options.style),
+    }
+}
+
+/// `to_arabic`, following the options.
+pub fn to_arabic_with(input: &str, options: &RomanOptions) -> Result<u64, NumeralError> {
+    if options.nulla && input.eq_ignore_ascii_case(NULLA) {
+        return Ok(0);
+    }
+    match options.strictness {
+        Strictness::Standard => to_arabic_with_rules(input, &options.rules),
+        strictness => to_arabic_with_strictness(input, strictness),
+    }
+}
+
+/// Iterator over the numerals written for a range of values, created by
+/// `iter`.
+#[derive(Clone, Debug)]
+pub struct Iter {
+    values: RangeInclusive<u64>,
+    options: RomanOptions,
+}
+
+impl Iterator for Iter {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        let value = self.values.next()?;
+        // The values are within the bounds of the options
+        Some(to_roman_with(value, &self.options).unwrap())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Iter {
+    fn next_back(&mut self) -> Option<String> {
+        let value = self.values.next_back()?;
+        Some(to_roman_with(value, &self.options).unwrap())
+    }
+}
+
+/// Write the numerals for a range of values, one at a time, as `to_roman_with`.
+///
+/// The range is narrowed to the values the options can write: from 1, or 0
+/// with nulla, up to `MAX` in the standard notation.
+///
+///     use numerals::roman::{iter, RomanOptions};
+///
+///     let options = RomanOptions::new().lowercase(true);
+///     let pages: Vec<String> = iter(1..=4, &options).collect();
+///     assert_eq!(pages, vec!["i", "ii", "iii", "iv"]);
+///     assert_eq!(iter(.., &options).last().unwrap(), "mmmcmxcix");
+pub fn iter<R: RangeBounds<u64>>(range: R, options: &RomanOptions) -> Iter {
+    let (min, max) = options.bounds();
+    let start = match range.start_bound() {
+        Bound::Included(&start) => Some(start),
+        Bound::Excluded(&start) => start.checked_add(1),
+        Bound::Unbounded => Some(min),
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => Some(end),
+        Bound::Excluded(&end) => end.checked_sub(1),
+        Bound::Unbounded => Some(max),
+    };
+    let values = match (start, end) {
+        (Some(start), Some(end)) => start.max(min)..=end.min(max),
+        _ => RangeInclusive::new(1, 0),
+    };
+    Iter {
+        values,
+        options: *options,
+    }
+}
+
+/// Compare two roman numerals by their value.
+///
+/// This is a total order, so that it can be used for sorting: the strings
+/// that are not valid numerals come after the valid ones, in lexicographic
+/// order.
+///
+///     use numerals::roman::cmp_str;
+///
+///     let mut chapters = vec!["XC", "IX", "XI"];
+///     chapters.sort_by(|a, b| cmp_str(a, b));
+///     assert_eq!(chapters, vec!["IX", "XI", "XC"]);
+pub fn cmp_str(a: &str, b: &str) -> Ordering {
+    match (to_arabic(a), to_arabic(b)) {
+        (Ok(a), Ok(b)) => a.cmp(&b),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        (Err(_), Err(_)) => a.cmp(b),
+    }
+}
+
+/// The errors found by `compare` and `equals`, on either side.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CompareError {
+    /// The error of the first numeral, if it is invalid.
+    pub left: Option<NumeralError>,
+    /// The error of the second numeral, if it is invalid.
+    pub right: Option<NumeralError>,
+}
+
+impl fmt::Display for CompareError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.left, self.right) {
+            (Some(left), Some(right)) => write!(f, "Left: {}, right: {}", left, right),
+            (Some(left), None) => write!(f, "Left: {}", left),
+            (None, Some(right)) => write!(f, "Right: {}", right),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CompareError {}
+
+/// Compare the values of two roman numerals.
+///
+/// Unlike `cmp_str`, both numerals should be valid. Otherwise, the errors
+/// are reported for each of them.
+///
+///     use core::cmp::Ordering;
+///     use numerals::roman::compare;
+///
+///     assert_eq!(compare("XIV", "XV"), Ok(Ordering::Less));
+///     assert_eq!(compare("IIII", "IV"), Ok(Ordering::Equal));
+///     assert!(compare("XIV", "VX").unwrap_err().right.is_some());
+pub fn compare(a: &str, b: &str) -> Result<Ordering, CompareError> {
+    match (to_arabic(a), to_arabic(b)) {
+        (Ok(a), Ok(b)) => Ok(a.cmp(&b)),
+        (a, b) => Err(CompareError {
+            left: a.err(),
+            right: b.err(),
+        }),
+    }
+}
+
+/// Whether two roman numerals have the same value, as found by `compare`.
+///
+///     use numerals::roman::equals;
+///
+///     assert_eq!(equals("ⅩⅣ", "xiv"), Ok(true));
+///     assert_eq!(equals("XIV", "XV"), Ok(false));
+pub fn equals(a: &str, b: &str) -> Result<bool, CompareError> {
+    compare(a, b).map(|ordering| ordering == Ordering::Equal)
+}
+
+/// The difference between the values of two roman numerals, `a - b`.
+///
+/// Both numerals should be valid, and their values fit in an `i64`.
+/// Otherwise, the errors are reported for each of them, as in `compare`.
+///
+///     use numerals::roman::diff;
+///
+///     // The reign of Louis XIV
+///     assert_eq!(diff("MDCCXV", "MDCXLIII"), Ok(72));
+///     assert_eq!(diff("X", "XV"), Ok(-5));
+pub fn diff(a: &str, b: &str) -> Result<i64, CompareError> {
+    let signed = |input: &str| {
+        let value = to_arabic(input)?;
+        i64::try_from(value).map_err(|_| NumeralError::OutOfRange {
+            value,
+            min: 0,
+            max: i64::MAX as u64,
+        })
+    };
+    match (signed(a), signed(b)) {
+        (Ok(a), Ok(b)) => Ok(a - b),
+        (a, b) => Err(CompareError {
+            left: a.err(),
+            right: b.err(),
+        }),
+    }
+}
+
+/// `diff`, written as a roman numeral.
+///
+/// A negative difference is written with a minus sign, as by
+/// `to_roman_signed`, and a null one as N. The differences beyond 3999 are
+/// written in the vinculum notation.
+///
+///     use numerals::roman::diff_roman;
+///
+///     assert_eq!(diff_roman("MDCCXV", "MDCXLIII", false).unwrap(), "LXXII");
+///     assert_eq!(diff_roman("X", "XV", false).unwrap(), "-V");
+///     assert_eq!(diff_roman("X", "X", false).unwrap(), "N");
+pub fn diff_roman(a: &str, b: &str, use_unicode: bool) -> Result<String, CompareError> {
+    let value = diff(a, b)?;
+    let mut ret = String::new();
+    if value < 0 {
+        ret.push('-');
+    }
+    // The magnitude is always in range
+    let numeral = match value.unsigned_abs() {
+        0 => String::from(NULLA),
+        magnitude @ 1..=MAX => to_roman(magnitude, use_unicode).unwrap(),
+        magnitude => to_vinculum(magnitude).unwrap(),
+    };
+    ret.push_str(&numeral);
+    Ok(ret)
+}
+
+/// A value that can be written as a standard roman numeral.
+///
+///     use numerals::roman::RomanNumeral;
+///
+///     let numeral: RomanNumeral = "XIV".parse().unwrap();
+///     assert_eq!(numeral.value(), 14);
+///     assert_eq!(format!("{}", RomanNumeral::new(14).unwrap()), "XIV");
+///
+/// The alternate flag formats the numeral with the unicode characters:
+///
+///     # use numerals::roman::RomanNumeral;
+///     assert_eq!(format!("{:#}", RomanNumeral::new(14).unwrap()), "ⅩⅠⅤ");
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RomanNumeral(u64);
+
+impl RomanNumeral {
+    /// Wrap `value`, which should be between 1 and `MAX` inclusive.
+    pub fn new(value: u64) -> Result<RomanNumeral, NumeralError> {
+        if !(1..=MAX).contains(&value) {
+            return Err(NumeralError::OutOfRange {
+                value,
+                min: 1,
+                max: MAX,
+            });
+        }
+        Ok(RomanNumeral(value))
+    }
+
+    /// Parse a roman numeral, as done by `to_arabic`.
+    pub fn parse(input: &str) -> Result<RomanNumeral, NumeralError> {
+        RomanNumeral::new(to_arabic(input)?)
+    }
+
+    pub fn value(self) -> u64 {
+        self.0
+    }
+
+    /// Iterate over the numerals whose values are in `range`.
+    ///
+    /// The range is clamped to the values that can be represented, so that
+    /// `RomanNumeral::range(..)` yields all of them.
+    ///
+    ///     use numerals::roman::RomanNumeral;
+    ///
+    ///     let list: Vec<String> = RomanNumeral::range(1..=3).map(|n| n.to_string()).collect();
+    ///     assert_eq!(list, vec!["I", "II", "III"]);
+    pub fn range<R: RangeBounds<u64>>(range: R) -> RomanRange {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 1,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end,
+            Bound::Excluded(&end) => end.saturating_sub(1),
+            Bound::Unbounded => MAX,
+        };
+        RomanRange {
+            next: start.max(1),
+            last: end.min(MAX),
+        }
+    }
+
+    /// Add two numerals, returning `None` if the sum is greater than `MAX`.
+    pub fn checked_add(self, rhs: RomanNumeral) -> Option<RomanNumeral> {
+        (self + rhs).ok()
+    }
+
+    /// Subtract two numerals, returning `None` unless the difference is positive.
+    pub fn checked_sub(self, rhs: RomanNumeral) -> Option<RomanNumeral> {
+        (self - rhs).ok()
+    }
+
+    /// Multiply two numerals, returning `None` if the product is greater than `MAX`.
+    pub fn checked_mul(self, rhs: RomanNumeral) -> Option<RomanNumeral> {
+        (self * rhs).ok()
+    }
+}
+
+/// Iterator over successive roman numerals, created by `RomanNumeral::range`.
+#[derive(Clone, Debug)]
+pub struct RomanRange {
+    next: u64,
+    last: u64,
+}
+
+impl Iterator for RomanRange {
+    type Item = RomanNumeral;
+
+    fn next(&mut self) -> Option<RomanNumeral> {
+        if self.next > self.last {
+            return None;
+        }
+        self.next += 1;
+        Some(RomanNumeral(self.next - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.last + 1).saturating_sub(self.next) as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for RomanRange {
+    fn next_back(&mut self) -> Option<RomanNumeral> {
+        if self.next > self.last {
+            return None;
+        }
+        self.last -= 1;
+        Some(RomanNumeral(self.last + 1))
+    }
+}
+
+impl ExactSizeIterator for RomanRange {}
+
+// The operators return a Result, as the outcome may not be representable.
+// Results below 1 are reported as out of range with a value of 0.
+
+impl Add for RomanNumeral {
+    type Output = Result<RomanNumeral, NumeralError>;
+
+    fn add(self, rhs: RomanNumeral) -> Self::Output {
+        RomanNumeral::new(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl Sub for RomanNumeral {
+    type Output = Result<RomanNumeral, NumeralError>;
+
+    fn sub(self, rhs: RomanNumeral) -> Self::Output {
+        RomanNumeral::new(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl Mul for RomanNumeral {
+    type Output = Result<RomanNumeral, NumeralError>;
+
+    fn mul(self, rhs: RomanNumeral) -> Self::Output {
+        RomanNumeral::new(self.0.saturating_mul(rhs.0))
+    }
+}
+
+impl fmt::Display for RomanNumeral {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        to_roman_into(self.0, f.alternate(), f).map_err(|_| fmt::Error)
+    }
+}
+
+impl FromStr for RomanNumeral {
+    type Err = NumeralError;
+
+    fn from_str(input: &str) -> Result<RomanNumeral, NumeralError> {
+        RomanNumeral::parse(input)
+    }
+}
+
+impl TryFrom<u64> for RomanNumeral {
+    type Error = NumeralError;
+
+    fn try_from(value: u64) -> Result<RomanNumeral, NumeralError> {
+        RomanNumeral::new(value)
+    }
+}
+
+impl TryFrom<&str> for RomanNumeral {
+    type Error = NumeralError;
+
+    fn try_from(input: &str) -> Result<RomanNumeral, NumeralError> {
+        RomanNumeral::parse(input)
+    }
+}
+
+impl From<RomanNumeral> for u64 {
+    fn from(numeral: RomanNumeral) -> u64 {
+        numeral.0
+    }
+}
+
+// RomanNumeral is serialized as its numeral, and validated when deserialized.
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for RomanNumeral {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for RomanNumeral {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = RomanNumeral;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a roman numeral")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, input: &str) -> Result<RomanNumeral, E> {
+                RomanNumeral::parse(input).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+/// Uniform distribution of the roman numerals within a range of values.
+///
+///     use numerals::roman::{RomanDistribution, RomanNumeral};
+///     use rand::{rngs::SmallRng, Rng, SeedableRng};
+///
+///     let distribution = RomanDistribution::new(1..=20).unwrap();
+///     let numeral: RomanNumeral = SmallRng::seed_from_u64(0).sample(distribution);
+///     assert!((1..=20).contains(&numeral.value()));
+///
+/// `rand::distributions::Standard` samples from all the numerals.
+#[cfg(feature = "rand")]
+#[derive(Clone, Copy, Debug)]
+pub struct RomanDistribution {
+    values: rand::distributions::Uniform<u64>,
+}
+
+#[cfg(feature = "rand")]
+impl RomanDistribution {
+    /// The distribution of the numerals whose values are in `range`, clamped
+    /// as done by `RomanNumeral::range`. `None` if there are none.
+    pub fn new<R: RangeBounds<u64>>(range: R) -> Option<RomanDistribution> {
+        let range = RomanNumeral::range(range);
+        if range.len() == 0 {
+            return None;
+        }
+        Some(RomanDistribution {
+            values: rand::distributions::Uniform::new_inclusive(range.next, range.last),
+        })
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<RomanNumeral> for RomanDistribution {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> RomanNumeral {
+        RomanNumeral(self.values.sample(rng))
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<RomanNumeral> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> RomanNumeral {
+        RomanNumeral(rng.gen_range(1..=MAX))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for RomanNumeral {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<RomanNumeral> {
+        Ok(RomanNumeral(u.int_in_range(1..=MAX)?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <u64 as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for RomanNumeral {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<RomanNumeral>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        proptest::strategy::Strategy::boxed(strategy::numerals())
+    }
+}
+
+/// Proptest strategies generating roman numerals.
+///
+///     use numerals::roman::{strategy, to_arabic};
+///     use proptest::proptest;
+///
+///     proptest!(|(numeral in strategy::valid_strings())| {
+///         assert!(to_arabic(&numeral).is_ok());
+///     });
+#[cfg(feature = "proptest")]
+pub mod strategy {
+    use super::{to_roman, RomanNumeral, MAX};
+    use proptest::prelude::*;
+
+    /// Any `RomanNumeral`.
+    pub fn numerals() -> impl Strategy<Value = RomanNumeral> {
+        (1..=MAX).prop_map(RomanNumeral)
+    }
+
+    /// Strings that are valid roman numerals, written with ascii or unicode
+    /// characters, in upper or lower case.
+    pub fn valid_strings() -> impl Strategy<Value = String> {
+        (1..=MAX, any::<bool>(), any::<bool>()).prop_map(|(value, unicode, lowercase)| {
+            let numeral = to_roman(value, unicode).unwrap();
+            match lowercase {
+                true => numeral.to_lowercase(),
+                false => numeral,
+            }
+        })
+    }
+}
+
+/// The roman numeral system, for use through `NumeralSystem`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Roman;
+
+impl NumeralSystem for Roman {
+    fn name(&self) -> &'static str {
+        "roman"
+    }
+
+    fn parse(&self, input: &str) -> Result<u64, NumeralError> {
+        to_arabic(input)
+    }
+
+    fn parse_with(&self, input: &str, options: &Options) -> Result<u64, NumeralError> {
+        to_arabic_with(input, &RomanOptions::from(options))
+    }
+
+    fn format(&self, value: u64, options: &Options) -> Result<String, NumeralError> {
+        to_roman_with(value, &RomanOptions::from(options))
+    }
+
+    fn suggest(&self, input: &str) -> Option<String> {
+        suggest(input)
+    }
+}
+
+#[cfg(test)]
+mod test_roman_options {
+    use crate::roman::{to_arabic_with, to_roman_with, Notation, RomanOptions, RuleSet, Style};
+    use crate::{NumeralError, Options};
+
+    #[test]
+    fn test_to_roman_with() {
+        let x = to_roman_with(1994, &RomanOptions::new());
+        assert_eq!(x.unwrap(), "MCMXCIV");
+
+        let options = RomanOptions::new().unicode(true).compact(true);
+        let x = to_roman_with(1994, &options);
+        assert_eq!(x.unwrap(), "ⅯⅭⅯⅩⅭⅣ");
+
+        let options = RomanOptions::new().additive().lowercase(true);
+        let x = to_roman_with(1999, &options);
+        assert_eq!(x.unwrap(), "mdcccclxxxxviiii");
+
+        let options = RomanOptions::new().style(Style::Clock);
+        let x = to_roman_with(4, &options);
+        assert_eq!(x.unwrap(), "IIII");
+
+        let options = RomanOptions::new().notation(Notation::Brackets);
+        let x = to_roman_with(4221, &options);
+        assert_eq!(x.unwrap(), "(IV)CCXXI");
+
+        let options = RomanOptions::new().notation(Notation::Apostrophus);
+        let x = to_roman_with(5000, &options);
+        assert_eq!(x.unwrap(), "ↁ");
+
+        let options = RomanOptions::new().nulla(true);
+        let x = to_roman_with(0, &options);
+        assert_eq!(x.unwrap(), "N");
+
+        let x = to_roman_with(0, &RomanOptions::new());
+        assert!(x.is_err());
+    }
+
+    #[test]
+    fn test_separator() {
+        let options = RomanOptions::new().separator(Some('·'));
+        let x = to_roman_with(1999, &options);
+        assert_eq!(x.unwrap(), "M·CM·XC·IX");
+
+        let options = options.unicode(true).compact(true).lowercase(true);
+        let x = to_roman_with(1912, &options);
+        assert_eq!(x.unwrap(), "ⅿ·ⅽⅿ·ⅹ·ⅱ");
+
+        let options = RomanOptions::new().additive().separator(Some(' '));
+        let x = to_roman_with(1999, &options);
+        assert_eq!(x.unwrap(), "M DCCCC LXXXX VIIII");
+
+        let options = options.notation(Notation::Brackets);
+        let x = to_roman_with(4221, &options);
+        assert_eq!(x.unwrap(), "(IV)CCXXI");
+
+        let options = RomanOptions::new().separator(Some('·')).nulla(true);
+        let x = to_roman_with(0, &options);
+        assert_eq!(x.unwrap(), "N");
+    }
+
+    #[test]
+    fn test_to_arabic_with() {
+        let x = to_arabic_with("IIII", &RomanOptions::new());
+        assert_eq!(x.unwrap(), 4);
+
+        let x = to_arabic_with("IIII", &RomanOptions::new().strict());
+        assert!(x.is_err());
+
+        let x = to_arabic_with("MDCCCCX", &RomanOptions::new().lenient());
+        assert_eq!(x.unwrap(), 1910);
+
+        let rules = RuleSet {
+            repeated_five: false,
+            ..RuleSet::default()
+        };
+        let x = to_arabic_with("LL", &RomanOptions::new().rules(rules));
+        assert_eq!(x.unwrap(), 100);
+
+        let x = to_arabic_with("N", &RomanOptions::new());
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: 'N', index: 0 }
+        );
+    }
+
+    #[test]
+    fn test_from_options() {
+        let options = Options {
+            unicode: true,
+            clock: true,
+            nulla: true,
+            ..Options::default()
+        };
+        let x = RomanOptions::from(&options);
+        assert_eq!(x, RomanOptions::new().unicode(true).clock().nulla(true));
+    }
+}
+
+#[cfg(test)]
+mod test_to_roman {
+    use crate::roman::to_roman;
+    use crate::NumeralError;
+
+    #[test]
+    fn test_invalid_inputs() {
+        let x = to_roman(0u64, false);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::OutOfRange {
+                value: 0,
+                min: 1,
+                max: 3999
+            }
+        );
+
+        let x = to_roman(1u64, true);
+        assert!(x.is_ok());
+
+        let x = to_roman(3999u64, false);
+        assert!(x.is_ok());
+
+        let x = to_roman(4000u64, true);
+        assert!(x.is_err());
+    }
+
+    #[test]
+    fn test_valid_inputs() {
+        let x = to_roman(1999u64, false);
+        assert_eq!(x.unwrap(), "MCMXCIX".to_string());
+
+        let x = to_roman(99u64, false);
+        assert_eq!(x.unwrap(), "XCIX".to_string());
+
+        let x = to_roman(1999, true);
+        assert_eq!(x.unwrap(), "ⅯⅭⅯⅩⅭⅠⅩ");
+    }
+}
+
+#[cfg(test)]
+mod test_to_roman_separated {
+    use crate::roman::{to_roman, to_roman_separated};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_to_roman_separated() {
+        let x = to_roman_separated(1999, false, '·');
+        assert_eq!(x.unwrap(), "M·CM·XC·IX");
+
+        let x = to_roman_separated(3888, true, ' ');
+        assert_eq!(x.unwrap(), "ⅯⅯⅯ ⅮⅭⅭⅭ ⅬⅩⅩⅩ ⅤⅠⅠⅠ");
+
+        let x = to_roman_separated(1004, false, '.');
+        assert_eq!(x.unwrap(), "M.IV");
+
+        let x = to_roman_separated(7, false, '.');
+        assert_eq!(x.unwrap(), "VII");
+    }
+
+    #[test]
+    fn test_same_numerals() {
+        for value in 1..=3999 {
+            let x = to_roman_separated(value, false, '·').unwrap();
+            assert_eq!(x.replace('·', ""), to_roman(value, false).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let x = to_roman_separated(0, false, '·');
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::OutOfRange {
+                value: 0,
+                min: 1,
+                max: 3999
+            }
+        );
+        assert!(to_roman_separated(4000, false, '·').is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_to_roman_styled {
+    use crate::roman::{
+        to_arabic, to_arabic_lenient, to_roman, to_roman_compact, to_roman_styled, Style,
+    };
+
+    #[test]
+    fn test_clock() {
+        let x = to_roman_styled(4, false, Style::Clock);
+        assert_eq!(x.unwrap(), "IIII");
+
+        let x = to_roman_styled(14, true, Style::Clock);
+        assert_eq!(x.unwrap(), "ⅩⅠⅠⅠⅠ");
+
+        for value in [1, 9, 12, 40, 3999].iter() {
+            let x = to_roman_styled(*value, false, Style::Clock);
+            assert_eq!(x, to_roman(*value, false));
+        }
+
+        let x = to_roman_styled(0, false, Style::Clock);
+        assert!(x.is_err());
+    }
+
+    #[test]
+    fn test_additive() {
+        let x = to_roman_styled(1999, false, Style::Additive);
+        assert_eq!(x.unwrap(), "MDCCCCLXXXXVIIII");
+
+        let x = to_roman_styled(44, true, Style::Additive);
+        assert_eq!(x.unwrap(), "ⅩⅩⅩⅩⅠⅠⅠⅠ");
+
+        for value in 1..=3999 {
+            let numeral = to_roman_styled(value, false, Style::Additive).unwrap();
+            let x = to_arabic_lenient(&numeral);
+            assert_eq!(x.unwrap().value, value);
+        }
+    }
+
+    #[test]
+    fn test_compact() {
+        let expected = [
+            "Ⅰ", "Ⅱ", "Ⅲ", "Ⅳ", "Ⅴ", "Ⅵ", "Ⅶ", "Ⅷ", "Ⅸ", "Ⅹ", "Ⅺ", "Ⅻ", "ⅩⅢ",
+        ];
+        for (value, numeral) in (1..).zip(expected.iter()) {
+            let x = to_roman_compact(value, Style::Subtractive);
+            assert_eq!(x.unwrap(), *numeral);
+        }
+
+        let x = to_roman_compact(112, Style::Subtractive);
+        assert_eq!(x.unwrap(), "ⅭⅫ");
+
+        let x = to_roman_compact(1990, Style::Subtractive);
+        assert_eq!(x.unwrap(), "ⅯⅭⅯⅩⅭ");
+
+        let x = to_roman_compact(19, Style::Clock);
+        assert_eq!(x.unwrap(), "ⅩⅨ");
+
+        let x = to_roman_compact(9, Style::Additive);
+        assert_eq!(x.unwrap(), "ⅤⅠⅠⅠⅠ");
+
+        let x = to_roman_compact(0, Style::Subtractive);
+        assert!(x.is_err());
+
+        for value in 1..=3999 {
+            let numeral = to_roman_compact(value, Style::Subtractive).unwrap();
+            assert_eq!(to_arabic(&numeral), Ok(value), "{}", numeral);
+        }
+    }
+
+    #[test]
+    fn test_subtractive() {
+        for value in 1..=3999 {
+            let x = to_roman_styled(value, false, Style::Subtractive);
+            assert_eq!(x, to_roman(value, false));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_signed {
+    use crate::roman::{to_arabic_signed, to_roman_signed};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_to_roman_signed() {
+        let x = to_roman_signed(-1994, false);
+        assert_eq!(x.unwrap(), "-MCMXCIV");
+
+        let x = to_roman_signed(-4, true);
+        assert_eq!(x.unwrap(), "-ⅠⅤ");
+
+        let x = to_roman_signed(0, false);
+        assert!(x.is_err());
+
+        let x = to_roman_signed(i64::MIN, false);
+        assert!(x.is_err());
+    }
+
+    #[test]
+    fn test_to_arabic_signed() {
+        let x = to_arabic_signed("-MCMXCIV");
+        assert_eq!(x.unwrap(), -1994);
+
+        let x = to_arabic_signed("−ⅠⅤ");
+        assert_eq!(x.unwrap(), -4);
+
+        let x = to_arabic_signed("-");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = to_arabic_signed("--X");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: '-', index: 1 }
+        );
+
+        let x = to_arabic_signed("-IIX");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'I', index: 1 }
+        );
+
+        let x = to_arabic_signed("M\u{305}\u{305}\u{305}\u{305}\u{305}\u{305}");
+        assert_eq!(x.unwrap_err(), NumeralError::Overflow);
+    }
+}
+
+#[cfg(test)]
+mod test_zero {
+    use crate::roman::{to_arabic, to_arabic_with_zero, to_roman, to_roman_with_zero};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_to_roman_with_zero() {
+        let x = to_roman_with_zero(0, false);
+        assert_eq!(x.unwrap(), "N");
+
+        let x = to_roman_with_zero(0, true);
+        assert_eq!(x.unwrap(), "N");
+
+        let x = to_roman_with_zero(1994, false);
+        assert_eq!(x, to_roman(1994, false));
+
+        let x = to_roman_with_zero(4000, false);
+        assert!(x.is_err());
+    }
+
+    #[test]
+    fn test_to_arabic_with_zero() {
+        let x = to_arabic_with_zero("N");
+        assert_eq!(x.unwrap(), 0);
+
+        let x = to_arabic_with_zero("n");
+        assert_eq!(x.unwrap(), 0);
+
+        let x = to_arabic_with_zero("XN");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: 'N', index: 1 }
+        );
+
+        let x = to_arabic("N");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: 'N', index: 0 }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_to_vinculum {
+    use crate::roman::to_vinculum;
+    use crate::NumeralError;
+
+    #[test]
+    fn test_valid_inputs() {
+        assert_eq!(to_vinculum(3999).unwrap(), "MMMCMXCIX");
+        assert_eq!(to_vinculum(4000).unwrap(), "I\u{305}V\u{305}");
+        assert_eq!(to_vinculum(5007).unwrap(), "V\u{305}VII");
+        assert_eq!(
+            to_vinculum(3_999_999).unwrap(),
+            "M\u{305}M\u{305}M\u{305}C\u{305}M\u{305}X\u{305}C\u{305}I\u{305}X\u{305}CMXCIX"
+        );
+        assert_eq!(
+            to_vinculum(4_000_001).unwrap(),
+            "I\u{305}\u{305}V\u{305}\u{305}I"
+        );
+        assert!(to_vinculum(u64::MAX).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        assert_eq!(
+            to_vinculum(0).unwrap_err(),
+            NumeralError::OutOfRange {
+                value: 0,
+                min: 1,
+                max: u64::MAX
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_to_brackets {
+    use crate::roman::to_brackets;
+    use crate::NumeralError;
+
+    #[test]
+    fn test_valid_inputs() {
+        assert_eq!(to_brackets(3999).unwrap(), "MMMCMXCIX");
+        assert_eq!(to_brackets(4000).unwrap(), "(IV)");
+        assert_eq!(to_brackets(1_000_000).unwrap(), "(M)");
+        assert_eq!(to_brackets(4_000_001).unwrap(), "((IV))I");
+        assert_eq!(to_brackets(12_345_678).unwrap(), "((XII)CCCXLV)DCLXXVIII");
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        assert!(to_brackets(0).is_err());
+        assert_eq!(
+            to_brackets(0).unwrap_err(),
+            NumeralError::OutOfRange {
+                value: 0,
+                min: 1,
+                max: u64::MAX
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_to_apostrophus {
+    use crate::roman::{to_apostrophus, to_arabic};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_valid_inputs() {
+        assert_eq!(to_apostrophus(4).unwrap(), "ⅠⅤ");
+        assert_eq!(to_apostrophus(1000).unwrap(), "ↀ");
+        assert_eq!(to_apostrophus(4000).unwrap(), "ↀↁ");
+        assert_eq!(to_apostrophus(19_000).unwrap(), "ↂↀↂ");
+        assert_eq!(to_apostrophus(399_999).unwrap(), "ↈↈↈↂↈↀↂⅭↀⅩⅭⅠⅩ");
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        assert!(to_apostrophus(0).is_err());
+        assert_eq!(
+            to_apostrophus(400_000).unwrap_err(),
+            NumeralError::OutOfRange {
+                value: 400_000,
+                min: 1,
+                max: 399_999
+            }
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for value in (1..=399_999).step_by(7) {
+            let numeral = to_apostrophus(value).unwrap();
+            assert_eq!(to_arabic(&numeral), Ok(value), "{}", numeral);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_generic_integers {
+    use crate::roman::{to_arabic_as, to_roman_from};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_to_roman_from() {
+        assert_eq!(to_roman_from(14u8, false).unwrap(), "XIV");
+        assert_eq!(to_roman_from(1994i16, false).unwrap(), "MCMXCIV");
+        assert_eq!(to_roman_from(3999usize, true).unwrap(), "ⅯⅯⅯⅭⅯⅩⅭⅠⅩ");
+        assert_eq!(to_roman_from(12u128, false).unwrap(), "XII");
+
+        let x = to_roman_from(-14i32, false);
+        assert_eq!(x.unwrap_err(), NumeralError::Negative);
+
+        let x = to_roman_from(i128::MIN, false);
+        assert_eq!(x.unwrap_err(), NumeralError::Negative);
+
+        let x = to_roman_from(u128::MAX, false);
+        assert_eq!(x.unwrap_err(), NumeralError::Overflow);
+
+        let x = to_roman_from(u64::MAX, false);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::OutOfRange {
+                value: u64::MAX,
+                min: 1,
+                max: 3999
+            }
+        );
+    }
+
+    #[test]
+    fn test_to_arabic_as() {
+        assert_eq!(to_arabic_as::<u8, _>("XIV").unwrap(), 14u8);
+        assert_eq!(to_arabic_as::<i32, _>("MCMXCIV").unwrap(), 1994i32);
+        assert_eq!(to_arabic_as::<u128, _>("ↈ").unwrap(), 100000u128);
+
+        let x = to_arabic_as::<u8, _>("CCLVI");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::OutOfRange {
+                value: 256,
+                min: 0,
+                max: 255
+            }
+        );
+
+        let x = to_arabic_as::<i8, _>("IXC");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'I', index: 0 }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_to_roman_into {
+    use crate::roman::to_roman_into;
+    use crate::NumeralError;
+    use core::fmt;
+
+    #[test]
+    fn test_reused_buffer() {
+        let mut buffer = String::new();
+        to_roman_into(1994, false, &mut buffer).unwrap();
+        buffer.push(' ');
+        to_roman_into(12, true, &mut buffer).unwrap();
+        assert_eq!(buffer, "MCMXCIV ⅩⅠⅠ");
+
+        let x = to_roman_into(4000, false, &mut buffer);
+        assert!(x.is_err());
+        assert_eq!(buffer, "MCMXCIV ⅩⅠⅠ");
+    }
+
+    #[test]
+    fn test_failing_writer() {
+        struct Full;
+
+        impl fmt::Write for Full {
+            fn write_str(&mut self, _: &str) -> fmt::Result {
+                Err(fmt::Error)
+            }
+        }
+
+        let x = to_roman_into(14, false, &mut Full);
+        assert_eq!(x.unwrap_err(), NumeralError::Write);
+    }
+}
+
+#[cfg(test)]
+mod test_to_arabic {
+    use crate::roman::{evaluate, to_arabic, to_brackets, to_vinculum, RuleSet};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_string_cases() {
+        let x = to_arabic("iv");
+        assert!(x.is_ok());
+        assert_eq!(x.unwrap(), 4);
+
+        let x = to_arabic("LIX");
+        assert!(x.is_ok());
+        assert_eq!(x.unwrap(), 59);
+
+        let x = to_arabic("CvL");
+        assert!(x.is_ok());
+        assert_eq!(x.unwrap(), 145);
+    }
+    #[test]
+    fn test_unicode_cases() {
+        let x = to_arabic("ⅳ");
+        assert!(x.is_ok());
+        assert_eq!(x.unwrap(), 4);
+
+        let x = to_arabic("ⅬⅨ");
+        assert!(x.is_ok());
+        assert_eq!(x.unwrap(), 59);
+
+        let x = to_arabic("ⅭⅴⅬ");
+        assert!(x.is_ok());
+        assert_eq!(x.unwrap(), 145);
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let x = to_arabic(String::new());
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+    }
+
+    #[test]
+    fn test_invalid_characters() {
+        let x = to_arabic("LXS");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: 'S', index: 2 }
+        );
+
+        let x = to_arabic("mcmxciq");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: 'q', index: 6 }
+        );
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let x = to_arabic("XIL");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'X', index: 0 }
+        );
+
+        let x = to_arabic("VIL");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'V', index: 0 }
+        );
+
+        let x = to_arabic("IXC");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'I', index: 0 }
+        );
+
+        let x = to_arabic("XXC");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'X', index: 0 }
+        );
+
+        let x = to_arabic("LC");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'L', index: 0 }
+        );
+
+        let x = to_arabic("LDVX");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'V', index: 2 }
+        );
+    }
+
+    #[test]
+    fn test_valid_inputs() {
+        let x = to_arabic("XCIX");
+        assert_eq!(x.unwrap(), 99);
+
+        let x = to_arabic("MCMLXXXIV");
+        assert_eq!(x.unwrap(), 1984);
+
+        let x = to_arabic("MMMCMXCIX");
+        assert_eq!(x.unwrap(), 3999);
+
+        let x = to_arabic("LXXX");
+        assert_eq!(x.unwrap(), 80);
+    }
+
+    #[test]
+    fn test_four_same_symbols() {
+        let x = to_arabic("IIII");
+        assert_eq!(x.unwrap(), 4);
+
+        let x = to_arabic("XXXX");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'X', index: 0 }
+        );
+
+        let x = to_arabic("VIIII");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'I', index: 1 }
+        );
+    }
+
+    #[test]
+    fn test_double_symbols() {
+        let x = to_arabic("MM");
+        assert!(x.is_ok());
+        assert_eq!(x.unwrap(), 2000);
+
+        let x = to_arabic("CC");
+        assert!(x.is_ok());
+        assert_eq!(x.unwrap(), 200);
+
+        let x = to_arabic("XX");
+        assert!(x.is_ok());
+        assert_eq!(x.unwrap(), 20);
+
+        let x = to_arabic("II");
+        assert!(x.is_ok());
+        assert_eq!(x.unwrap(), 2);
+
+        let x = to_arabic("LL");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'L', index: 0 }
+        );
+
+        let x = to_arabic("DD");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'D', index: 0 }
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        // Such values cannot be reached with the existing numerals
+        let half = u64::MAX / 2 + 1;
+        let rules = RuleSet::default();
+        let x = evaluate(&[(0, 'ↈ', half), (1, 'ↈ', half)], &rules);
+        assert_eq!(x.unwrap_err(), NumeralError::Overflow);
+
+        let x = evaluate(&[(0, 'ↈ', half), (1, 'ↈ', half - 1)], &rules);
+        assert_eq!(x.unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn test_borrowed_input() {
+        let text = "Chapter XIV, verse ⅲ";
+        let x = to_arabic(&text[8..11]);
+        assert_eq!(x.unwrap(), 14);
+
+        let x = to_arabic(text.rsplit(' ').next().unwrap());
+        assert_eq!(x.unwrap(), 3);
+
+        let x = to_arabic("iiii");
+        assert_eq!(x.unwrap(), 4);
+
+        let x = to_arabic("ⅰⅠⅰⅠ");
+        assert_eq!(x.unwrap(), 4);
+    }
+
+    #[test]
+    fn test_error_positions() {
+        let x = to_arabic("ⅯⅭⅯⅩⅭⅠⅠⅩ");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: 'Ⅰ', index: 5
+            }
+        );
+
+        let x = to_arabic("mcmxciix");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'i', index: 5 }
+        );
+    }
+
+    #[test]
+    fn test_vinculum() {
+        let x = to_arabic("V\u{305}II");
+        assert_eq!(x.unwrap(), 5002);
+
+        let x = to_arabic("V\u{305}VII");
+        assert_eq!(x.unwrap(), 5007);
+
+        let x = to_arabic("I\u{305}V\u{305}CCXXI");
+        assert_eq!(x.unwrap(), 4221);
+
+        let x = to_arabic("M\u{305}\u{305}X\u{305}I");
+        assert_eq!(x.unwrap(), 1_000_010_001);
+
+        let x = to_arabic("ⅿ\u{305}");
+        assert_eq!(x.unwrap(), 1_000_000);
+
+        for value in [1, 3999, 4000, 12_345, 3_999_999, 4_000_001, u64::MAX].iter() {
+            let x = to_arabic(to_vinculum(*value).unwrap());
+            assert_eq!(x.unwrap(), *value);
+        }
+    }
+
+    #[test]
+    fn test_invalid_vinculum() {
+        let x = to_arabic("\u{305}V");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter {
+                ch: '\u{305}',
+                index: 0
+            }
+        );
+
+        let x = to_arabic("XV\u{305}");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'V', index: 1 }
+        );
+
+        let x = to_arabic("D\u{305}D\u{305}I");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'D', index: 0 }
+        );
+
+        let x = to_arabic("M\u{305}\u{305}\u{305}\u{305}\u{305}\u{305}\u{305}");
+        assert_eq!(x.unwrap_err(), NumeralError::Overflow);
+    }
+
+    #[test]
+    fn test_brackets() {
+        let x = to_arabic("(IV)CCXXI");
+        assert_eq!(x.unwrap(), 4221);
+
+        let x = to_arabic("|IV|CCXXI");
+        assert_eq!(x.unwrap(), 4221);
+
+        let x = to_arabic("((V))(X)");
+        assert_eq!(x.unwrap(), 5_010_000);
+
+        let x = to_arabic("|(V)|");
+        assert_eq!(x.unwrap(), 5_000_000);
+
+        for value in [1, 3999, 4000, 12_345, 3_999_999, u64::MAX].iter() {
+            let x = to_arabic(to_brackets(*value).unwrap());
+            assert_eq!(x.unwrap(), *value);
+        }
+    }
+
+    #[test]
+    fn test_invalid_brackets() {
+        let x = to_arabic("(IV");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: '(', index: 0 }
+        );
+
+        let x = to_arabic("IV)");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: ')', index: 2 }
+        );
+
+        let x = to_arabic("X(V)");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'V', index: 2 }
+        );
+    }
+
+    #[test]
+    fn test_letter_apostrophus() {
+        let x = to_arabic("CIƆ");
+        assert_eq!(x.unwrap(), 1000);
+
+        let x = to_arabic("IƆ");
+        assert_eq!(x.unwrap(), 500);
+
+        let x = to_arabic("CCIƆƆ");
+        assert_eq!(x.unwrap(), 10000);
+
+        let x = to_arabic("IƆƆ");
+        assert_eq!(x.unwrap(), 5000);
+
+        let x = to_arabic("CCCIƆƆƆIƆƆƆ");
+        assert_eq!(x.unwrap(), 150_000);
+
+        let x = to_arabic("CIƆIƆCXXIV");
+        assert_eq!(x.unwrap(), 1624);
+
+        let x = to_arabic("CCIƆXCIX");
+        assert_eq!(x.unwrap(), 999);
+
+        let x = to_arabic("ciↄdcxxiv");
+        assert_eq!(x.unwrap(), 1624);
+    }
+
+    #[test]
+    fn test_invalid_letter_apostrophus() {
+        let x = to_arabic("CIƆƆ");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'C', index: 0 }
+        );
+
+        let x = to_arabic("XƆ");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: 'Ɔ', index: 1 }
+        );
+
+        let x = to_arabic("IƆCIƆ");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'I', index: 0 }
+        );
+    }
+
+    #[test]
+    fn test_precomposed() {
+        let x = to_arabic("ⅩⅪ");
+        assert_eq!(x.unwrap(), 21);
+
+        let x = to_arabic("ⅯⅯⅩⅫ");
+        assert_eq!(x.unwrap(), 2022);
+
+        let x = to_arabic("ⅩⅭⅧ");
+        assert_eq!(x.unwrap(), 98);
+
+        let x = to_arabic("Ⅽↅ");
+        assert_eq!(x.unwrap(), 106);
+
+        let x = to_arabic("ⅫⅩ");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: 'Ⅻ', index: 0
+            }
+        );
+
+        let x = to_arabic("ⅢⅠ");
+        assert!(x.is_err());
+
+        let x = to_arabic("(Ⅻ)Ⅸ");
+        assert_eq!(x.unwrap(), 12_009);
+    }
+
+    #[test]
+    fn test_lowercase_unicode() {
+        let lowercase = "ⅰⅱⅲⅳⅴⅵⅶⅷⅸⅹⅺⅻⅼⅽⅾⅿ";
+        let values = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 50, 100, 500, 1000];
+        for (ch, value) in lowercase.chars().zip(values.iter()) {
+            let x = to_arabic(ch.to_string());
+            assert_eq!(x.unwrap(), *value, "{}", ch);
+        }
+
+        let x = to_arabic("ⅿⅿⅹⅺ");
+        assert!(x.is_ok());
+    }
+
+    #[test]
+    fn test_apostrohpus() {
+        let x = to_arabic("ↀ");
+        assert!(x.is_ok());
+        assert_eq!(x.unwrap(), 1000);
+
+        let x = to_arabic("ↀXↀIX");
+        assert!(x.is_ok());
+        assert_eq!(x.unwrap(), 1999);
+
+        let x = to_arabic("ↈIXC");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'I', index: 1 }
+        );
+
+        let x = to_arabic("ↈⅠV");
+        assert_eq!(x.unwrap(), 100004);
+    }
+}
+
+#[cfg(test)]
+mod test_validate {
+    use crate::roman::{validate, Rule, Violation};
+    use crate::NumeralError;
+
+    fn rules(input: &str) -> Vec<(Rule, usize)> {
+        validate(input)
+            .unwrap_err()
+            .iter()
+            .map(|violation| (violation.rule, violation.index))
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_inputs() {
+        assert_eq!(validate("MCMXCIV"), Ok(()));
+        assert_eq!(validate("iiii"), Ok(()));
+        assert_eq!(validate("ⅠⅠⅠⅠ"), Ok(()));
+        assert_eq!(validate("ↀXↀIX"), Ok(()));
+    }
+
+    #[test]
+    fn test_repeated_numerals() {
+        assert_eq!(rules("VV"), vec![(Rule::RepeatedFive, 0)]);
+        assert_eq!(
+            rules("VVV"),
+            vec![(Rule::RepeatedFive, 1), (Rule::RepeatedFive, 0)]
+        );
+        assert_eq!(rules("IVI"), vec![(Rule::AddedBack, 0)]);
+        assert_eq!(rules("XCX"), vec![(Rule::AddedBack, 0)]);
+        assert_eq!(rules("LXL"), vec![(Rule::AddedBack, 0)]);
+    }
+
+    #[test]
+    fn test_letter_apostrophus() {
+        assert_eq!(validate("CIƆ"), Ok(()));
+        assert_eq!(validate("IƆƆ"), Ok(()));
+        assert_eq!(validate("CCIƆƆ"), Ok(()));
+        assert_eq!(validate("CIƆIƆCXXIV"), Ok(()));
+
+        assert_eq!(rules("CIƆƆ"), vec![(Rule::Apostrophus, 0)]);
+        assert_eq!(rules("XƆ"), vec![(Rule::InvalidCharacter, 1)]);
+        assert_eq!(rules("CIƆCIƆCIƆCIƆ"), vec![(Rule::FourInARow, 0)]);
+    }
+
+    #[test]
+    fn test_all_violations() {
+        assert_eq!(rules("IXC"), vec![(Rule::DoubleSubtraction, 0)]);
+        assert_eq!(
+            rules("LLXXXXX"),
+            vec![
+                (Rule::FourInARow, 3),
+                (Rule::FourInARow, 2),
+                (Rule::RepeatedFive, 0)
+            ]
+        );
+        assert_eq!(
+            rules("VXIIX"),
+            vec![
+                (Rule::DoubleSubtraction, 2),
+                (Rule::RedundantSubtraction, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_characters() {
+        assert_eq!(rules("MCMXCIQ"), vec![(Rule::InvalidCharacter, 6)]);
+        assert_eq!(
+            rules("Q XX"),
+            vec![(Rule::InvalidCharacter, 0), (Rule::InvalidCharacter, 1)]
+        );
+        assert_eq!(
+            validate("").unwrap_err(),
+            vec![Violation {
+                rule: Rule::Empty,
+                index: 0,
+                ch: None
+            }]
+        );
+    }
+
+    #[test]
+    fn test_same_as_to_arabic() {
+        for input in &["", "LXS", "XIL", "LDVX", "XXXX", "VIIII", "DD", "ⅯⅭⅯⅩⅭⅠⅠⅩ"]
+        {
+            let first = validate(input).unwrap_err()[0];
+            assert_eq!(
+                NumeralError::from(first),
+                crate::roman::to_arabic(input).unwrap_err()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_explain {
+    use crate::roman::{explain, explain_value, Term};
+    use crate::NumeralError;
+
+    fn terms(terms: Vec<Term>) -> Vec<(String, u64)> {
+        terms
+            .into_iter()
+            .map(|term| (term.numeral, term.value))
+            .collect()
+    }
+
+    fn expected(terms: &[(&str, u64)]) -> Vec<(String, u64)> {
+        terms
+            .iter()
+            .map(|&(numeral, value)| (numeral.into(), value))
+            .collect()
+    }
+
+    #[test]
+    fn test_explain() {
+        let x = explain("MCMXCIX");
+        assert_eq!(
+            terms(x.unwrap()),
+            expected(&[("M", 1000), ("CM", 900), ("XC", 90), ("IX", 9)])
+        );
+
+        let x = explain("xiv");
+        assert_eq!(terms(x.unwrap()), expected(&[("x", 10), ("iv", 4)]));
+
+        let x = explain("IIII");
+        assert_eq!(
+            terms(x.unwrap()),
+            expected(&[("I", 1), ("I", 1), ("I", 1), ("I", 1)])
+        );
+    }
+
+    #[test]
+    fn test_explain_unusual_numerals() {
+        let x = explain("ⅯⅯⅫ");
+        assert_eq!(
+            terms(x.unwrap()),
+            expected(&[("Ⅿ", 1000), ("Ⅿ", 1000), ("Ⅻ", 12)])
+        );
+
+        let x = explain("CIƆXL");
+        assert_eq!(terms(x.unwrap()), expected(&[("CIƆ", 1000), ("XL", 40)]));
+    }
+
+    #[test]
+    fn test_explain_invalid_numerals() {
+        let x = explain("IXC");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'I', index: 0 }
+        );
+
+        let x = explain("");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+    }
+
+    #[test]
+    fn test_explain_value() {
+        let x = explain_value(1994, false);
+        assert_eq!(
+            terms(x.unwrap()),
+            expected(&[("M", 1000), ("CM", 900), ("XC", 90), ("IV", 4)])
+        );
+
+        let x = explain_value(3, true);
+        assert_eq!(terms(x.unwrap()), expected(&[("Ⅰ", 1), ("Ⅰ", 1), ("Ⅰ", 1)]));
+
+        let x = explain_value(0, false);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::OutOfRange {
+                value: 0,
+                min: 1,
+                max: 3999
+            }
+        );
+    }
+
+    #[test]
+    fn test_explain_roundtrip() {
+        for value in 1..=3999 {
+            let numeral = crate::roman::to_roman(value, false).unwrap();
+            let explained = explain(&numeral).unwrap();
+            assert_eq!(explained, explain_value(value, false).unwrap());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_parse_prefix {
+    use crate::roman::parse_prefix;
+    use crate::NumeralError;
+
+    #[test]
+    fn test_prefixes() {
+        assert_eq!(parse_prefix("MCMXCIV").unwrap(), (1994, ""));
+        assert_eq!(
+            parse_prefix("XIV. The Sorcerer").unwrap(),
+            (14, ". The Sorcerer")
+        );
+        assert_eq!(parse_prefix("ⅩⅣ, 3").unwrap(), (14, ", 3"));
+        assert_eq!(parse_prefix("iv-b").unwrap(), (4, "-b"));
+        assert_eq!(parse_prefix("Chapter").unwrap(), (100, "hapter"));
+        assert_eq!(parse_prefix("IIIIV").unwrap(), (4, "V"));
+    }
+
+    #[test]
+    fn test_longest_valid_prefix() {
+        assert_eq!(parse_prefix("XIIX").unwrap(), (12, "X"));
+        assert_eq!(parse_prefix("XXXXI").unwrap(), (30, "XI"));
+        assert_eq!(parse_prefix("LLL").unwrap(), (50, "LL"));
+    }
+
+    #[test]
+    fn test_no_prefix() {
+        assert_eq!(parse_prefix("").unwrap_err(), NumeralError::Empty);
+        assert_eq!(
+            parse_prefix("§ XIV").unwrap_err(),
+            NumeralError::InvalidCharacter { ch: '§', index: 0 }
+        );
+        assert_eq!(
+            parse_prefix(" XIV").unwrap_err(),
+            NumeralError::InvalidCharacter { ch: ' ', index: 0 }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_find_all {
+    use crate::roman::{find_all, Match};
+
+    #[test]
+    fn test_matches() {
+        let text = "Book II, Chapter XIV: the Ⅺ labours (see ⅩⅣ, MCMXCIV-MMI).";
+        let matches: Vec<Match> = find_all(text).collect();
+        let values: Vec<u64> = matches.iter().map(|m| m.value).collect();
+        assert_eq!(values, vec![2, 14, 11, 14, 1994, 2001]);
+
+        assert_eq!(matches[1].text, "XIV");
+        assert_eq!(matches[1].range, 17..20);
+        assert_eq!(&text[matches[3].range.clone()], "ⅩⅣ");
+    }
+
+    #[test]
+    fn test_words_left_out() {
+        // Upper case words that are valid numerals are still found, unlike
+        // DID, where D is written again around a subtraction
+        let text = "DID the civil MIX of XIXth and IXC LIVE? mix I";
+        let values: Vec<u64> = find_all(text).map(|m| m.value).collect();
+        assert_eq!(values, vec![1009, 1]);
+
+        assert_eq!(find_all("").count(), 0);
+        assert_eq!(find_all("no numerals here").count(), 0);
+    }
+}
+
+#[cfg(test)]
+mod test_to_arabic_lenient {
+    use crate::roman::{to_arabic_lenient, LenientValue};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_non_canonical() {
+        for &(input, value) in [("VIIII", 9), ("XXXX", 40), ("IM", 999), ("MDCCCCX", 1910)].iter() {
+            let x = to_arabic_lenient(input);
+            assert_eq!(
+                x.unwrap(),
+                LenientValue {
+                    value,
+                    canonical: false
+                }
+            );
+        }
+
+        let x = to_arabic_lenient("ⅭⅭⅭⅭ");
+        assert_eq!(x.unwrap().value, 400);
+    }
+
+    #[test]
+    fn test_canonical() {
+        let x = to_arabic_lenient("XLII");
+        assert_eq!(
+            x.unwrap(),
+            LenientValue {
+                value: 42,
+                canonical: true
+            }
+        );
+
+        let x = to_arabic_lenient("ⅹⅼⅰⅰ");
+        assert!(x.unwrap().canonical);
+
+        let x = to_arabic_lenient("V\u{305}");
+        assert_eq!(
+            x.unwrap(),
+            LenientValue {
+                value: 5000,
+                canonical: true
+            }
+        );
+
+        let x = to_arabic_lenient("IIII");
+        assert_eq!(
+            x.unwrap(),
+            LenientValue {
+                value: 4,
+                canonical: false
+            }
+        );
+
+        let x = to_arabic_lenient("(I)");
+        assert_eq!(
+            x.unwrap(),
+            LenientValue {
+                value: 1000,
+                canonical: false
+            }
+        );
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let x = to_arabic_lenient("");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = to_arabic_lenient("XXXXA");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: 'A', index: 4 }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_canonicalize {
+    use crate::roman::canonicalize;
+    use crate::NumeralError;
+
+    #[test]
+    fn test_valid_inputs() {
+        let x = canonicalize("IIII");
+        assert_eq!(x.unwrap(), "IV");
+
+        let x = canonicalize("LXXXX");
+        assert_eq!(x.unwrap(), "XC");
+
+        let x = canonicalize("XIV");
+        assert_eq!(x.unwrap(), "XIV");
+
+        let x = canonicalize("ⅯⅭⅭⅭⅭ");
+        assert_eq!(x.unwrap(), "ⅯⅭⅮ");
+
+        let x = canonicalize("CIƆIƆCCCCX");
+        assert_eq!(x.unwrap(), "MCMX");
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let x = canonicalize("");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = canonicalize("XIV!");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: '!', index: 3 }
+        );
+
+        let x = canonicalize("MMMM");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::OutOfRange {
+                value: 4000,
+                min: 1,
+                max: 3999
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_to_arabic_historical {
+    use crate::roman::{to_arabic_historical, Variant};
+    use crate::NumeralError;
+
+    fn parse(input: &str) -> (u64, Vec<Variant>) {
+        let parsed = to_arabic_historical(input).unwrap();
+        (parsed.value, parsed.variants)
+    }
+
+    #[test]
+    fn test_valid_numerals() {
+        assert_eq!(parse("XIV"), (14, vec![]));
+        assert_eq!(parse("ⅯⅭⅯⅩⅭⅠⅩ"), (1999, vec![]));
+    }
+
+    #[test]
+    fn test_variants() {
+        assert_eq!(parse("IIXX"), (18, vec![Variant::RepeatedSubtraction]));
+        assert_eq!(parse("XIIX"), (18, vec![Variant::RepeatedSubtraction]));
+        assert_eq!(parse("IIX"), (8, vec![Variant::RepeatedSubtraction]));
+        assert_eq!(parse("IC"), (99, vec![Variant::WideSubtraction]));
+        assert_eq!(parse("MIM"), (1999, vec![Variant::WideSubtraction]));
+        assert_eq!(parse("VIIII"), (9, vec![Variant::Additive]));
+        assert_eq!(parse("IIII"), (4, vec![Variant::Additive]));
+        assert_eq!(parse("VL"), (45, vec![Variant::SubtractedFive]));
+        assert_eq!(parse("MLD"), (1450, vec![Variant::SubtractedFive]));
+        assert_eq!(parse("MDCCCCLXXXXVIIII"), (1999, vec![Variant::Additive]));
+        assert_eq!(
+            parse("IIC"),
+            (
+                98,
+                vec![Variant::WideSubtraction, Variant::RepeatedSubtraction]
+            )
+        );
+    }
+
+    #[test]
+    fn test_archaic_forms() {
+        assert_eq!(parse("ↅ"), (6, vec![Variant::ArchaicForm]));
+        assert_eq!(parse("ↆX"), (60, vec![Variant::ArchaicForm]));
+        assert_eq!(
+            parse("ↆIIX"),
+            (58, vec![Variant::ArchaicForm, Variant::RepeatedSubtraction])
+        );
+    }
+
+    #[test]
+    fn test_unattested_numerals() {
+        let x = to_arabic_historical("IXC");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'I', index: 0 }
+        );
+
+        let x = to_arabic_historical("LLIIX");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'L', index: 0 }
+        );
+
+        let x = to_arabic_historical("VX");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'V', index: 0 }
+        );
+
+        let x = to_arabic_historical("VVX");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'V', index: 1 }
+        );
+
+        let x = to_arabic_historical("IIIIIV");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'I', index: 2 }
+        );
+
+        let x = to_arabic_historical("XIQ");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: 'Q', index: 2 }
+        );
+
+        let x = to_arabic_historical("");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+    }
+}
+
+#[cfg(test)]
+mod test_twelfths {
+    use crate::roman::{to_arabic_twelfths, to_roman_twelfths};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_to_roman_twelfths() {
+        let x = to_roman_twelfths(30, false);
+        assert_eq!(x.unwrap(), "IIS");
+
+        let x = to_roman_twelfths(1, false);
+        assert_eq!(x.unwrap(), "•");
+
+        let x = to_roman_twelfths(12 * 14 + 7, true);
+        assert_eq!(x.unwrap(), "ⅩⅠⅤS•");
+
+        let x = to_roman_twelfths(0, false);
+        assert!(x.is_err());
+
+        let x = to_roman_twelfths(4000 * 12, false);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::OutOfRange {
+                value: 48000,
+                min: 1,
+                max: 47999
+            }
+        );
+    }
+
+    #[test]
+    fn test_to_arabic_twelfths() {
+        let x = to_arabic_twelfths("IIS");
+        assert_eq!(x.unwrap(), 30);
+
+        let x = to_arabic_twelfths("s");
+        assert_eq!(x.unwrap(), 6);
+
+        let x = to_arabic_twelfths("•••••");
+        assert_eq!(x.unwrap(), 5);
+
+        for twelfths in 1..=600 {
+            let numeral = to_roman_twelfths(twelfths, false).unwrap();
+            assert_eq!(to_arabic_twelfths(&numeral), Ok(twelfths), "{}", numeral);
+        }
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let x = to_arabic_twelfths("");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = to_arabic_twelfths("ISS");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'S', index: 2 }
+        );
+
+        let x = to_arabic_twelfths("I•S");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'S', index: 2 }
+        );
+
+        let x = to_arabic_twelfths("••••••");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: '•', index: 5
+            }
+        );
+
+        let x = to_arabic_twelfths("IIXS");
+        assert!(x.is_err());
+
+        let x = to_arabic_twelfths("IS•I");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: 'I', index: 3 }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_to_arabic_with_separators {
+    use crate::roman::{to_arabic_with_separators, SEPARATORS};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_separators() {
+        for input in [
+            "M CM XC IX",
+            "M.CM.XC.IX",
+            "MCMXCIX\n",
+            " M·CM·XC·IX ",
+            "M⸱CM⸱XC⸱IX",
+        ]
+        .iter()
+        {
+            let x = to_arabic_with_separators(input, &SEPARATORS);
+            assert_eq!(x.unwrap(), 1999, "{:?}", input);
+        }
+
+        let x = to_arabic_with_separators("X-IV", &['-']);
+        assert_eq!(x.unwrap(), 14);
+
+        let x = to_arabic_with_separators("X-IV", &SEPARATORS);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: '-', index: 1 }
+        );
+    }
+
+    #[test]
+    fn test_error_positions() {
+        let x = to_arabic_with_separators("M CM XC IIX", &SEPARATORS);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'I', index: 8 }
+        );
+
+        let x = to_arabic_with_separators(" . ", &SEPARATORS);
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+    }
+}
+
+#[cfg(test)]
+mod test_to_arabic_ordinal {
+    use crate::roman::to_arabic_ordinal;
+    use crate::NumeralError;
+
+    fn parse(input: &str) -> (u64, Option<&str>) {
+        let parsed = to_arabic_ordinal(input).unwrap();
+        (parsed.value, parsed.marker)
+    }
+
+    #[test]
+    fn test_markers() {
+        assert_eq!(parse("XIV."), (14, Some(".")));
+        assert_eq!(parse("XIVᵉ"), (14, Some("ᵉ")));
+        assert_eq!(parse("XIXe"), (19, Some("e")));
+        assert_eq!(parse("XIXème"), (19, Some("ème")));
+        assert_eq!(parse("Ier"), (1, Some("er")));
+        assert_eq!(parse("XXIIIrd"), (23, Some("rd")));
+        assert_eq!(parse("XXIInd"), (22, Some("nd")));
+        assert_eq!(parse("xivth"), (14, Some("th")));
+        assert_eq!(parse("XIX.º"), (19, Some(".º")));
+    }
+
+    #[test]
+    fn test_no_marker() {
+        assert_eq!(parse("XIV"), (14, None));
+        assert_eq!(parse("CD"), (400, None));
+        assert_eq!(parse("ⅯⅮ"), (1500, None));
+    }
+
+    #[test]
+    fn test_invalid_numerals() {
+        let x = to_arabic_ordinal("IXCe");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'I', index: 0 }
+        );
+
+        let x = to_arabic_ordinal("XIVz");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: 'z', index: 3 }
+        );
+
+        let x = to_arabic_ordinal("e");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: 'e', index: 0 }
+        );
+
+        let x = to_arabic_ordinal(".");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: '.', index: 0 }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_scripts {
+    use crate::roman::{normalize_script, scripts, to_arabic, Scripts};
+
+    #[test]
+    fn test_scripts() {
+        let x = scripts("MⅭM");
+        assert_eq!(
+            x,
+            Scripts {
+                ascii: true,
+                unicode: true
+            }
+        );
+        assert!(x.is_mixed());
+
+        let x = scripts("ⅯⅭⅯ");
+        assert!(!x.ascii && x.unicode);
+
+        let x = scripts("(IV)CCXXI");
+        assert!(x.ascii && !x.unicode);
+
+        let x = scripts("");
+        assert_eq!(x, Scripts::default());
+    }
+
+    #[test]
+    fn test_normalize_script() {
+        let x = normalize_script("MⅭM", false);
+        assert_eq!(x, "MCM");
+        assert!(!scripts(&x).is_mixed());
+
+        let x = normalize_script("ⅿcⅿxcⅳ", true);
+        assert_eq!(x, "ⅿⅽⅿⅹⅽⅳ");
+
+        let x = normalize_script("ↀↅ", false);
+        assert_eq!(x, "MVI");
+
+        let x = normalize_script("ↁI", false);
+        assert_eq!(x, "ↁI");
+
+        let x = normalize_script("X\u{305}I", true);
+        assert_eq!(x, "Ⅹ\u{305}Ⅰ");
+
+        for input in ["MⅭMⅩCⅣ", "ⅿcⅿxcⅳ"].iter() {
+            let x = to_arabic(normalize_script(input, false));
+            assert_eq!(x, to_arabic(input));
+        }
+    }
+}
+
+#[cfg(all(test, feature = "unicode-normalization"))]
+mod test_normalize {
+    use crate::roman::{normalize, to_arabic_normalized};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("MCMXCIX"), "MCMXCIX");
+        assert_eq!(normalize("ＭＣＭ"), "MCM");
+        assert_eq!(normalize("ｍｃｍ"), "mcm");
+        assert_eq!(normalize("ⅯⅭⅯⅫ"), "MCMXII");
+        assert_eq!(normalize("ↀↁ"), "ↀↁ");
+        assert_eq!(normalize("V‾"), "V\u{305}");
+    }
+
+    #[test]
+    fn test_to_arabic_normalized() {
+        let x = to_arabic_normalized("ＭＣＭＸＣＩＸ");
+        assert_eq!(x.unwrap(), 1999);
+
+        let x = to_arabic_normalized("ＭⅭⅯⅫ");
+        assert_eq!(x.unwrap(), 1912);
+
+        let x = to_arabic_normalized("V‾ＶＩＩ");
+        assert_eq!(x.unwrap(), 5007);
+
+        let x = to_arabic_normalized("ⅫＸ");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'I', index: 1 }
+        );
+
+        let x = to_arabic_normalized("ＸＹ");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: 'Y', index: 1 }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_to_arabic_with_rules {
+    use crate::roman::{to_arabic_with_rules, RuleSet};
+    use crate::NumeralError;
+
+    #[test]
+    fn test_default_rules() {
+        let rules = RuleSet::default();
+        for input in ["XIV", "IIII", "IM", "MCMXCIV"].iter() {
+            let x = to_arabic_with_rules(input, &rules);
+            assert!(x.is_ok(), "{}", input);
+        }
+        for input in ["IXC", "XXXX", "LL", "VX"].iter() {
+            let x = to_arabic_with_rules(input, &rules);
+            assert!(x.is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_strict_rules() {
+        let rules = RuleSet::strict();
+        for input in ["XIV", "MCMXCIV", "ↀↂ"].iter() {
+            let x = to_arabic_with_rules(input, &rules);
+            assert!(x.is_ok(), "{}", input);
+        }
+        for input in ["IIII", "IM", "IC", "VL", "XD"].iter() {
+            let x = to_arabic_with_rules(input, &rules);
+            assert!(x.is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_single_rules() {
+        let x = to_arabic_with_rules("IXC", &RuleSet::none());
+        assert_eq!(x.unwrap(), 89);
+
+        let rules = RuleSet {
+            repeated_five: false,
+            ..RuleSet::default()
+        };
+        let x = to_arabic_with_rules("LL", &rules);
+        assert_eq!(x.unwrap(), 100);
+
+        let x = to_arabic_with_rules("VV", &RuleSet::default());
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'V', index: 0 }
+        );
+
+        let x = to_arabic_with_rules("VV", &rules);
+        assert_eq!(x.unwrap(), 10);
+
+        for &(input, ch) in [("IVI", 'I'), ("XCX", 'X'), ("LXL", 'L'), ("VIV", 'V')].iter() {
+            let x = to_arabic_with_rules(input, &RuleSet::default());
+            assert_eq!(
+                x.unwrap_err(),
+                NumeralError::InvalidSequence { ch, index: 0 }
+            );
+        }
+
+        let rules = RuleSet {
+            added_back: false,
+            ..RuleSet::default()
+        };
+        let x = to_arabic_with_rules("IVI", &rules);
+        assert_eq!(x.unwrap(), 5);
+
+        let x = to_arabic_with_rules("LXL", &rules);
+        assert_eq!(x.unwrap(), 90);
+
+        let rules = RuleSet {
+            value_order: true,
+            ..RuleSet::default()
+        };
+        let x = to_arabic_with_rules("XM", &rules);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'X', index: 0 }
+        );
+
+        let rules = RuleSet {
+            iiii_exception: false,
+            ..RuleSet::default()
+        };
+        let x = to_arabic_with_rules("IIII", &rules);
+        assert!(x.is_err());
+
+        let x = to_arabic_with_rules("(XXXX)", &RuleSet::none());
+        assert_eq!(x.unwrap(), 40_000);
+    }
+}
+
+#[cfg(test)]
+mod test_to_arabic_with_strictness {
+    use crate::roman::to_arabic_with_strictness;
+    use crate::{NumeralError, Strictness};
+
+    #[test]
+    fn test_strict() {
+        let x = to_arabic_with_strictness("XIV", Strictness::Strict);
+        assert_eq!(x.unwrap(), 14);
+
+        let x = to_arabic_with_strictness("ⅩⅣ", Strictness::Strict);
+        assert_eq!(x.unwrap(), 14);
+
+        let x = to_arabic_with_strictness("IIII", Strictness::Strict);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'I', index: 0 }
+        );
+
+        let x = to_arabic_with_strictness("ⅹⅰⅰⅰⅰ", Strictness::Strict);
+        assert!(x.is_err());
+
+        let x = to_arabic_with_strictness("XⅣ", Strictness::Strict);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter {
+                ch: 'Ⅳ', index: 1
+            }
+        );
+
+        let x = to_arabic_with_strictness("IM", Strictness::Strict);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'I', index: 0 }
+        );
+
+        let x = to_arabic_with_strictness("V\u{305}II", Strictness::Strict);
+        assert_eq!(x.unwrap(), 5002);
+
+        // Only the numerals written as by `to_roman` are accepted
+        for &(input, ch) in [("VV", 'V'), ("IVI", 'I'), ("XCX", 'X'), ("LXL", 'L')].iter() {
+            let x = to_arabic_with_strictness(input, Strictness::Strict);
+            assert_eq!(
+                x.unwrap_err(),
+                NumeralError::InvalidSequence { ch, index: 0 }
+            );
+        }
+
+        let x = to_arabic_with_strictness("MCMXCIV", Strictness::Strict);
+        assert_eq!(x.unwrap(), 1994);
+    }
+
+    #[test]
+    fn test_standard() {
+        let x = to_arabic_with_strictness("IIII", Strictness::Standard);
+        assert_eq!(x.unwrap(), 4);
+
+        let x = to_arabic_with_strictness("XⅣ", Strictness::Standard);
+        assert_eq!(x.unwrap(), 14);
+
+        let x = to_arabic_with_strictness("XXXX", Strictness::Standard);
+        assert!(x.is_err());
+    }
+
+    #[test]
+    fn test_lenient() {
+        let x = to_arabic_with_strictness("XXXX", Strictness::Lenient);
+        assert_eq!(x.unwrap(), 40);
+
+        let x = to_arabic_with_strictness("XXXXA", Strictness::Lenient);
+        assert!(x.is_err());
     }
+}
 
-    let list = match use_unicode {
-        true => ARABIC_TO_UNICODE.to_vec(),
-        false => ARABIC_TO_ASCII.to_vec(),
-    };
-    let mut ret = String::new();
-    for (arabic, roman) in list.iter() {
-        while input % arabic < input {
-            ret += roman;
-            input -= arabic;
-        }
+#[cfg(test)]
+mod test_suggest {
+    use crate::roman::suggest;
+
+    #[test]
+    fn test_invalid_sequences() {
+        assert_eq!(suggest("XXXX"), Some("XL".to_string()));
+        assert_eq!(suggest("IIX"), Some("VIII".to_string()));
+        assert_eq!(suggest("VIIII"), Some("IX".to_string()));
+        assert_eq!(suggest("LC"), Some("L".to_string()));
+        assert_eq!(suggest("mdcccc"), Some("MCM".to_string()));
+        assert_eq!(suggest("ⅩⅩⅩⅩ"), Some("ⅩⅬ".to_string()));
+    }
+
+    #[test]
+    fn test_no_suggestion() {
+        assert_eq!(suggest("XL"), None);
+        assert_eq!(suggest("IIII"), None);
+        assert_eq!(suggest(""), None);
+        assert_eq!(suggest("LXS"), None);
+        assert_eq!(suggest("IIIIIIIIIIIX"), None);
+        assert_eq!(suggest("MMMM"), None);
     }
-    Ok(ret)
 }
 
-pub fn to_arabic(roman: String) -> Result<u64, Box<dyn Error>> {
-    let roman = roman.to_uppercase();
-    if roman.is_empty() {
-        return Err("Invalid empty string".into());
+#[cfg(test)]
+mod test_roman_numeral {
+    use crate::roman::RomanNumeral;
+    use crate::NumeralError;
+
+    #[test]
+    fn test_new() {
+        let x = RomanNumeral::new(14);
+        assert_eq!(x.unwrap().value(), 14);
+
+        let x = RomanNumeral::new(0);
+        assert!(x.is_err());
+
+        let x = RomanNumeral::new(4000);
+        assert!(x.is_err());
     }
 
-    if roman == "IIII" || roman == "ⅠⅠⅠⅠ" {
-        // Having four additions in a row is illegal, short of the sequence IIII
-        return Ok(4u64);
+    #[test]
+    fn test_display() {
+        let x = RomanNumeral::new(1994).unwrap();
+        assert_eq!(format!("{}", x), "MCMXCIV");
+        assert_eq!(x.to_string(), "MCMXCIV");
+        assert_eq!(format!("{:#}", x), "ⅯⅭⅯⅩⅭⅠⅤ");
     }
 
-    let mut characters = BTreeSet::new();
-    for c in roman.chars() {
-        characters.insert(c);
+    #[test]
+    fn test_from_str() {
+        let x = "XIV".parse::<RomanNumeral>();
+        assert_eq!(x.unwrap(), RomanNumeral::new(14).unwrap());
+
+        let x = "ⅭⅴⅬ".parse::<RomanNumeral>();
+        assert_eq!(x.unwrap().value(), 145);
+
+        let x = "XIL".parse::<RomanNumeral>();
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'X', index: 0 }
+        );
+
+        let x = "ↈ".parse::<RomanNumeral>();
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::OutOfRange {
+                value: 100000,
+                min: 1,
+                max: 3999
+            }
+        );
     }
+}
+
+#[cfg(test)]
+mod test_roman_numeral_conversions {
+    use crate::roman::RomanNumeral;
+    use crate::NumeralError;
+    use core::convert::{TryFrom, TryInto};
+
+    #[test]
+    fn test_try_from_u64() {
+        let x = RomanNumeral::try_from(14u64);
+        assert_eq!(x.unwrap().to_string(), "XIV");
 
-    if !characters.is_subset(&NUMERALS) {
-        return Err("Input contains invalid characters".into());
+        let x: Result<RomanNumeral, NumeralError> = 4000u64.try_into();
+        assert!(x.is_err());
     }
 
-    // This buffer is used to check that not 4 elements in a row are similar.
-    let mut buffer: VecDeque<u64> = VecDeque::with_capacity(4);
-    buffer.push_back(0); // oldest
-    buffer.push_back(0); // preprevious
-    buffer.push_back(0); // previous
-    buffer.push_back(0); // current
+    #[test]
+    fn test_try_from_str() {
+        let x = RomanNumeral::try_from("XIV");
+        assert_eq!(x.unwrap().value(), 14);
 
-    let mut value: u64 = 0;
-    for c in roman.chars().rev() {
-        let current: u64 = ROMAN_TO_ARABIC[&c];
-        buffer.pop_front();
-        buffer.push_back(current);
+        let x: Result<RomanNumeral, NumeralError> = "LXS".try_into();
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: 'S', index: 2 }
+        );
+    }
 
-        if current < buffer[1] {
-            // Having two subtraction in a row is illegal
-            return Err("Invalid sequence".into());
-        } else if buffer.iter().all(|&item| item == current) {
-            // Having four additions in a row is illegal
-            return Err("Invalid sequence".into());
-        } else if current == buffer[2] && (current == 50 || current == 500) {
-            // Having two consecutive L or D is illegal
-            return Err("Invalid sequence".into());
-        } else if current < buffer[2] {
-            if buffer[2] - current == current {
-                // Having a subtraction that does nothing is illegal
-                return Err("Invalid sequence".into());
-            }
-            value -= current;
-        } else {
-            value += current;
+    #[test]
+    fn test_into_u64() {
+        fn double(input: &str) -> Result<u64, NumeralError> {
+            let value: u64 = RomanNumeral::try_from(input)?.into();
+            Ok(value * 2)
         }
+
+        assert_eq!(double("XXI").unwrap(), 42);
+        assert!(double("IIX").is_err());
+        assert_eq!(u64::from(RomanNumeral::new(7).unwrap()), 7);
     }
-    Ok(value)
 }
 
 #[cfg(test)]
-mod test_to_roman {
-    use crate::roman::to_roman;
+mod test_roman_numeral_arithmetic {
+    use crate::roman::RomanNumeral;
+    use crate::NumeralError;
 
     #[test]
-    fn test_invalid_inputs() {
-        let x = to_roman(0u64, false);
-        assert!(x.is_err());
+    fn test_operators() {
+        let sum = (|| RomanNumeral::parse("XIV")? + RomanNumeral::parse("VI")?)();
+        assert_eq!(sum.unwrap().to_string(), "XX");
 
-        let x = to_roman(1u64, true);
-        assert!(x.is_ok());
+        let x = RomanNumeral::new(20).unwrap();
+        let y = RomanNumeral::new(6).unwrap();
+        assert_eq!((x - y).unwrap().value(), 14);
+        assert_eq!((x * y).unwrap().value(), 120);
+    }
 
-        let x = to_roman(3999u64, false);
-        assert!(x.is_ok());
+    #[test]
+    fn test_out_of_range() {
+        let x = RomanNumeral::new(3000).unwrap();
+        let y = RomanNumeral::new(1000).unwrap();
+        assert_eq!(
+            (x + y).unwrap_err(),
+            NumeralError::OutOfRange {
+                value: 4000,
+                min: 1,
+                max: 3999
+            }
+        );
+        assert_eq!(
+            (y - x).unwrap_err(),
+            NumeralError::OutOfRange {
+                value: 0,
+                min: 1,
+                max: 3999
+            }
+        );
+        assert!((x * y).is_err());
+        assert!((x - x).is_err());
+    }
 
-        let x = to_roman(4000u64, true);
-        assert!(x.is_err());
+    #[test]
+    fn test_checked() {
+        let x = RomanNumeral::new(2000).unwrap();
+        let y = RomanNumeral::new(2).unwrap();
+        assert_eq!(x.checked_add(y), RomanNumeral::new(2002).ok());
+        assert_eq!(x.checked_sub(y), RomanNumeral::new(1998).ok());
+        assert_eq!(x.checked_mul(y), None);
+        assert_eq!(y.checked_sub(x), None);
+        assert_eq!(x.checked_add(x), None);
     }
+}
+
+#[cfg(test)]
+mod test_ordering {
+    use crate::roman::{cmp_str, RomanNumeral};
+    use core::cmp::Ordering;
 
     #[test]
-    fn test_valid_inputs() {
-        let x = to_roman(1999u64, false);
-        assert_eq!(x.unwrap(), "MCMXCIX".to_string());
+    fn test_roman_numeral_order() {
+        let mut numerals: Vec<RomanNumeral> = ["XC", "IX", "XI"]
+            .iter()
+            .map(|numeral| numeral.parse().unwrap())
+            .collect();
+        numerals.sort();
+        assert_eq!(numerals[0].to_string(), "IX");
+        assert_eq!(numerals[2].to_string(), "XC");
+        assert!(numerals[0] < numerals[1]);
+        assert_eq!(numerals.iter().max().unwrap().value(), 90);
+    }
 
-        let x = to_roman(99u64, false);
-        assert_eq!(x.unwrap(), "XCIX".to_string());
+    #[test]
+    fn test_cmp_str() {
+        assert_eq!(cmp_str("XIV", "XV"), Ordering::Less);
+        assert_eq!(cmp_str("M", "CMXCIX"), Ordering::Greater);
+        assert_eq!(cmp_str("iv", "IIII"), Ordering::Equal);
+        assert_eq!(cmp_str("MMM", "IXC"), Ordering::Less);
+        assert_eq!(cmp_str("IXC", "I"), Ordering::Greater);
+        assert_eq!(cmp_str("IXC", "LXS"), Ordering::Less);
 
-        let x = to_roman(1999, true);
-        assert_eq!(x.unwrap(), "ⅯⅭⅯⅩⅭⅠⅩ");
+        let mut numerals = vec!["LXS", "C", "ⅩⅬ", "ix", "IXC", "MMXX"];
+        numerals.sort_by(|a, b| cmp_str(a, b));
+        assert_eq!(numerals, vec!["ix", "ⅩⅬ", "C", "MMXX", "IXC", "LXS"]);
     }
 }
 
 #[cfg(test)]
-mod test_to_arabic {
-    use crate::roman::to_arabic;
+mod test_iter {
+    use crate::roman::{iter, to_roman, Notation, RomanOptions};
+    use core::ops::Bound;
 
     #[test]
-    fn test_string_cases() {
-        let x = to_arabic("iv".to_string());
-        assert!(x.is_ok());
-        assert_eq!(x.unwrap(), 4);
+    fn test_iter() {
+        let options = RomanOptions::new();
+        let x: Vec<String> = iter(1..=4, &options).collect();
+        assert_eq!(x, vec!["I", "II", "III", "IV"]);
 
-        let x = to_arabic("LIX".to_string());
-        assert!(x.is_ok());
-        assert_eq!(x.unwrap(), 59);
+        let x: Vec<String> = iter(8..11, &options).rev().collect();
+        assert_eq!(x, vec!["X", "IX", "VIII"]);
 
-        let x = to_arabic("CvL".to_string());
-        assert!(x.is_ok());
-        assert_eq!(x.unwrap(), 145);
+        for (value, numeral) in (1..=3999).zip(iter(.., &options)) {
+            assert_eq!(numeral, to_roman(value, false).unwrap());
+        }
     }
+
     #[test]
-    fn test_unicode_cases() {
-        let x = to_arabic("ⅳ".to_string());
-        assert!(x.is_ok());
-        assert_eq!(x.unwrap(), 4);
+    fn test_iter_options() {
+        let options = RomanOptions::new().unicode(true).clock();
+        let x: Vec<String> = iter(3..=5, &options).collect();
+        assert_eq!(x, vec!["ⅠⅠⅠ", "ⅠⅠⅠⅠ", "Ⅴ"]);
 
-        let x = to_arabic("ⅬⅨ".to_string());
-        assert!(x.is_ok());
-        assert_eq!(x.unwrap(), 59);
+        let options = RomanOptions::new().nulla(true);
+        let x: Vec<String> = iter(..2, &options).collect();
+        assert_eq!(x, vec!["N", "I"]);
 
-        let x = to_arabic("ⅭⅴⅬ".to_string());
-        assert!(x.is_ok());
-        assert_eq!(x.unwrap(), 145);
+        let options = RomanOptions::new().notation(Notation::Vinculum);
+        let x: Vec<String> = iter(4999..5001, &options).collect();
+        assert_eq!(x, vec!["I\u{305}V\u{305}CMXCIX", "V\u{305}"]);
     }
 
     #[test]
-    fn test_empty_input() {
-        let x = to_arabic(String::new());
-        assert!(x.is_err());
-        assert_eq!(format!("{:?}", x), "Err(\"Invalid empty string\")");
+    fn test_iter_bounds() {
+        let options = RomanOptions::new();
+        assert_eq!(iter(0..=3, &options).count(), 3);
+        assert_eq!(iter(3990.., &options).count(), 10);
+        assert_eq!(iter(3999..5000, &options).count(), 1);
+        assert_eq!(iter(5..5, &options).count(), 0);
+        assert_eq!(iter(..0, &options).count(), 0);
+
+        let options = RomanOptions::new().notation(Notation::Apostrophus);
+        assert_eq!(iter(399_990.., &options).count(), 10);
+
+        let options = RomanOptions::new().notation(Notation::Brackets);
+        let x = iter((Bound::Excluded(u64::MAX), Bound::Unbounded), &options);
+        assert_eq!(x.count(), 0);
+    }
+}
+
+#[cfg(test)]
+mod test_compare {
+    use crate::roman::{compare, equals, CompareError};
+    use crate::NumeralError;
+    use core::cmp::Ordering;
+
+    #[test]
+    fn test_compare() {
+        assert_eq!(compare("XIV", "XV"), Ok(Ordering::Less));
+        assert_eq!(compare("MM", "CM"), Ok(Ordering::Greater));
+        assert_eq!(compare("ⅩⅣ", "xiv"), Ok(Ordering::Equal));
     }
 
     #[test]
-    fn test_invalid_characters() {
-        let x = to_arabic("LXS".to_string());
-        assert!(x.is_err());
+    fn test_equals() {
+        assert_eq!(equals("IIII", "IV"), Ok(true));
+        assert_eq!(equals("ⅻ", "XII"), Ok(true));
+        assert_eq!(equals("XIV", "XV"), Ok(false));
+    }
+
+    #[test]
+    fn test_errors() {
+        let x = compare("VX", "XV");
+        assert_eq!(
+            x.unwrap_err(),
+            CompareError {
+                left: Some(NumeralError::InvalidSequence { ch: 'V', index: 0 }),
+                right: None
+            }
+        );
+
+        let x = equals("XV", "");
+        assert_eq!(
+            x.unwrap_err(),
+            CompareError {
+                left: None,
+                right: Some(NumeralError::Empty)
+            }
+        );
+
+        let x = compare("Q", "");
+        let error = x.unwrap_err();
+        assert_eq!(
+            error.left,
+            Some(NumeralError::InvalidCharacter { ch: 'Q', index: 0 })
+        );
+        assert_eq!(error.right, Some(NumeralError::Empty));
         assert_eq!(
-            format!("{:?}", x),
-            "Err(\"Input contains invalid characters\")"
+            error.to_string(),
+            "Left: Input contains invalid characters, right: Invalid empty string"
         );
     }
+}
+
+#[cfg(test)]
+mod test_diff {
+    use crate::roman::{diff, diff_roman, CompareError};
+    use crate::NumeralError;
 
     #[test]
-    fn test_invalid_inputs() {
-        let x = to_arabic("XIL".to_string());
-        assert!(x.is_err());
-        assert_eq!(format!("{:?}", x), "Err(\"Invalid sequence\")");
+    fn test_diff() {
+        assert_eq!(diff("MDCCXV", "MDCXLIII"), Ok(72));
+        assert_eq!(diff("X", "XV"), Ok(-5));
+        assert_eq!(diff("ⅩⅣ", "xiv"), Ok(0));
+        assert_eq!(diff("X\u{305}", "I"), Ok(9999));
+    }
 
-        let x = to_arabic("VIL".to_string());
-        assert!(x.is_err());
-        assert_eq!(format!("{:?}", x), "Err(\"Invalid sequence\")");
+    #[test]
+    fn test_diff_roman() {
+        assert_eq!(diff_roman("MDCCXV", "MDCXLIII", false).unwrap(), "LXXII");
+        assert_eq!(diff_roman("MDCCXV", "MDCXLIII", true).unwrap(), "ⅬⅩⅩⅠⅠ");
+        assert_eq!(diff_roman("I", "MMM", false).unwrap(), "-MMCMXCIX");
+        assert_eq!(diff_roman("IV", "IIII", false).unwrap(), "N");
+        assert_eq!(
+            diff_roman("X\u{305}", "I", false).unwrap(),
+            "I\u{305}X\u{305}CMXCIX"
+        );
+    }
 
-        let x = to_arabic("IXC".to_string());
-        assert!(x.is_err());
-        assert_eq!(format!("{:?}", x), "Err(\"Invalid sequence\")");
+    #[test]
+    fn test_errors() {
+        let x = diff("VX", "");
+        assert_eq!(
+            x.unwrap_err(),
+            CompareError {
+                left: Some(NumeralError::InvalidSequence { ch: 'V', index: 0 }),
+                right: Some(NumeralError::Empty)
+            }
+        );
 
-        let x = to_arabic("XXC".to_string());
-        assert!(x.is_err());
-        assert_eq!(format!("{:?}", x), "Err(\"Invalid sequence\")");
+        let x = diff_roman("X", "XQ", false);
+        assert_eq!(
+            x.unwrap_err(),
+            CompareError {
+                left: None,
+                right: Some(NumeralError::InvalidCharacter { ch: 'Q', index: 1 })
+            }
+        );
 
-        let x = to_arabic("LC".to_string());
-        assert!(x.is_err());
-        assert_eq!(format!("{:?}", x), "Err(\"Invalid sequence\")");
+        let huge = crate::roman::to_vinculum(u64::MAX).unwrap();
+        let x = diff(&huge, "I");
+        assert_eq!(
+            x.unwrap_err(),
+            CompareError {
+                left: Some(NumeralError::OutOfRange {
+                    value: u64::MAX,
+                    min: 0,
+                    max: i64::MAX as u64
+                }),
+                right: None
+            }
+        );
+    }
+}
 
-        let x = to_arabic("LDVX".to_string());
-        assert!(x.is_err());
-        assert_eq!(format!("{:?}", x), "Err(\"Invalid sequence\")");
+#[cfg(test)]
+mod test_roman_range {
+    use crate::roman::RomanNumeral;
+
+    #[test]
+    fn test_bounds() {
+        let x: Vec<u64> = RomanNumeral::range(1..=5).map(|n| n.value()).collect();
+        assert_eq!(x, vec![1, 2, 3, 4, 5]);
+
+        let x: Vec<u64> = RomanNumeral::range(3..5).map(|n| n.value()).collect();
+        assert_eq!(x, vec![3, 4]);
+
+        let x: Vec<String> = RomanNumeral::range(0..3).map(|n| n.to_string()).collect();
+        assert_eq!(x, vec!["I", "II"]);
+
+        assert_eq!(RomanNumeral::range(3990..).count(), 10);
+        assert_eq!(RomanNumeral::range(..).len(), 3999);
+        assert_eq!(RomanNumeral::range(5..5).count(), 0);
+        assert_eq!(RomanNumeral::range(0..1).count(), 0);
+        assert_eq!(RomanNumeral::range(5000..6000).count(), 0);
     }
 
     #[test]
-    fn test_valid_inputs() {
-        let x = to_arabic("XCIX".to_string());
-        assert_eq!(x.unwrap(), 99);
+    fn test_reversed() {
+        let x: Vec<String> = RomanNumeral::range(8..=10)
+            .rev()
+            .map(|n| n.to_string())
+            .collect();
+        assert_eq!(x, vec!["X", "IX", "VIII"]);
 
-        let x = to_arabic("MCMLXXXIV".to_string());
-        assert_eq!(x.unwrap(), 1984);
+        let mut range = RomanNumeral::range(1..=3);
+        assert_eq!(range.next_back().unwrap().value(), 3);
+        assert_eq!(range.next().unwrap().value(), 1);
+        assert_eq!(range.len(), 1);
+        assert_eq!(range.next_back().unwrap().value(), 2);
+        assert!(range.next().is_none());
+        assert!(range.next_back().is_none());
+    }
+}
 
-        let x = to_arabic("MMMCMXCIX".to_string());
-        assert_eq!(x.unwrap(), 3999);
+#[cfg(all(test, feature = "serde"))]
+mod test_serde {
+    use crate::roman::RomanNumeral;
 
-        let x = to_arabic("LXXX".to_string());
-        assert_eq!(x.unwrap(), 80);
+    #[test]
+    fn test_roman_numeral() {
+        let x = serde_json::to_string(&RomanNumeral::new(1994).unwrap());
+        assert_eq!(x.unwrap(), r#""MCMXCIV""#);
+
+        let x: Result<RomanNumeral, _> = serde_json::from_str(r#""mcmxciv""#);
+        assert_eq!(x.unwrap().value(), 1994);
+
+        let x: Vec<RomanNumeral> = serde_json::from_str(r#"["I", "ⅡⅠ"]"#).unwrap();
+        assert_eq!(x[1].value(), 3);
     }
 
     #[test]
-    fn test_four_same_symbols() {
-        let x = to_arabic("IIII".to_string());
-        assert_eq!(x.unwrap(), 4);
+    fn test_invalid_roman_numeral() {
+        let x: Result<RomanNumeral, _> = serde_json::from_str(r#""IXC""#);
+        assert_eq!(
+            x.unwrap_err().to_string(),
+            "Invalid sequence at line 1 column 5"
+        );
 
-        let x = to_arabic("XXXX".to_string());
+        let x: Result<RomanNumeral, _> = serde_json::from_str("14");
         assert!(x.is_err());
-        assert_eq!(format!("{:?}", x), "Err(\"Invalid sequence\")");
+    }
+}
 
-        let x = to_arabic("VIIII".to_string());
-        assert!(x.is_err());
-        assert_eq!(format!("{:?}", x), "Err(\"Invalid sequence\")");
+#[cfg(all(test, feature = "rand"))]
+mod test_rand {
+    use crate::roman::{RomanDistribution, RomanNumeral};
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_distribution() {
+        let mut rng = SmallRng::seed_from_u64(1994);
+        let distribution = RomanDistribution::new(10..=12).unwrap();
+        let numerals: Vec<RomanNumeral> = (&mut rng).sample_iter(distribution).take(100).collect();
+        assert!(numerals.iter().all(|n| (10..=12).contains(&n.value())));
+        for value in 10..=12 {
+            assert!(numerals.iter().any(|n| n.value() == value));
+        }
+
+        let distribution = RomanDistribution::new(3990..).unwrap();
+        let numeral: RomanNumeral = rng.sample(distribution);
+        assert!(numeral.value() >= 3990);
     }
 
     #[test]
-    fn test_double_symbols() {
-        let x = to_arabic("MM".to_string());
-        assert!(x.is_ok());
-        assert_eq!(x.unwrap(), 2000);
+    fn test_empty_range() {
+        assert!(RomanDistribution::new(5..5).is_none());
+        assert!(RomanDistribution::new(4000..).is_none());
+        assert!(RomanDistribution::new(..1).is_none());
+    }
 
-        let x = to_arabic("CC".to_string());
-        assert!(x.is_ok());
-        assert_eq!(x.unwrap(), 200);
+    #[test]
+    fn test_standard() {
+        let mut rng = SmallRng::seed_from_u64(1994);
+        for _ in 0..100 {
+            let numeral: RomanNumeral = rng.gen();
+            assert!((1..=3999).contains(&numeral.value()));
+            assert_eq!(
+                numeral.to_string().parse::<RomanNumeral>().unwrap(),
+                numeral
+            );
+        }
+    }
+}
 
-        let x = to_arabic("XX".to_string());
-        assert!(x.is_ok());
-        assert_eq!(x.unwrap(), 20);
+#[cfg(all(test, feature = "arbitrary"))]
+mod test_arbitrary {
+    use crate::roman::RomanNumeral;
+    use arbitrary::{Arbitrary, Unstructured};
 
-        let x = to_arabic("II".to_string());
-        assert!(x.is_ok());
-        assert_eq!(x.unwrap(), 2);
+    #[test]
+    fn test_roman_numeral() {
+        let data: Vec<u8> = (0..=255).collect();
+        let mut u = Unstructured::new(&data);
+        while !u.is_empty() {
+            let numeral = RomanNumeral::arbitrary(&mut u).unwrap();
+            assert!((1..=3999).contains(&numeral.value()));
+        }
+    }
+}
 
-        let x = to_arabic("LL".to_string());
-        assert!(x.is_err());
-        assert_eq!(format!("{:?}", x), "Err(\"Invalid sequence\")");
+#[cfg(all(test, feature = "proptest"))]
+mod test_strategy {
+    use crate::roman::{strategy, to_arabic, RomanNumeral};
+    use proptest::prelude::*;
 
-        let x = to_arabic("DD".to_string());
-        assert!(x.is_err());
-        assert_eq!(format!("{:?}", x), "Err(\"Invalid sequence\")");
+    proptest! {
+        #[test]
+        fn test_numerals(numeral in any::<RomanNumeral>()) {
+            prop_assert_eq!(numeral.to_string().parse::<RomanNumeral>().unwrap(), numeral);
+        }
+
+        #[test]
+        fn test_valid_strings(numeral in strategy::valid_strings()) {
+            prop_assert!(to_arabic(&numeral).is_ok());
+        }
     }
+}
+
+#[cfg(test)]
+mod test_const {
+    use crate::roman::{arabic_const, roman_const, to_arabic, to_roman, RomanBuf};
+
+    const YEAR: RomanBuf = roman_const(1994);
+    const LONGEST: &str = roman!(3888);
+    const VALUE: u64 = arabic_const("mcmxciv");
 
     #[test]
-    fn test_apostrohpus() {
-        let x = to_arabic("ↀ".to_string());
-        assert!(x.is_ok());
-        assert_eq!(x.unwrap(), 1000);
+    fn test_compile_time() {
+        assert_eq!(YEAR.as_str(), "MCMXCIV");
+        assert_eq!(YEAR.to_string(), "MCMXCIV");
+        assert_eq!(LONGEST, "MMMDCCCLXXXVIII");
+        assert_eq!(VALUE, 1994);
+        assert_eq!(arabic_const("IIII"), 4);
+    }
 
-        let x = to_arabic("ↀXↀIX".to_string());
-        assert!(x.is_ok());
-        assert_eq!(x.unwrap(), 1999);
+    #[test]
+    fn test_same_as_runtime() {
+        for value in 1..=3999 {
+            let numeral = to_roman(value, false).unwrap();
+            assert_eq!(roman_const(value).as_str(), numeral);
+            assert_eq!(arabic_const(&numeral), value);
+            assert_eq!(arabic_const(&numeral.to_lowercase()), value);
+        }
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        for input in &["", "IXC", "XXXX", "LL", "VX", "LXS"] {
+            assert!(to_arabic(input).is_err());
+            assert!(std::panic::catch_unwind(|| arabic_const(input)).is_err());
+        }
+        // Only the ascii numerals are supported
+        assert!(std::panic::catch_unwind(|| arabic_const("ⅩⅠⅤ")).is_err());
+        assert!(std::panic::catch_unwind(|| roman_const(0)).is_err());
+        assert!(std::panic::catch_unwind(|| roman_const(4000)).is_err());
+    }
+
+    #[test]
+    fn test_value_of() {
+        const TEN: Option<u64> = crate::roman::value_of('X');
+        assert_eq!(TEN, Some(10));
+        assert_eq!(crate::roman::value_of('Ⅻ'), Some(12));
+        assert_eq!(crate::roman::value_of('ↈ'), Some(100000));
+        assert_eq!(crate::roman::value_of('x'), None);
+        assert_eq!(crate::roman::value_of('Q'), None);
+    }
+}
+
+#[cfg(test)]
+mod test_numeral_system {
+    use crate::roman::Roman;
+    use crate::{NumeralSystem, Options, Strictness};
+
+    #[test]
+    fn test_roman_system() {
+        assert_eq!(Roman.name(), "roman");
+
+        let x = Roman.parse("MCMXCIV");
+        assert_eq!(x.unwrap(), 1994);
 
-        let x = to_arabic("ↈIXC".to_string());
+        let x = Roman.format(1994, &Options::default());
+        assert_eq!(x.unwrap(), "MCMXCIV");
+
+        let options = Options {
+            unicode: true,
+            ..Options::default()
+        };
+        let x = Roman.format(1999, &options);
+        assert_eq!(x.unwrap(), "ⅯⅭⅯⅩⅭⅠⅩ");
+
+        let x = Roman.format(0, &options);
         assert!(x.is_err());
 
-        let x = to_arabic("ↈⅠV".to_string());
-        assert_eq!(x.unwrap(), 100004);
+        let options = Options {
+            vinculum: true,
+            ..Options::default()
+        };
+        let x = Roman.format(12_000, &options);
+        assert_eq!(x.unwrap(), "X\u{305}I\u{305}I\u{305}");
+
+        let options = Options {
+            strictness: Strictness::Lenient,
+            ..Options::default()
+        };
+        let x = Roman.parse_with("MDCCCCX", &options);
+        assert_eq!(x.unwrap(), 1910);
+
+        let options = Options {
+            clock: true,
+            ..Options::default()
+        };
+        let x = Roman.format(4, &options);
+        assert_eq!(x.unwrap(), "IIII");
+
+        let options = Options {
+            additive: true,
+            ..Options::default()
+        };
+        let x = Roman.format(1999, &options);
+        assert_eq!(x.unwrap(), "MDCCCCLXXXXVIIII");
+
+        let options = Options {
+            unicode: true,
+            lowercase: true,
+            ..Options::default()
+        };
+        let x = Roman.format(1994, &options);
+        assert_eq!(x.unwrap(), "ⅿⅽⅿⅹⅽⅰⅴ");
+        let x = Roman.parse("ⅿⅽⅿⅹⅽⅰⅴ");
+        assert_eq!(x.unwrap(), 1994);
+
+        let options = Options {
+            lowercase: true,
+            ..Options::default()
+        };
+        let x = Roman.format(14, &options);
+        assert_eq!(x.unwrap(), "xiv");
+
+        let options = Options {
+            unicode: true,
+            compact: true,
+            ..Options::default()
+        };
+        let x = Roman.format(1994, &options);
+        assert_eq!(x.unwrap(), "ⅯⅭⅯⅩⅭⅣ");
+
+        let options = Options {
+            nulla: true,
+            ..Options::default()
+        };
+        let x = Roman.format(0, &options);
+        assert_eq!(x.unwrap(), "N");
+        let x = Roman.parse_with("N", &options);
+        assert_eq!(x.unwrap(), 0);
+        let x = Roman.parse_with("N", &Options::default());
+        assert!(x.is_err());
     }
 }
@@ -0,0 +1,106 @@
+/*!
+Common interface to the numeral systems.
+
+Every numeral system module provides a type implementing `NumeralSystem`, so
+that conversions can be written once for any system.
+*/
+
+use alloc::string::String;
+
+use crate::error::NumeralError;
+
+/// Options shared by the numeral systems.
+///
+/// A system ignores the options it has no use for.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Options {
+    /// Use the dedicated unicode characters rather than ascii letters.
+    pub unicode: bool,
+    /// Write the numerals in lower case.
+    pub lowercase: bool,
+    /// Use the unicode characters standing for several numerals, as in Ⅻ.
+    pub compact: bool,
+    /// Write large values with overlines multiplying the numerals by 1000.
+    pub vinculum: bool,
+    /// Write and parse zero as N, from the latin nulla.
+    pub nulla: bool,
+    /// Write and parse negative values with a leading minus sign.
+    pub signed: bool,
+    /// Write 4 as IIII, as on clock faces.
+    pub clock: bool,
+    /// Write numerals without subtractions, as in VIIII.
+    pub additive: bool,
+    /// How closely numerals should follow the rules to be parsed.
+    pub strictness: Strictness,
+    /// Write the arabic digits in full width, as in japanese text.
+    pub fullwidth: bool,
+}
+
+/// How closely the numerals should follow the rules of their system.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Strictness {
+    /// Only accept the numerals following every rule, without exceptions.
+    Strict,
+    /// Accept the numerals following the rules, with the common exceptions.
+    #[default]
+    Standard,
+    /// Accept any numeral with an obvious value, even breaking the rules.
+    Lenient,
+}
+
+pub trait NumeralSystem {
+    /// The name the system is known by, e.g. "roman".
+    fn name(&self) -> &'static str;
+
+    /// Convert a numeral written in this system to its value.
+    fn parse(&self, input: &str) -> Result<u64, NumeralError>;
+
+    /// `parse`, following the parsing options.
+    fn parse_with(&self, input: &str, _options: &Options) -> Result<u64, NumeralError> {
+        self.parse(input)
+    }
+
+    /// Write a value as a numeral of this system.
+    fn format(&self, value: u64, options: &Options) -> Result<String, NumeralError>;
+
+    /// Suggest the numeral that an input rejected by `parse` was meant to be.
+    fn suggest(&self, _input: &str) -> Option<String> {
+        None
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod test_serde {
+    use crate::{Options, Strictness};
+
+    #[test]
+    fn test_options() {
+        let options = Options {
+            unicode: true,
+            lowercase: false,
+            compact: false,
+            vinculum: false,
+            nulla: false,
+            signed: false,
+            clock: false,
+            additive: false,
+            strictness: Strictness::Lenient,
+            fullwidth: false,
+        };
+        let x = serde_json::to_string(&options).unwrap();
+        assert_eq!(
+            x,
+            r#"{"unicode":true,"lowercase":false,"compact":false,"vinculum":false,"nulla":false,"signed":false,"clock":false,"additive":false,"strictness":"lenient","fullwidth":false}"#
+        );
+
+        let x: Options = serde_json::from_str(&x).unwrap();
+        assert_eq!(x, options);
+
+        let x: Options = serde_json::from_str("{}").unwrap();
+        assert_eq!(x, Options::default());
+    }
+}
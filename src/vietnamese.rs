@@ -0,0 +1,649 @@
+/*!
+Convert vietnamese number words to arabic, and vice-versa.
+
+Vietnamese numbers are spelled in words, the digits một to chín being followed
+by the unit they multiply: mươi for the tens, trăm for the hundreds, as in
+một trăm hai mươi ba for 123. Ten is mười by itself.
+
+Larger values are grouped by thousands: nghìn for 10^3, triệu for 10^6 and tỷ
+for 10^9, the groups beyond tỷ being counted in tỷ, as in một nghìn tỷ for
+10^12. Within the number, a group without hundreds is written with không trăm,
+as in một nghìn không trăm hai mươi for 1020.
+
+The units change after the tens: one is mốt after mươi, as in hai mươi mốt,
+five is lăm after mười and mươi, as in mười lăm, and four is often tư after
+mươi, as in hai mươi tư. A zero between the hundreds and the units is written
+linh, or lẻ, as in một trăm linh năm for 105.
+
+The north and the south of Vietnam differ in their words, which is selected
+with `Dialect`: the north writes nghìn, linh and tư, and the south ngàn, lẻ and
+bốn. Either is parsed, and so is tỉ for tỷ.
+
+The words are expected in the precomposed form of their diacritics.
+*/
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::error::NumeralError;
+use crate::system::{NumeralSystem, Options, Strictness};
+
+/// The digits, from không to chín.
+const DIGITS: [&str; 10] = [
+    "không", "một", "hai", "ba", "bốn", "năm", "sáu", "bảy", "tám", "chín",
+];
+
+/// The words of the numerals, other than the digits and the thousands.
+const WORDS: [&str; 9] = [
+    "mười", "mươi", "trăm", "linh", "lẻ", "mốt", "tư", "lăm", "tỉ",
+];
+
+/// The dialect the numbers are written in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Dialect {
+    /// As in Hanoi: nghìn, linh, and tư after mươi.
+    #[default]
+    Northern,
+    /// As in Ho Chi Minh City: ngàn, lẻ, and bốn after mươi.
+    Southern,
+}
+
+impl Dialect {
+    /// The word for 10^3.
+    fn thousand(self) -> &'static str {
+        match self {
+            Dialect::Northern => "nghìn",
+            Dialect::Southern => "ngàn",
+        }
+    }
+
+    /// The word for a zero between the hundreds and the units.
+    fn zero_tens(self) -> &'static str {
+        match self {
+            Dialect::Northern => "linh",
+            Dialect::Southern => "lẻ",
+        }
+    }
+
+    /// The word for four after mươi.
+    fn four(self) -> &'static str {
+        match self {
+            Dialect::Northern => "tư",
+            Dialect::Southern => "bốn",
+        }
+    }
+}
+
+/// The value of a digit.
+fn digit_value(word: &str) -> Option<u64> {
+    DIGITS
+        .iter()
+        .position(|&digit| digit == word)
+        .map(|digit| digit as u64)
+}
+
+/// The value of a word for 10^3, 10^6 or 10^9.
+fn multiplier_value(word: &str) -> Option<u64> {
+    match word {
+        "nghìn" | "ngàn" => Some(1000),
+        "triệu" => Some(1_000_000),
+        "tỷ" | "tỉ" => Some(1_000_000_000),
+        _ => None,
+    }
+}
+
+/// The value of the units following mười, for `tens` 1, or mươi.
+fn final_unit_value(word: &str, tens: u64) -> Option<u64> {
+    match word {
+        "mốt" if tens >= 2 => Some(1),
+        "tư" => Some(4),
+        "lăm" => Some(5),
+        "năm" => None,
+        word => digit_value(word).filter(|&digit| digit > 0),
+    }
+}
+
+/// Convert `input` to its vietnamese number words, as in the north.
+///
+///     use numerals::vietnamese::to_vietnamese;
+///
+///     assert_eq!(to_vietnamese(21), "hai mươi mốt");
+///     assert_eq!(to_vietnamese(15), "mười lăm");
+///     assert_eq!(to_vietnamese(105), "một trăm linh năm");
+///     assert_eq!(to_vietnamese(1994), "một nghìn chín trăm chín mươi tư");
+pub fn to_vietnamese(input: u64) -> String {
+    to_vietnamese_with(input, Dialect::Northern)
+}
+
+/// Convert `input` to its vietnamese number words, in the given dialect.
+///
+///     use numerals::vietnamese::{to_vietnamese_with, Dialect};
+///
+///     assert_eq!(to_vietnamese_with(1994, Dialect::Southern), "một ngàn chín trăm chín mươi bốn");
+///     assert_eq!(to_vietnamese_with(105, Dialect::Southern), "một trăm lẻ năm");
+pub fn to_vietnamese_with(input: u64, dialect: Dialect) -> String {
+    if input == 0 {
+        return String::from(DIGITS[0]);
+    }
+
+    let mut words = Vec::new();
+    write_number(input, false, dialect, &mut words);
+    words.join(" ")
+}
+
+/// Write the words of a value, the groups beyond tỷ being written the same
+/// way, and the hundreds written in full if the value follows a larger group.
+fn write_number(input: u64, full: bool, dialect: Dialect, words: &mut Vec<&'static str>) {
+    let (high, low) = (input / 1_000_000_000, input % 1_000_000_000);
+    if high > 0 {
+        write_number(high, full, dialect, words);
+        words.push("tỷ");
+        if low > 0 {
+            write_number(low, true, dialect, words);
+        }
+        return;
+    }
+
+    let groups = [
+        (low / 1_000_000, Some("triệu")),
+        (low / 1000 % 1000, Some(dialect.thousand())),
+        (low % 1000, None),
+    ];
+    let mut full = full;
+    for &(group, multiplier) in groups.iter() {
+        if group == 0 {
+            continue;
+        }
+        write_group(group, full, dialect, words);
+        words.extend(multiplier);
+        full = true;
+    }
+}
+
+/// Write the words of a value below 1000, with không trăm if it follows a
+/// larger group.
+fn write_group(input: u64, full: bool, dialect: Dialect, words: &mut Vec<&'static str>) {
+    let (hundreds, tens, units) = (input / 100, input / 10 % 10, input % 10);
+    if hundreds > 0 || full {
+        words.push(DIGITS[hundreds as usize]);
+        words.push("trăm");
+    }
+    match (tens, units) {
+        (0, 0) => {}
+        (0, units) if hundreds > 0 || full => {
+            words.push(dialect.zero_tens());
+            words.push(DIGITS[units as usize]);
+        }
+        (0, units) => words.push(DIGITS[units as usize]),
+        (tens, units) => {
+            if tens == 1 {
+                words.push("mười");
+            } else {
+                words.push(DIGITS[tens as usize]);
+                words.push("mươi");
+            }
+            match units {
+                0 => {}
+                1 if tens >= 2 => words.push("mốt"),
+                4 if tens >= 2 => words.push(dialect.four()),
+                5 => words.push("lăm"),
+                units => words.push(DIGITS[units as usize]),
+            }
+        }
+    }
+}
+
+/// Convert vietnamese number words to their value.
+///
+/// The words of both dialects are accepted, and một after mươi as well, as in
+/// hai mươi một. After a larger group, không trăm can be left out before linh,
+/// as in một nghìn lẻ năm.
+///
+///     use numerals::vietnamese::to_arabic;
+///
+///     assert_eq!(to_arabic("hai mươi mốt"), Ok(21));
+///     assert_eq!(to_arabic("một trăm lẻ năm"), Ok(105));
+///     assert_eq!(to_arabic("một nghìn lẻ năm"), Ok(1005));
+///     assert_eq!(to_arabic("ba triệu hai trăm ngàn"), Ok(3_200_000));
+///     assert!(to_arabic("mười năm").is_err());
+pub fn to_arabic(input: &str) -> Result<u64, NumeralError> {
+    to_arabic_with_strictness(input, Strictness::Standard)
+}
+
+/// `to_arabic`, following the rules as closely as asked.
+///
+/// Strictly, only the numbers written as by `to_vietnamese_with` are accepted,
+/// in either dialect, with a single space between the words.
+///
+///     use numerals::vietnamese::to_arabic_with_strictness;
+///     use numerals::Strictness;
+///
+///     assert_eq!(to_arabic_with_strictness("hai mươi tư", Strictness::Strict), Ok(24));
+///     assert!(to_arabic_with_strictness("hai mươi một", Strictness::Strict).is_err());
+pub fn to_arabic_with_strictness(input: &str, strictness: Strictness) -> Result<u64, NumeralError> {
+    if input.is_empty() {
+        return Err(NumeralError::Empty);
+    }
+
+    let value = from_words(input)?;
+    if strictness == Strictness::Strict {
+        if let Some((index, ch)) = find_non_canonical(input, value) {
+            return Err(NumeralError::InvalidSequence { ch, index });
+        }
+    }
+    Ok(value)
+}
+
+/// Split the input into its words, each with the index of its first character.
+fn split_words(input: &str) -> Vec<(usize, &str)> {
+    let mut ret = Vec::new();
+    // The index and byte offset of the current word
+    let mut start: Option<(usize, usize)> = None;
+    for (index, (offset, ch)) in input.char_indices().enumerate() {
+        if ch.is_whitespace() {
+            if let Some((index, start)) = start.take() {
+                ret.push((index, &input[start..offset]));
+            }
+        } else if start.is_none() {
+            start = Some((index, offset));
+        }
+    }
+    if let Some((index, start)) = start {
+        ret.push((index, &input[start..]));
+    }
+    ret
+}
+
+/// The error for a word which cannot stand where it is, or is not a word of
+/// the numbers at all.
+fn word_error(index: usize, word: &str) -> NumeralError {
+    let ch = word.chars().next().unwrap_or_default();
+    let known =
+        digit_value(word).is_some() || multiplier_value(word).is_some() || WORDS.contains(&word);
+    if known {
+        NumeralError::InvalidSequence { ch, index }
+    } else {
+        NumeralError::InvalidCharacter { ch, index }
+    }
+}
+
+/// The value of vietnamese number words.
+fn from_words(input: &str) -> Result<u64, NumeralError> {
+    let words = split_words(input);
+    if words.is_empty() {
+        return Err(NumeralError::Empty);
+    }
+    if let [(_, "không")] = words[..] {
+        return Ok(0);
+    }
+
+    // The value of the groups completed by tỷ, of the groups since, and the
+    // last of nghìn or triệu since
+    let mut total: u64 = 0;
+    let mut current: u64 = 0;
+    let mut last_multiplier = u64::MAX;
+    let mut position = 0;
+    let checked = |value: Option<u64>| value.ok_or(NumeralError::Overflow);
+
+    while position < words.len() {
+        // Không trăm only follows a larger group
+        let full = position > 0;
+        let group = parse_group(&words, &mut position, full)?;
+        let (index, word) = match words.get(position) {
+            Some(&(index, word)) => (index, word),
+            None => {
+                current += group.unwrap_or(0);
+                break;
+            }
+        };
+        let multiplier = multiplier_value(word).ok_or_else(|| word_error(index, word))?;
+
+        if multiplier == 1_000_000_000 {
+            let section = current + group.unwrap_or(0);
+            // Tỷ by itself only follows another, as in một tỷ tỷ
+            let after_ty =
+                position > 0 && multiplier_value(words[position - 1].1) == Some(multiplier);
+            if section == 0 && !after_ty {
+                return Err(word_error(index, word));
+            }
+            total = checked(
+                total
+                    .checked_add(section)
+                    .and_then(|total| total.checked_mul(multiplier)),
+            )?;
+            current = 0;
+            last_multiplier = multiplier;
+        } else {
+            let group = group.filter(|&group| group > 0);
+            let group = group.ok_or_else(|| word_error(index, word))?;
+            if multiplier >= last_multiplier {
+                return Err(word_error(index, word));
+            }
+            current += group * multiplier;
+            last_multiplier = multiplier;
+        }
+        position += 1;
+    }
+    checked(total.checked_add(current))
+}
+
+/// Parse the words of a value below 1000 at `position`, if any, moving past
+/// them. Không trăm is accepted if the value follows a larger group, and so is
+/// linh without the hundreds, as in một nghìn lẻ năm.
+fn parse_group(
+    words: &[(usize, &str)],
+    position: &mut usize,
+    full: bool,
+) -> Result<Option<u64>, NumeralError> {
+    let word = |position: usize| words.get(position).map_or("", |&(_, word)| word);
+    let error = |position: usize| {
+        let (index, word) = words[position];
+        word_error(index, word)
+    };
+    let start = *position;
+
+    // The hundreds
+    let mut value = 0;
+    let hundreds = match digit_value(word(*position)) {
+        Some(digit) if word(*position + 1) == "trăm" => {
+            if digit == 0 && !full {
+                return Err(error(*position));
+            }
+            value = 100 * digit;
+            *position += 2;
+            true
+        }
+        _ => false,
+    };
+
+    // The tens and units
+    let first = word(*position);
+    if first == "linh" || first == "lẻ" {
+        if !hundreds && !full {
+            return Err(error(*position));
+        }
+        let units = match word(*position + 1) {
+            "tư" => Some(4),
+            word => digit_value(word).filter(|&digit| digit > 0),
+        };
+        let units = units.ok_or_else(|| match words.get(*position + 1) {
+            Some(_) => error(*position + 1),
+            None => error(*position),
+        })?;
+        value += units;
+        *position += 2;
+    } else if first == "mười" || word(*position + 1) == "mươi" {
+        let tens = match first {
+            "mười" => 1,
+            first => digit_value(first)
+                .filter(|&digit| digit >= 2)
+                .ok_or_else(|| error(*position + 1))?,
+        };
+        value += 10 * tens;
+        *position += if tens == 1 { 1 } else { 2 };
+        if let Some(units) = final_unit_value(word(*position), tens) {
+            value += units;
+            *position += 1;
+        }
+    } else if let Some(digit) = digit_value(first).filter(|&digit| digit > 0) {
+        // A digit after trăm needs linh, as in một trăm linh năm
+        if hundreds {
+            return Err(error(*position));
+        }
+        value = digit;
+        *position += 1;
+    }
+
+    Ok(if *position == start {
+        None
+    } else {
+        Some(value)
+    })
+}
+
+/// Find where the words differ from the ones written by `to_vietnamese_with`
+/// for their value, in either dialect.
+fn find_non_canonical(input: &str, value: u64) -> Option<(usize, char)> {
+    // The length of the longest prefix shared with a canonical number
+    let length = [Dialect::Northern, Dialect::Southern]
+        .iter()
+        .map(|&dialect| {
+            let canonical = to_vietnamese_with(value, dialect);
+            input
+                .chars()
+                .zip(canonical.chars())
+                .take_while(|(a, b)| a == b)
+                .count()
+                + usize::from(canonical == input)
+        })
+        .max()?;
+    let count = input.chars().count();
+    if length > count {
+        return None;
+    }
+    let index = length.min(count - 1);
+    Some((index, input.chars().nth(index)?))
+}
+
+/// The vietnamese number words, for use through `NumeralSystem`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Vietnamese;
+
+impl NumeralSystem for Vietnamese {
+    fn name(&self) -> &'static str {
+        "vietnamese"
+    }
+
+    fn parse(&self, input: &str) -> Result<u64, NumeralError> {
+        to_arabic(input)
+    }
+
+    fn parse_with(&self, input: &str, options: &Options) -> Result<u64, NumeralError> {
+        to_arabic_with_strictness(input, options.strictness)
+    }
+
+    fn format(&self, value: u64, _options: &Options) -> Result<String, NumeralError> {
+        Ok(to_vietnamese(value))
+    }
+}
+
+#[cfg(test)]
+mod test_to_vietnamese {
+    use crate::vietnamese::{to_vietnamese, to_vietnamese_with, Dialect};
+
+    #[test]
+    fn test_to_vietnamese() {
+        assert_eq!(to_vietnamese(0), "không");
+        assert_eq!(to_vietnamese(5), "năm");
+        assert_eq!(to_vietnamese(10), "mười");
+        assert_eq!(to_vietnamese(11), "mười một");
+        assert_eq!(to_vietnamese(14), "mười bốn");
+        assert_eq!(to_vietnamese(15), "mười lăm");
+        assert_eq!(to_vietnamese(20), "hai mươi");
+        assert_eq!(to_vietnamese(21), "hai mươi mốt");
+        assert_eq!(to_vietnamese(24), "hai mươi tư");
+        assert_eq!(to_vietnamese(25), "hai mươi lăm");
+        assert_eq!(to_vietnamese(100), "một trăm");
+        assert_eq!(to_vietnamese(105), "một trăm linh năm");
+        assert_eq!(to_vietnamese(110), "một trăm mười");
+        assert_eq!(to_vietnamese(1005), "một nghìn không trăm linh năm");
+        assert_eq!(to_vietnamese(2020), "hai nghìn không trăm hai mươi");
+        assert_eq!(to_vietnamese(1_000_000), "một triệu");
+        assert_eq!(to_vietnamese(1_000_005), "một triệu không trăm linh năm");
+        assert_eq!(to_vietnamese(3_200_000), "ba triệu hai trăm nghìn");
+        assert_eq!(to_vietnamese(1_000_000_000), "một tỷ");
+        assert_eq!(to_vietnamese(1_000_000_000_000), "một nghìn tỷ");
+        assert_eq!(to_vietnamese(1_000_000_000_000_000_000), "một tỷ tỷ");
+    }
+
+    #[test]
+    fn test_southern() {
+        let x = to_vietnamese_with(24, Dialect::Southern);
+        assert_eq!(x, "hai mươi bốn");
+
+        let x = to_vietnamese_with(1005, Dialect::Southern);
+        assert_eq!(x, "một ngàn không trăm lẻ năm");
+
+        let x = to_vietnamese_with(104, Dialect::Southern);
+        assert_eq!(x, "một trăm lẻ bốn");
+    }
+}
+
+#[cfg(test)]
+mod test_to_arabic {
+    use crate::vietnamese::{to_arabic, to_arabic_with_strictness, to_vietnamese_with, Dialect};
+    use crate::{NumeralError, Strictness};
+
+    #[test]
+    fn test_to_arabic() {
+        let x = to_arabic("không");
+        assert_eq!(x.unwrap(), 0);
+
+        let x = to_arabic("mười lăm");
+        assert_eq!(x.unwrap(), 15);
+
+        let x = to_arabic("mười tư");
+        assert_eq!(x.unwrap(), 14);
+
+        let x = to_arabic("hai mươi một");
+        assert_eq!(x.unwrap(), 21);
+
+        let x = to_arabic("một trăm linh tư");
+        assert_eq!(x.unwrap(), 104);
+
+        let x = to_arabic("hai ngàn không trăm hai mươi");
+        assert_eq!(x.unwrap(), 2020);
+
+        let x = to_arabic("hai nghìn hai mươi");
+        assert_eq!(x.unwrap(), 2020);
+
+        let x = to_arabic("một nghìn lẻ năm");
+        assert_eq!(x.unwrap(), 1005);
+
+        let x = to_arabic("hai triệu linh năm");
+        assert_eq!(x.unwrap(), 2_000_005);
+
+        let x = to_arabic("năm tỉ");
+        assert_eq!(x.unwrap(), 5_000_000_000);
+
+        let x = to_arabic("một  nghìn\ttỷ");
+        assert_eq!(x.unwrap(), 1_000_000_000_000);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for &dialect in &[Dialect::Northern, Dialect::Southern] {
+            for value in (0..=20_000).chain((0..64).map(|shift| 1u64 << shift)) {
+                let x = to_vietnamese_with(value, dialect);
+                assert_eq!(to_arabic_with_strictness(&x, Strictness::Strict), Ok(value));
+            }
+            let x = to_vietnamese_with(u64::MAX, dialect);
+            assert_eq!(to_arabic(&x), Ok(u64::MAX));
+        }
+    }
+
+    #[test]
+    fn test_strict() {
+        let x = to_arabic_with_strictness("hai mươi một", Strictness::Strict);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence {
+                ch: 'ộ', index: 10
+            }
+        );
+
+        let x = to_arabic_with_strictness("hai nghìn hai mươi", Strictness::Strict);
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'h', index: 10 }
+        );
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let x = to_arabic("");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = to_arabic(" ");
+        assert_eq!(x.unwrap_err(), NumeralError::Empty);
+
+        let x = to_arabic("mười năm");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'n', index: 5 }
+        );
+
+        let x = to_arabic("mười mốt");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'm', index: 5 }
+        );
+
+        let x = to_arabic("một mươi");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'm', index: 4 }
+        );
+
+        let x = to_arabic("linh năm");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'l', index: 0 }
+        );
+
+        let x = to_arabic("một trăm năm");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'n', index: 9 }
+        );
+
+        let x = to_arabic("không trăm năm");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'k', index: 0 }
+        );
+
+        let x = to_arabic("một nghìn triệu");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 't', index: 10 }
+        );
+
+        let x = to_arabic("nghìn");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'n', index: 0 }
+        );
+
+        let x = to_arabic("hai mươi x");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidCharacter { ch: 'x', index: 9 }
+        );
+
+        let x = to_arabic("hai ba");
+        assert_eq!(
+            x.unwrap_err(),
+            NumeralError::InvalidSequence { ch: 'b', index: 4 }
+        );
+
+        let x = to_arabic("hai mươi tỷ tỷ tỷ");
+        assert_eq!(x.unwrap_err(), NumeralError::Overflow);
+    }
+}
+
+#[cfg(test)]
+mod test_numeral_system {
+    use crate::vietnamese::Vietnamese;
+    use crate::{NumeralSystem, Options};
+
+    #[test]
+    fn test_numeral_system() {
+        assert_eq!(Vietnamese.name(), "vietnamese");
+        assert_eq!(Vietnamese.parse("mười lăm").unwrap(), 15);
+        assert_eq!(
+            Vietnamese.format(1994, &Options::default()).unwrap(),
+            "một nghìn chín trăm chín mươi tư"
+        );
+    }
+}
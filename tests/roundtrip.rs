@@ -0,0 +1,154 @@
+//! Round trips between the values and the numerals, over the whole range of
+//! every notation, along with the invariants the canonical numerals follow.
+//!
+//! The spellings of a few values are pinned in `SPELLINGS`, so that any change
+//! to the tables shows up here.
+
+#![cfg(feature = "roman")]
+
+use numerals::roman::{
+    canonicalize, explain, iter, to_apostrophus, to_arabic, to_arabic_lenient,
+    to_arabic_with_strictness, to_brackets, to_roman, to_roman_compact, to_roman_styled,
+    to_vinculum, validate, RomanOptions, Style, MAX, MAX_APOSTROPHUS,
+};
+use numerals::Strictness;
+
+/// Values along with their ascii and unicode numerals.
+const SPELLINGS: [(u64, &str, &str); 16] = [
+    (1, "I", "Ⅰ"),
+    (2, "II", "ⅠⅠ"),
+    (3, "III", "ⅠⅠⅠ"),
+    (4, "IV", "ⅠⅤ"),
+    (5, "V", "Ⅴ"),
+    (6, "VI", "ⅤⅠ"),
+    (9, "IX", "ⅠⅩ"),
+    (12, "XII", "ⅩⅠⅠ"),
+    (14, "XIV", "ⅩⅠⅤ"),
+    (40, "XL", "ⅩⅬ"),
+    (90, "XC", "ⅩⅭ"),
+    (400, "CD", "ⅭⅮ"),
+    (900, "CM", "ⅭⅯ"),
+    (1994, "MCMXCIV", "ⅯⅭⅯⅩⅭⅠⅤ"),
+    (3888, "MMMDCCCLXXXVIII", "ⅯⅯⅯⅮⅭⅭⅭⅬⅩⅩⅩⅤⅠⅠⅠ"),
+    (3999, "MMMCMXCIX", "ⅯⅯⅯⅭⅯⅩⅭⅠⅩ"),
+];
+
+/// The canonical numerals of every value, in both scripts and both cases.
+fn canonical_numerals() -> impl Iterator<Item = (u64, String)> {
+    (1..=MAX).flat_map(|value| {
+        let ascii = to_roman(value, false).unwrap();
+        let unicode = to_roman(value, true).unwrap();
+        vec![
+            (value, ascii.to_lowercase()),
+            (value, unicode.to_lowercase()),
+            (value, ascii),
+            (value, unicode),
+        ]
+    })
+}
+
+#[test]
+fn test_spellings() {
+    for &(value, ascii, unicode) in SPELLINGS.iter() {
+        assert_eq!(to_roman(value, false).unwrap(), ascii);
+        assert_eq!(to_roman(value, true).unwrap(), unicode);
+        assert_eq!(to_arabic(ascii), Ok(value));
+        assert_eq!(to_arabic(unicode), Ok(value));
+    }
+}
+
+#[test]
+fn test_unicode_numerals_are_unicode() {
+    for value in 1..=MAX {
+        let numeral = to_roman(value, true).unwrap();
+        assert!(numeral.chars().all(|c| !c.is_ascii()), "{}", numeral);
+    }
+}
+
+#[test]
+fn test_roundtrip() {
+    for (value, numeral) in canonical_numerals() {
+        assert_eq!(to_arabic(&numeral), Ok(value), "{}", numeral);
+    }
+}
+
+#[test]
+fn test_canonical_numerals_parse_strictly() {
+    for (value, numeral) in canonical_numerals() {
+        let parsed = to_arabic_with_strictness(&numeral, Strictness::Strict);
+        assert_eq!(parsed, Ok(value), "{}", numeral);
+    }
+}
+
+#[test]
+fn test_canonical_numerals_are_valid() {
+    for (value, numeral) in canonical_numerals() {
+        assert_eq!(validate(&numeral), Ok(()), "{}", numeral);
+        let parsed = to_arabic_lenient(&numeral).unwrap();
+        assert_eq!(parsed.value, value);
+        assert!(parsed.canonical, "{}", numeral);
+    }
+}
+
+#[test]
+fn test_canonicalize_is_identity() {
+    for value in 1..=MAX {
+        for &unicode in &[false, true] {
+            let numeral = to_roman(value, unicode).unwrap();
+            assert_eq!(canonicalize(&numeral).unwrap(), numeral);
+        }
+    }
+}
+
+#[test]
+fn test_terms_add_up() {
+    for value in 1..=MAX {
+        let numeral = to_roman(value, false).unwrap();
+        let terms = explain(&numeral).unwrap();
+        assert_eq!(terms.iter().map(|term| term.value).sum::<u64>(), value);
+        let spelled: String = terms.iter().map(|term| term.numeral.as_str()).collect();
+        assert_eq!(spelled, numeral);
+    }
+}
+
+#[test]
+fn test_styles() {
+    for value in 1..=MAX {
+        // Only IIII itself is accepted by to_arabic
+        let clock = to_roman_styled(value, false, Style::Clock).unwrap();
+        let parsed = to_arabic_lenient(&clock).unwrap();
+        assert_eq!(parsed.value, value, "{}", clock);
+
+        let additive = to_roman_styled(value, false, Style::Additive).unwrap();
+        let parsed = to_arabic_lenient(&additive).unwrap();
+        assert_eq!(parsed.value, value, "{}", additive);
+
+        let compact = to_roman_compact(value, Style::Subtractive).unwrap();
+        assert_eq!(to_arabic(&compact), Ok(value), "{}", compact);
+    }
+}
+
+#[test]
+fn test_large_notations() {
+    let values = (1..=20_000).chain((1..=1000).map(|value| value * 7919));
+    for value in values {
+        let vinculum = to_vinculum(value).unwrap();
+        assert_eq!(to_arabic(&vinculum), Ok(value), "{}", vinculum);
+
+        let brackets = to_brackets(value).unwrap();
+        assert_eq!(to_arabic(&brackets), Ok(value), "{}", brackets);
+
+        if value <= MAX_APOSTROPHUS {
+            let apostrophus = to_apostrophus(value).unwrap();
+            assert_eq!(to_arabic(&apostrophus), Ok(value), "{}", apostrophus);
+        }
+    }
+}
+
+#[test]
+fn test_iter() {
+    let options = RomanOptions::new().unicode(true);
+    for (value, numeral) in (1..).zip(iter(.., &options)) {
+        assert_eq!(numeral, to_roman(value, true).unwrap());
+    }
+}